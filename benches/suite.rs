@@ -0,0 +1,109 @@
+//! Benchmarks for comparing the ways this library can be configured. Run them with
+//! `cargo bench --bench suite`, or pick a group with, for example,
+//! `cargo bench --bench suite -- elastic`.
+//!
+//! - `elastic` compares evaluating [ElasticOut] directly, through the erased [Easing] enum, and
+//!   through a [Baked] lookup table.
+//! - `tweener` compares driving a [Tweener] against calling a hand written lerp, to show the cost
+//!   of the driver itself.
+//! - `manager` updates a [TweenManager] holding 100,000 tweeners.
+
+use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main};
+use tween::{Baked, Easing, ElasticOut, FixedTweener, Linear, Tween, TweenManager, Tweener};
+
+/// How many samples each easing benchmark takes per iteration.
+const SAMPLES: usize = 1024;
+
+fn percents() -> Vec<f32> {
+    (0..SAMPLES).map(|i| i as f32 / (SAMPLES - 1) as f32).collect()
+}
+
+fn ease_all<T: Tween<f32>>(tween: &mut T, percents: &[f32]) -> f32 {
+    percents
+        .iter()
+        .map(|&percent| tween.tween(black_box(100.0), percent))
+        .sum()
+}
+
+fn bench_elastic(c: &mut Criterion) {
+    let percents = percents();
+    let mut exact = ElasticOut;
+    let mut erased = Easing::ElasticOut;
+    let mut baked_64 = Baked::<64>::new(ElasticOut);
+    let mut baked_256 = Baked::<256>::new(ElasticOut);
+
+    let mut group = c.benchmark_group("elastic");
+    group.bench_function("exact", |b| b.iter(|| ease_all(&mut exact, black_box(&percents))));
+    group.bench_function("easing enum", |b| {
+        b.iter(|| ease_all(&mut erased, black_box(&percents)))
+    });
+    group.bench_function("baked 64", |b| b.iter(|| ease_all(&mut baked_64, black_box(&percents))));
+    group.bench_function("baked 256", |b| {
+        b.iter(|| ease_all(&mut baked_256, black_box(&percents)))
+    });
+    group.finish();
+}
+
+#[inline(never)]
+fn raw_lerp(start: f32, end: f32, times: &[f32], duration: f32, out: &mut [f32]) {
+    for (slot, &time) in out.iter_mut().zip(times) {
+        let percent = (time / duration).clamp(0.0, 1.0);
+        *slot = start + (end - start) * percent;
+    }
+}
+
+#[inline(never)]
+fn tweener_lerp(tweener: &mut Tweener<f32, f32, Linear>, times: &[f32], out: &mut [f32]) {
+    for (slot, &time) in out.iter_mut().zip(times) {
+        *slot = tweener.move_to(time);
+    }
+}
+
+fn bench_tweener(c: &mut Criterion) {
+    let times: Vec<f32> = percents().into_iter().map(|percent| percent * 10.0).collect();
+    let mut out = vec![0.0; SAMPLES];
+    let mut tweener = Tweener::linear(0.0, 100.0, 10.0);
+
+    let mut group = c.benchmark_group("tweener");
+    group.bench_function("raw function", |b| {
+        b.iter(|| raw_lerp(black_box(0.0), black_box(100.0), black_box(&times), 10.0, &mut out))
+    });
+    group.bench_function("tweener move_to", |b| {
+        b.iter(|| tweener_lerp(&mut tweener, black_box(&times), &mut out))
+    });
+    group.bench_function("fixed sample_into", |b| {
+        b.iter_batched_ref(
+            || FixedTweener::linear(0.0, 100.0, 10.0, 10.0 / SAMPLES as f32),
+            |fixed| fixed.sample_into(&mut out),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+
+    black_box(out);
+}
+
+fn bench_manager(c: &mut Criterion) {
+    let mut group = c.benchmark_group("manager");
+    group.sample_size(20);
+    group.bench_function("update 100k", |b| {
+        b.iter_batched_ref(
+            || {
+                let mut manager = TweenManager::new();
+                for i in 0..100_000 {
+                    let easing = Easing::ALL[i % Easing::ALL.len()];
+                    manager.add(Tweener::new(0.0f32, 1.0, 10.0, easing), |value| {
+                        black_box(value);
+                    });
+                }
+                manager
+            },
+            |manager| manager.update(black_box(1.0 / 60.0)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(suite, bench_elastic, bench_tweener, bench_manager);
+criterion_main!(suite);
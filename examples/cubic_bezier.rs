@@ -10,7 +10,7 @@
 
 use std::ops::{Add, Sub};
 
-use tween::{Tween, TweenValue, Tweener};
+use tween::{Tween, TweenValue, Tweener, lerp};
 
 fn main() {
     let start = Point(0.0, 0.0);
@@ -77,6 +77,27 @@ fn cubic_bezier_closure(
 /// These are our two control points
 pub struct CubicBezier<T>(T, T);
 
+impl<T: TweenValue> Tween<T> for CubicBezier<T> {
+    fn tween(&mut self, delta: T, t: f32) -> T {
+        // cheeky way to get a zero
+        let zero = delta.clone().scale(0.0);
+
+        let a = lerp(zero, self.0.clone(), t);
+        let b = lerp(self.0.clone(), self.1.clone(), t);
+        let c = lerp(self.1.clone(), delta, t);
+
+        let d = lerp(a, b.clone(), t);
+        let e = lerp(b, c, t);
+
+        lerp(d, e, t)
+    }
+
+    // oh yeah, we're wild
+    fn is_finite(&self) -> bool {
+        false
+    }
+}
+
 /// This is going to be a strictly speaking **better* implementation:
 /// we're going to implement our Tween generically here. That means, although we'll
 /// only use Points in this example, you could use this to cubic bezier tween anything.
@@ -87,32 +108,6 @@ fn cubic_bezier_for_real(
     quarter_pt: Point,
     three_quarter_pt: Point,
 ) -> Tweener<Point, f32, CubicBezier<Point>> {
-    impl<T: TweenValue> Tween<T> for CubicBezier<T> {
-        fn tween(&mut self, delta: T, t: f32) -> T {
-            // we need to write our own lerp with the generic functions available to us
-            fn lerp<T: TweenValue>(a: T, b: T, t: f32) -> T {
-                (b - a).scale(t) + a
-            }
-
-            // cheeky way to get a zero
-            let zero = delta.scale(0.0);
-
-            let a = lerp(zero, self.0, t);
-            let b = lerp(self.0, self.1, t);
-            let c = lerp(self.1, delta, t);
-
-            let d = lerp(a, b, t);
-            let e = lerp(b, c, t);
-
-            lerp(d, e, t)
-        }
-
-        // oh yeah, we're wild
-        fn is_finite(&self) -> bool {
-            false
-        }
-    }
-
     Tweener::new(start, destination, duration, CubicBezier(quarter_pt, three_quarter_pt))
 }
 
@@ -0,0 +1,212 @@
+//! An interactive gallery of every easing in the library, drawn in the terminal.
+//!
+//! Run it with `cargo run --example demo --features demo`. Use the arrow keys (or `j` and `k`) to
+//! pick an easing, space to replay it, and `q` or escape to quit.
+//!
+//! Each easing is drawn twice: as a plot of its whole curve, and as a bar which is animated by a
+//! [TweenManager], just like a real game would drive it.
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType},
+};
+use std::{
+    cell::Cell,
+    io::{self, Write},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+use tween::{Easing, TweenManager, Tweener};
+
+const PLOT_WIDTH: usize = 48;
+const PLOT_HEIGHT: usize = 16;
+const BAR_WIDTH: usize = 48;
+
+/// Easings which overshoot go outside of `0.0..=1.0`, so the plot leaves room for them.
+const PLOT_MIN: f32 = -0.25;
+const PLOT_MAX: f32 = 1.25;
+
+/// How long each animation runs, and how long it rests at the end before replaying.
+const DURATION: f32 = 1.5;
+const REST: f32 = 0.75;
+
+struct Gallery {
+    easings: Vec<Easing>,
+    selected: usize,
+    manager: TweenManager<f32>,
+    value: Rc<Cell<f32>>,
+    resting: f32,
+}
+
+impl Gallery {
+    fn new() -> Self {
+        let mut gallery = Self {
+            easings: Easing::ALL.to_vec(),
+            selected: 0,
+            manager: TweenManager::new(),
+            value: Rc::new(Cell::new(0.0)),
+            resting: 0.0,
+        };
+        gallery.replay();
+
+        gallery
+    }
+
+    fn easing(&self) -> Easing {
+        self.easings[self.selected]
+    }
+
+    fn select(&mut self, offset: isize) {
+        let len = self.easings.len() as isize;
+        self.selected = (self.selected as isize + offset).rem_euclid(len) as usize;
+        self.replay();
+    }
+
+    /// Starts the bar animation again from the beginning.
+    fn replay(&mut self) {
+        self.manager.clear();
+        self.manager.drain_finished().for_each(drop);
+        self.resting = 0.0;
+        self.value.set(0.0);
+
+        let value = self.value.clone();
+        self.manager
+            .add(Tweener::new(0.0, 1.0, DURATION, self.easing()), move |v| value.set(v));
+    }
+
+    fn update(&mut self, delta: f32) {
+        self.manager.update(delta);
+
+        if self.manager.is_empty() {
+            self.resting += delta;
+            if self.resting >= REST {
+                self.replay();
+            }
+        }
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut lines = vec![String::from(
+            "tween easing gallery   [up/down] pick   [space] replay   [q] quit",
+        )];
+        lines.push(String::new());
+
+        let plot = plot(self.easing());
+        let list_len = self.easings.len().max(plot.len());
+
+        for row in 0..list_len {
+            let name = match self.easings.get(row) {
+                Some(easing) => {
+                    let marker = if row == self.selected { '>' } else { ' ' };
+                    format!("{marker} {:<16}", easing.name().unwrap_or("?"))
+                }
+                None => " ".repeat(18),
+            };
+            let plot_row = plot.get(row).map(String::as_str).unwrap_or("");
+
+            lines.push(format!("{name} {plot_row}"));
+        }
+
+        let filled = ((self.value.get().clamp(0.0, 1.25) / 1.25) * BAR_WIDTH as f32).round() as usize;
+        lines.push(String::new());
+        lines.push(format!(
+            "{:>18} [{}{}] {:.3}",
+            "animated",
+            "#".repeat(filled),
+            " ".repeat(BAR_WIDTH - filled),
+            self.value.get()
+        ));
+
+        for (y, line) in lines.iter().enumerate() {
+            // clearing each line as we go, rather than the whole screen, keeps it from flickering
+            queue!(
+                out,
+                cursor::MoveTo(0, y as u16),
+                Print(line),
+                terminal::Clear(ClearType::UntilNewLine)
+            )?;
+        }
+
+        out.flush()
+    }
+}
+
+/// Plots the whole curve of `easing`, with time going right and the value going up.
+fn plot(mut easing: Easing) -> Vec<String> {
+    let mut grid = vec![vec![' '; PLOT_WIDTH]; PLOT_HEIGHT];
+
+    let row_of = |value: f32| {
+        let scaled = (value - PLOT_MIN) / (PLOT_MAX - PLOT_MIN);
+        let row = ((1.0 - scaled) * (PLOT_HEIGHT - 1) as f32).round();
+        row.clamp(0.0, (PLOT_HEIGHT - 1) as f32) as usize
+    };
+
+    // guides for 0.0 and 1.0, so overshoot is easy to see
+    for cell in &mut grid[row_of(0.0)] {
+        *cell = '-';
+    }
+    for cell in &mut grid[row_of(1.0)] {
+        *cell = '-';
+    }
+
+    let curve: Vec<usize> = (0..PLOT_WIDTH)
+        .map(|column| row_of(easing.tween(1.0, column as f32 / (PLOT_WIDTH - 1) as f32)))
+        .collect();
+
+    for (y, row) in grid.iter_mut().enumerate() {
+        for (cell, _) in row.iter_mut().zip(&curve).filter(|(_, curve_row)| **curve_row == y) {
+            *cell = '*';
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| format!("|{}|", row.into_iter().collect::<String>()))
+        .collect()
+}
+
+fn run(out: &mut impl Write) -> io::Result<()> {
+    let mut gallery = Gallery::new();
+    let mut last = Instant::now();
+
+    loop {
+        gallery.draw(out)?;
+
+        if event::poll(Duration::from_millis(16))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Up | KeyCode::Char('k') => gallery.select(-1),
+                    KeyCode::Down | KeyCode::Char('j') => gallery.select(1),
+                    KeyCode::Char(' ') => gallery.replay(),
+                    _ => {}
+                }
+            }
+        }
+
+        let now = Instant::now();
+        gallery.update((now - last).as_secs_f32());
+        last = now;
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut out = io::stdout();
+
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = run(&mut out);
+
+    // put the terminal back, even if drawing failed
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+
+    result
+}
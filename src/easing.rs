@@ -0,0 +1,287 @@
+use crate::{CubicBezier, Linear, Steps, Tween, TweenValue};
+
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "std")]
+pub use registry::{CustomEasing, RegisterEasingError, register_easing};
+
+/// This is internal to the library, and declares the [Easing] enum over all of the
+/// parameterless tweens, along with their names.
+macro_rules! declare_easing {
+    ($($(#[$meta:meta])* $variant:ident => $name:literal),* $(,)?) => {
+        /// An erased tween, which can be any of the tweens built into this library, chosen at
+        /// runtime.
+        ///
+        /// Unlike `Box<dyn Tween<Value>>`, an Easing is `Copy`, needs no allocation (so it works
+        /// in `no_std`), and dispatches with a simple `match`. This makes it a good fit for
+        /// data-driven animation, where the easing is read out of a file:
+        ///
+        /// ```
+        /// # #[cfg(feature = "quad")] {
+        /// # use tween::{Easing, Tweener};
+        /// let easing: Easing = "quad_in".parse().unwrap();
+        /// let mut tweener = Tweener::new(0, 100, 10, easing);
+        ///
+        /// assert_eq!(tweener.move_to(5), 25);
+        /// assert_eq!(easing.name(), Some("quad_in"));
+        /// # }
+        /// ```
+        ///
+        /// Names are the same as the shortcut constructors on [Tweener](crate::Tweener), like
+        /// `sine_in_out`.
+        ///
+        /// Each family of easings, like `quad` or `elastic`, is behind a feature of the same name,
+        /// and `easings` turns them all on. Families which are turned off are left out of the
+        /// Easing too, so they can't be parsed. [Linear], [CubicBezier], and [Steps] are always
+        /// available. Since turning on a family adds variants, Easing is `non_exhaustive`, so
+        /// matches on it from outside this crate need a wildcard arm.
+        #[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+        #[non_exhaustive]
+        pub enum Easing {
+            /// A [Linear] tween.
+            #[default]
+            Linear,
+            $(
+                #[doc = concat!("A [", stringify!($variant), "](crate::", stringify!($variant), ") tween.")]
+                $(#[$meta])*
+                $variant,
+            )*
+            /// A [CubicBezier] tween.
+            CubicBezier(CubicBezier),
+            /// A [Steps] tween.
+            Steps(Steps),
+            /// An easing registered at runtime. See [register_easing].
+            #[cfg(feature = "std")]
+            Custom(CustomEasing),
+        }
+
+        impl Easing {
+            /// Every Easing which doesn't take any parameters.
+            pub const ALL: &'static [Easing] = &[Easing::Linear, $($(#[$meta])* Easing::$variant),*];
+
+            /// Returns the name of this Easing, which [FromStr](core::str::FromStr) accepts, or
+            /// `None` if this Easing has parameters. Custom easings return the name they were
+            /// registered with.
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    Easing::Linear => Some("linear"),
+                    $($(#[$meta])* Easing::$variant => Some($name),)*
+                    Easing::CubicBezier(_) | Easing::Steps(_) => None,
+                    #[cfg(feature = "std")]
+                    Easing::Custom(custom) => Some(custom.name()),
+                }
+            }
+
+            /// Run the given Tween with a new time.
+            #[inline]
+            pub fn tween<Value: TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+                match self {
+                    Easing::Linear => Linear.tween(value_delta, percent),
+                    $($(#[$meta])* Easing::$variant => crate::$variant.tween(value_delta, percent),)*
+                    Easing::CubicBezier(bezier) => bezier.tween(value_delta, percent),
+                    Easing::Steps(steps) => steps.tween(value_delta, percent),
+                    #[cfg(feature = "std")]
+                    Easing::Custom(custom) => value_delta.scale(custom.ease(percent)),
+                }
+            }
+        }
+
+        impl core::str::FromStr for Easing {
+            type Err = ParseEasingError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "linear" => Ok(Easing::Linear),
+                    $($(#[$meta])* $name => Ok(Easing::$variant),)*
+                    #[cfg(feature = "std")]
+                    _ => CustomEasing::find(s).map(Easing::Custom).ok_or(ParseEasingError),
+                    #[cfg(not(feature = "std"))]
+                    _ => Err(ParseEasingError),
+                }
+            }
+        }
+
+        $(
+            $(#[$meta])*
+            impl From<crate::$variant> for Easing {
+                fn from(_: crate::$variant) -> Self {
+                    Easing::$variant
+                }
+            }
+        )*
+    };
+}
+
+declare_easing! {
+    #[cfg(feature = "sine")]
+    SineIn => "sine_in",
+    #[cfg(feature = "sine")]
+    SineOut => "sine_out",
+    #[cfg(feature = "sine")]
+    SineInOut => "sine_in_out",
+    #[cfg(feature = "quad")]
+    QuadIn => "quad_in",
+    #[cfg(feature = "quad")]
+    QuadOut => "quad_out",
+    #[cfg(feature = "quad")]
+    QuadInOut => "quad_in_out",
+    #[cfg(feature = "cubic")]
+    CubicIn => "cubic_in",
+    #[cfg(feature = "cubic")]
+    CubicOut => "cubic_out",
+    #[cfg(feature = "cubic")]
+    CubicInOut => "cubic_in_out",
+    #[cfg(feature = "quart")]
+    QuartIn => "quart_in",
+    #[cfg(feature = "quart")]
+    QuartOut => "quart_out",
+    #[cfg(feature = "quart")]
+    QuartInOut => "quart_in_out",
+    #[cfg(feature = "quint")]
+    QuintIn => "quint_in",
+    #[cfg(feature = "quint")]
+    QuintOut => "quint_out",
+    #[cfg(feature = "quint")]
+    QuintInOut => "quint_in_out",
+    #[cfg(feature = "expo")]
+    ExpoIn => "expo_in",
+    #[cfg(feature = "expo")]
+    ExpoOut => "expo_out",
+    #[cfg(feature = "expo")]
+    ExpoInOut => "expo_in_out",
+    #[cfg(feature = "circ")]
+    CircIn => "circ_in",
+    #[cfg(feature = "circ")]
+    CircOut => "circ_out",
+    #[cfg(feature = "circ")]
+    CircInOut => "circ_in_out",
+    #[cfg(feature = "back")]
+    BackIn => "back_in",
+    #[cfg(feature = "back")]
+    BackOut => "back_out",
+    #[cfg(feature = "back")]
+    BackInOut => "back_in_out",
+    #[cfg(feature = "elastic")]
+    ElasticIn => "elastic_in",
+    #[cfg(feature = "elastic")]
+    ElasticOut => "elastic_out",
+    #[cfg(feature = "elastic")]
+    ElasticInOut => "elastic_in_out",
+    #[cfg(feature = "bounce")]
+    BounceIn => "bounce_in",
+    #[cfg(feature = "bounce")]
+    BounceOut => "bounce_out",
+    #[cfg(feature = "bounce")]
+    BounceInOut => "bounce_in_out",
+}
+
+impl From<Linear> for Easing {
+    fn from(_: Linear) -> Self {
+        Easing::Linear
+    }
+}
+
+impl From<CubicBezier> for Easing {
+    fn from(bezier: CubicBezier) -> Self {
+        Easing::CubicBezier(bezier)
+    }
+}
+
+impl From<Steps> for Easing {
+    fn from(steps: Steps) -> Self {
+        Easing::Steps(steps)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<CustomEasing> for Easing {
+    fn from(custom: CustomEasing) -> Self {
+        Easing::Custom(custom)
+    }
+}
+
+impl<Value> Tween<Value> for Easing
+where
+    Value: TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+}
+
+/// The error returned when parsing an unknown name into an [Easing].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseEasingError;
+
+impl core::fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown easing name")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEasingError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StepPosition;
+
+    #[test]
+    fn names_round_trip() {
+        for easing in Easing::ALL {
+            let name = easing.name().unwrap();
+            assert_eq!(name.parse::<Easing>(), Ok(*easing));
+        }
+
+        assert_eq!("not_an_easing".parse::<Easing>(), Err(ParseEasingError));
+        assert_eq!(Easing::CubicBezier(CubicBezier::EASE).name(), None);
+    }
+
+    #[test]
+    fn dispatch_matches() {
+        fn check<T: Tween<f32>>(mut tween: T, easing: impl Into<Easing>) {
+            let mut easing = easing.into();
+            for i in 0..=10 {
+                let pct = i as f32 / 10.0;
+                assert_eq!(Tween::<f32>::tween(&mut easing, 10.0, pct), tween.tween(10.0, pct));
+            }
+        }
+
+        check(Linear, Linear);
+        #[cfg(feature = "quad")]
+        check(crate::QuadInOut, crate::QuadInOut);
+        #[cfg(feature = "elastic")]
+        check(crate::ElasticOut, crate::ElasticOut);
+        #[cfg(feature = "bounce")]
+        check(crate::BounceIn, crate::BounceIn);
+        check(CubicBezier::EASE_IN, CubicBezier::EASE_IN);
+        check(
+            Steps::new(3, StepPosition::JumpBoth),
+            Steps::new(3, StepPosition::JumpBoth),
+        );
+    }
+
+    #[test]
+    fn is_copy_and_small() {
+        static_assertions::assert_impl_all!(Easing: Copy, Send, Sync);
+        assert!(core::mem::size_of::<Easing>() <= 20);
+    }
+
+    #[cfg(all(feature = "serde", feature = "sine", feature = "bounce"))]
+    #[test]
+    fn serde_names() {
+        assert_eq!(serde_json::to_string(&Easing::SineInOut).unwrap(), "\"sine_in_out\"");
+        assert_eq!(
+            serde_json::from_str::<Easing>("\"bounce_out\"").unwrap(),
+            Easing::BounceOut
+        );
+
+        let bezier = Easing::CubicBezier(CubicBezier::EASE);
+        let json = serde_json::to_string(&bezier).unwrap();
+        assert_eq!(serde_json::from_str::<Easing>(&json).unwrap(), bezier);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::Easing;
+use std::{
+    boxed::Box,
+    string::String,
+    sync::{Arc, PoisonError, RwLock},
+    vec::Vec,
+};
+
+type Curve = Arc<dyn Fn(f32) -> f32 + Send + Sync>;
+
+struct Entry {
+    name: &'static str,
+    curve: Curve,
+}
+
+/// Every custom easing registered so far. A [CustomEasing] is an index into this, and since
+/// entries are never removed, every CustomEasing stays valid for the life of the process.
+static REGISTRY: RwLock<Vec<Entry>> = RwLock::new(Vec::new());
+
+/// An easing which was registered at runtime with [register_easing], such as one added by a mod.
+///
+/// These are only valid within the process which registered them, so with `serde` they are
+/// written out by name, and reading one back fails if nothing has been registered under that name
+/// yet.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub struct CustomEasing(u32);
+
+impl CustomEasing {
+    /// Looks up a custom easing by name.
+    pub fn find(name: &str) -> Option<Self> {
+        let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+
+        registry
+            .iter()
+            .position(|entry| entry.name == name)
+            .map(|index| Self(index as u32))
+    }
+
+    /// The name this easing was registered with.
+    pub fn name(&self) -> &'static str {
+        let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+
+        registry[self.0 as usize].name
+    }
+
+    /// Runs the registered curve at `percent`.
+    #[inline]
+    pub(crate) fn ease(&self, percent: f32) -> f32 {
+        // the curve runs unlocked, so that it's free to use the registry itself
+        let curve = {
+            let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+            registry[self.0 as usize].curve.clone()
+        };
+
+        curve(percent)
+    }
+}
+
+/// Registers a new easing under `name`, which can then be parsed into an [Easing] like any of the
+/// built in ones. This is for scripting and modding, where the set of easings isn't known until
+/// runtime.
+///
+/// The `curve` takes a percent, from `0.0` to `1.0`, and returns how far along the tween should
+/// be. It can be a closure, or wrap a lookup table like a [Baked](crate::Baked). The registry
+/// isn't locked while it runs, so it can look up or register other easings.
+///
+/// Each call to a custom easing reads from a global registry, so they are a little slower than
+/// the built in easings.
+///
+/// ```
+/// # use tween::{Easing, Tweener, register_easing};
+/// register_easing("smoothstep", |t| t * t * (3.0 - 2.0 * t)).unwrap();
+///
+/// let easing: Easing = "smoothstep".parse().unwrap();
+/// assert_eq!(Tweener::new(0.0, 100.0, 10.0, easing).move_to(5.0), 50.0);
+/// assert_eq!(easing.name(), Some("smoothstep"));
+/// ```
+pub fn register_easing<F>(name: &str, curve: F) -> Result<Easing, RegisterEasingError>
+where
+    F: Fn(f32) -> f32 + Send + Sync + 'static,
+{
+    if name.parse::<Easing>().is_ok() {
+        return Err(RegisterEasingError);
+    }
+
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+
+    // someone else might have registered this name while we were unlocked
+    if registry.iter().any(|entry| entry.name == name) {
+        return Err(RegisterEasingError);
+    }
+
+    let id = CustomEasing(registry.len() as u32);
+    registry.push(Entry {
+        // names are small and easings are never unregistered, so we keep them forever, which lets
+        // `Easing::name` keep returning `&'static str`s
+        name: Box::leak(String::from(name).into_boxed_str()),
+        curve: Arc::new(curve),
+    });
+
+    Ok(Easing::Custom(id))
+}
+
+/// The error returned when registering an easing under a name which is already taken, either by
+/// a built in easing or a custom one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegisterEasingError;
+
+impl core::fmt::Display for RegisterEasingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("an easing with that name already exists")
+    }
+}
+
+impl std::error::Error for RegisterEasingError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CustomEasing {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CustomEasing {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        Self::find(&name).ok_or_else(|| serde::de::Error::custom(format_args!("no custom easing named `{name}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Baked, CubicBezier, Tween};
+
+    #[test]
+    fn custom_dispatch() {
+        let mut easing = register_easing("test_square", |t| t * t).unwrap();
+
+        assert_eq!(Tween::<f32>::tween(&mut easing, 10.0, 0.5), 2.5);
+        assert_eq!("test_square".parse::<Easing>(), Ok(easing));
+        assert_eq!(easing.name(), Some("test_square"));
+    }
+
+    #[test]
+    fn names_are_unique() {
+        assert_eq!(register_easing("linear", |t| t), Err(RegisterEasingError));
+
+        register_easing("test_unique", |t| t).unwrap();
+        assert_eq!(register_easing("test_unique", |t| t), Err(RegisterEasingError));
+    }
+
+    #[test]
+    fn baked_table() {
+        let baked = Baked::<32>::new(CubicBezier::EASE_OUT);
+        let mut easing = register_easing("test_baked", move |t| {
+            let mut baked = baked;
+            baked.tween(1.0, t)
+        })
+        .unwrap();
+
+        assert_eq!(Tween::<f32>::tween(&mut easing, 1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn curves_run_unlocked() {
+        let mut easing = register_easing("test_reentrant", |t| {
+            let _ = register_easing("test_reentrant_inner", |t| t);
+            t
+        })
+        .unwrap();
+
+        assert_eq!(Tween::<f32>::tween(&mut easing, 1.0, 0.5), 0.5);
+        assert!(CustomEasing::find("test_reentrant_inner").is_some());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_by_name() {
+        let easing = register_easing("test_serde", |t| t).unwrap();
+
+        let json = serde_json::to_string(&easing).unwrap();
+        assert_eq!(json, r#"{"custom":"test_serde"}"#);
+        assert_eq!(serde_json::from_str::<Easing>(&json).unwrap(), easing);
+        assert!(serde_json::from_str::<Easing>(r#"{"custom":"test_missing"}"#).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! A C API, so that C and C++ engines can link against this crate as their easing library.
+//!
+//! Each value type gets an opaque tweener handle and a family of functions, like
+//! `tween_f32_new`, `tween_f32_advance`, and `tween_f32_free`. Easings are picked by the same
+//! names [Easing] parses, such as `"sine_in_out"`, and times are in whatever unit you like, as
+//! long as you're consistent. Every type here is laid out for `cbindgen`, which can generate a
+//! header using the `cbindgen.toml` at the root of this repository.
+//!
+//! To get a library to link against, build this crate with the `ffi` feature as a `staticlib` or
+//! `cdylib`, for example with `cargo rustc --release --features ffi --crate-type staticlib`.
+#![allow(unsafe_code)]
+
+use crate::{Easing, TweenValue, Tweener};
+use core::ffi::{CStr, c_char};
+use std::boxed::Box;
+
+/// A two dimensional vector, for tweening positions and sizes over FFI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TweenVec2 {
+    /// The x component.
+    pub x: f32,
+    /// The y component.
+    pub y: f32,
+}
+
+/// A three dimensional vector, for tweening positions and colors over FFI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TweenVec3 {
+    /// The x component.
+    pub x: f32,
+    /// The y component.
+    pub y: f32,
+    /// The z component.
+    pub z: f32,
+}
+
+/// A four dimensional vector, for tweening colors with alpha over FFI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TweenVec4 {
+    /// The x component.
+    pub x: f32,
+    /// The y component.
+    pub y: f32,
+    /// The z component.
+    pub z: f32,
+    /// The w component.
+    pub w: f32,
+}
+
+macro_rules! impl_ffi_vec {
+    ($($name:ident { $($field:ident),* }),* $(,)?) => {
+        $(
+            impl core::ops::Add for $name {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    Self { $($field: self.$field + rhs.$field),* }
+                }
+            }
+
+            impl core::ops::Sub for $name {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    Self { $($field: self.$field - rhs.$field),* }
+                }
+            }
+
+            impl TweenValue for $name {
+                fn scale(self, scale: f32) -> Self {
+                    Self { $($field: self.$field * scale),* }
+                }
+            }
+        )*
+    };
+}
+
+impl_ffi_vec!(TweenVec2 { x, y }, TweenVec3 { x, y, z }, TweenVec4 { x, y, z, w });
+
+/// Parses a C string into an [Easing], returning `None` if it's null, isn't UTF-8, or isn't the
+/// name of an easing.
+///
+/// # Safety
+///
+/// `easing` must be null or point to a nul-terminated string.
+unsafe fn parse_easing(easing: *const c_char) -> Option<Easing> {
+    if easing.is_null() {
+        return None;
+    }
+
+    // SAFETY: the caller promises this is a valid C string
+    let easing = unsafe { CStr::from_ptr(easing) };
+    easing.to_str().ok()?.parse().ok()
+}
+
+macro_rules! declare_ffi_tweener {
+    ($(
+        $handle:ident, $value:ty,
+        $new:ident, $advance:ident, $seek:ident, $is_finished:ident, $free:ident;
+    )*) => {
+        $(
+            #[doc = concat!("An opaque tweener over `", stringify!($value), "` values, made with [", stringify!($new), "].")]
+            #[derive(Debug, Clone)]
+            pub struct $handle(Tweener<$value, f32, Easing>);
+
+            #[doc = concat!("Creates a new [", stringify!($handle), "] from `start` to `end` over `duration`, using the easing called `easing`.")]
+            ///
+            /// Returns null if `easing` isn't the name of an easing. The handle must be freed
+            #[doc = concat!("with [", stringify!($free), "].")]
+            ///
+            /// # Safety
+            ///
+            /// `easing` must be null or point to a nul-terminated string.
+            #[no_mangle]
+            pub unsafe extern "C" fn $new(easing: *const c_char, start: $value, end: $value, duration: f32) -> *mut $handle {
+                // SAFETY: passed on from our caller
+                match unsafe { parse_easing(easing) } {
+                    Some(easing) => Box::into_raw(Box::new($handle(Tweener::new(start, end, duration, easing)))),
+                    None => core::ptr::null_mut(),
+                }
+            }
+
+            /// Moves the tweener forward by `delta`, returning the new value.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("`tweener` must be a live handle from [", stringify!($new), "].")]
+            #[no_mangle]
+            pub unsafe extern "C" fn $advance(tweener: *mut $handle, delta: f32) -> $value {
+                // SAFETY: the caller promises this is a live handle, which we made from a Box
+                let tweener = unsafe { &mut *tweener };
+                tweener.0.move_by(delta)
+            }
+
+            /// Moves the tweener to `time`, returning the new value.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("`tweener` must be a live handle from [", stringify!($new), "].")]
+            #[no_mangle]
+            pub unsafe extern "C" fn $seek(tweener: *mut $handle, time: f32) -> $value {
+                // SAFETY: the caller promises this is a live handle, which we made from a Box
+                let tweener = unsafe { &mut *tweener };
+                tweener.0.move_to(time)
+            }
+
+            /// Returns `true` once the tweener has reached its end.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("`tweener` must be a live handle from [", stringify!($new), "].")]
+            #[no_mangle]
+            pub unsafe extern "C" fn $is_finished(tweener: *const $handle) -> bool {
+                // SAFETY: the caller promises this is a live handle, which we made from a Box
+                let tweener = unsafe { &*tweener };
+                tweener.0.is_finished()
+            }
+
+            /// Frees a tweener. Passing null does nothing.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("`tweener` must be null or a live handle from [", stringify!($new), "], and it can't be used again afterwards.")]
+            #[no_mangle]
+            pub unsafe extern "C" fn $free(tweener: *mut $handle) {
+                if !tweener.is_null() {
+                    // SAFETY: the caller promises this is a live handle, which we made from a Box
+                    drop(unsafe { Box::from_raw(tweener) });
+                }
+            }
+        )*
+    };
+}
+
+declare_ffi_tweener! {
+    TweenerF32, f32, tween_f32_new, tween_f32_advance, tween_f32_seek, tween_f32_is_finished, tween_f32_free;
+    TweenerF64, f64, tween_f64_new, tween_f64_advance, tween_f64_seek, tween_f64_is_finished, tween_f64_free;
+    TweenerVec2, TweenVec2, tween_vec2_new, tween_vec2_advance, tween_vec2_seek, tween_vec2_is_finished, tween_vec2_free;
+    TweenerVec3, TweenVec3, tween_vec3_new, tween_vec3_advance, tween_vec3_seek, tween_vec3_is_finished, tween_vec3_free;
+    TweenerVec4, TweenVec4, tween_vec4_new, tween_vec4_advance, tween_vec4_seek, tween_vec4_is_finished, tween_vec4_free;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32_lifecycle() {
+        unsafe {
+            let tweener = tween_f32_new(c"linear".as_ptr(), 0.0, 100.0, 10.0);
+            assert!(!tweener.is_null());
+
+            assert_eq!(tween_f32_advance(tweener, 5.0), 50.0);
+            assert!(!tween_f32_is_finished(tweener));
+            assert_eq!(tween_f32_seek(tweener, 10.0), 100.0);
+            assert!(tween_f32_is_finished(tweener));
+
+            tween_f32_free(tweener);
+        }
+    }
+
+    #[test]
+    fn vectors() {
+        unsafe {
+            let start = TweenVec2 { x: 0.0, y: 10.0 };
+            let end = TweenVec2 { x: 10.0, y: 0.0 };
+            let tweener = tween_vec2_new(c"linear".as_ptr(), start, end, 2.0);
+
+            assert_eq!(tween_vec2_advance(tweener, 1.0), TweenVec2 { x: 5.0, y: 5.0 });
+            tween_vec2_free(tweener);
+        }
+    }
+
+    #[test]
+    fn bad_easings() {
+        unsafe {
+            assert!(tween_f64_new(c"wobbly".as_ptr(), 0.0, 1.0, 1.0).is_null());
+            assert!(tween_f64_new(core::ptr::null(), 0.0, 1.0, 1.0).is_null());
+            tween_f64_free(core::ptr::null_mut());
+        }
+    }
+}
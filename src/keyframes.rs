@@ -0,0 +1,314 @@
+use core::marker::PhantomData;
+
+use crate::{Tween, TweenTime, TweenValue};
+
+mod reloadable;
+#[cfg(feature = "alloc")]
+mod resample;
+#[cfg(feature = "script")]
+mod script;
+mod slaved;
+mod track_morph;
+pub use reloadable::{ReloadAnchor, ReloadableTimeline};
+#[cfg(feature = "script")]
+pub use script::{MAX_SCRIPT_KEYFRAMES, ParseScriptError, parse_script};
+pub use slaved::SlavedTimeline;
+pub use track_morph::TrackMorph;
+
+/// A single point in [Keyframes]: a `value` to reach at a given `time`, and the `tween` used to get
+/// there from the previous keyframe.
+///
+/// The `tween` of the first keyframe in a set is never used.
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keyframe<Value, Time, T> {
+    /// The time at which this keyframe is reached.
+    pub time: Time,
+
+    /// The value of this keyframe.
+    pub value: Value,
+
+    /// The tween used to reach this keyframe from the previous one.
+    pub tween: T,
+}
+
+impl<Value, Time, T> Keyframe<Value, Time, T> {
+    /// Creates a new [Keyframe].
+    pub fn new(time: Time, value: Value, tween: T) -> Self {
+        Self { time, value, tween }
+    }
+}
+
+/// Keyframes are a driver, like [Tweener], which moves through an ordered list of [Keyframe]s
+/// rather than a single `start` and `end`. Each segment uses the tween of the keyframe it is
+/// heading towards.
+///
+/// Keyframes are generic over their storage, which can be anything that can be viewed as a slice.
+/// Use an array (see [KeyframesArray]) in `no_std`, or a `Vec` (see [KeyframesVec]) when the
+/// number of keyframes isn't known up front.
+///
+/// Since every keyframe shares one tween type, mixing easings means erasing them, as with
+/// `Box<dyn Tween<Value>>`.
+///
+/// Like a [Tweener], Keyframes clamp: before the first keyframe, you'll get the first value, and
+/// after the last keyframe, you'll get the last value.
+///
+/// ```
+/// # use tween::{Keyframe, Keyframes, Linear};
+/// let mut keyframes = Keyframes::new([
+///     Keyframe::new(0, 0, Linear),
+///     Keyframe::new(10, 100, Linear),
+///     Keyframe::new(20, 50, Linear),
+/// ]);
+///
+/// assert_eq!(keyframes.move_to(5), 50);
+/// assert_eq!(keyframes.move_to(10), 100);
+/// assert_eq!(keyframes.move_to(15), 75);
+/// assert_eq!(keyframes.move_by(100), 50);
+/// assert!(keyframes.is_finished());
+/// ```
+///
+/// [Tweener]: crate::Tweener
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RawKeyframes<Value, Time, T, S>",
+        bound(deserialize = "Time: TweenTime + serde::Deserialize<'de>, \
+                             S: AsRef<[Keyframe<Value, Time, T>]> + serde::Deserialize<'de>")
+    )
+)]
+pub struct Keyframes<Value, Time, T, S> {
+    /// The current time of the Keyframes. You can change this value at will, or change it with
+    /// `move_by`.
+    pub current_time: Time,
+
+    keyframes: S,
+    _marker: PhantomData<fn() -> (Value, T)>,
+}
+
+/// [Keyframes] as they are deserialized, before they're checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawKeyframes<Value, Time, T, S> {
+    current_time: Time,
+    keyframes: S,
+    _marker: PhantomData<fn() -> (Value, T)>,
+}
+
+#[cfg(feature = "serde")]
+impl<Value, Time, T, S> TryFrom<RawKeyframes<Value, Time, T, S>> for Keyframes<Value, Time, T, S>
+where
+    Time: TweenTime,
+    S: AsRef<[Keyframe<Value, Time, T>]>,
+{
+    type Error = &'static str;
+
+    fn try_from(raw: RawKeyframes<Value, Time, T, S>) -> Result<Self, Self::Error> {
+        check_keyframes(raw.keyframes.as_ref())?;
+
+        Ok(Self {
+            current_time: raw.current_time,
+            keyframes: raw.keyframes,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Checks that `keys` are usable as [Keyframes]: there has to be at least one, and they have to
+/// be sorted by time.
+fn check_keyframes<Value, Time: TweenTime, T>(keys: &[Keyframe<Value, Time, T>]) -> Result<(), &'static str> {
+    if keys.is_empty() {
+        return Err("keyframes cannot be empty");
+    }
+    if !keys.windows(2).all(|w| w[0].time <= w[1].time) {
+        return Err("keyframes must be sorted by time");
+    }
+
+    Ok(())
+}
+
+/// [Keyframes] stored in an array, for `no_std` usage.
+pub type KeyframesArray<Value, Time, T, const N: usize> = Keyframes<Value, Time, T, [Keyframe<Value, Time, T>; N]>;
+
+/// [Keyframes] stored in a `Vec`.
+#[cfg(feature = "alloc")]
+pub type KeyframesVec<Value, Time, T> = Keyframes<Value, Time, T, alloc::vec::Vec<Keyframe<Value, Time, T>>>;
+
+impl<Value, Time, T, S> Keyframes<Value, Time, T, S>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+    S: AsRef<[Keyframe<Value, Time, T>]> + AsMut<[Keyframe<Value, Time, T>]>,
+{
+    /// Creates a new set of [Keyframes] at time `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keyframes` is empty, or if its keyframes are not sorted by time.
+    pub fn new(keyframes: S) -> Self {
+        Self::new_at(keyframes, Time::ZERO)
+    }
+
+    /// Creates a new set of [Keyframes] at the given time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keyframes` is empty, or if its keyframes are not sorted by time.
+    /// Deserializing checks the same things, but returns an error instead.
+    pub fn new_at(keyframes: S, current_time: Time) -> Self {
+        if let Err(reason) = check_keyframes(keyframes.as_ref()) {
+            panic!("{reason}");
+        }
+
+        Self {
+            current_time,
+            keyframes,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Moves the keyframes to a given time, returning the value there. Times before the first
+    /// keyframe or after the last keyframe are clamped.
+    pub fn move_to(&mut self, position: Time) -> Value {
+        self.current_time = position;
+
+        let keys = self.keyframes.as_mut();
+        let next = keys.partition_point(|k| k.time <= position);
+
+        if next == 0 {
+            return keys[0].value.clone();
+        }
+        if next == keys.len() {
+            return keys[next - 1].value.clone();
+        }
+
+        let (before, after) = keys.split_at_mut(next);
+        let (from, to) = (&before[next - 1], &mut after[0]);
+
+        let pct = (position - from.time).to_f32() / (to.time - from.time).to_f32();
+        to.tween.tween(to.value.clone() - from.value.clone(), pct) + from.value.clone()
+    }
+
+    /// Drives the keyframes forward X steps in time.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        self.current_time = self.current_time.saturating_add(delta);
+
+        self.move_to(self.current_time)
+    }
+
+    /// The time of the last keyframe.
+    pub fn duration(&self) -> Time {
+        self.keyframes.as_ref()[self.keyframes.as_ref().len() - 1].time
+    }
+
+    /// Returns `true` if the current time is at or after the first keyframe.
+    pub fn is_started(&self) -> bool {
+        self.current_time >= self.keyframes.as_ref()[0].time
+    }
+
+    /// Returns `true` if the current time is at or after the last keyframe.
+    pub fn is_finished(&self) -> bool {
+        self.current_time >= self.duration()
+    }
+
+    /// The keyframes, in order.
+    pub fn keyframes(&self) -> &[Keyframe<Value, Time, T>] {
+        self.keyframes.as_ref()
+    }
+
+    /// Consumes this, returning the underlying storage.
+    pub fn into_inner(self) -> S {
+        self.keyframes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn segments() {
+        use crate::QuadIn;
+
+        let mut keyframes = Keyframes::new([
+            Keyframe::new(0.0, 0.0, QuadIn),
+            Keyframe::new(2.0, 4.0, QuadIn),
+            Keyframe::new(4.0, 0.0, QuadIn),
+        ]);
+
+        assert_eq!(keyframes.move_to(-1.0), 0.0);
+        assert_eq!(keyframes.move_to(0.0), 0.0);
+        assert_eq!(keyframes.move_to(1.0), 1.0);
+        assert_eq!(keyframes.move_to(2.0), 4.0);
+        assert_eq!(keyframes.move_to(3.0), 3.0);
+        assert_eq!(keyframes.move_to(4.0), 0.0);
+        assert_eq!(keyframes.move_to(5.0), 0.0);
+    }
+
+    #[test]
+    fn single_keyframe() {
+        let mut keyframes = Keyframes::new([Keyframe::new(3, 7, Linear)]);
+
+        assert_eq!(keyframes.move_to(0), 7);
+        assert_eq!(keyframes.move_to(5), 7);
+        assert!(keyframes.is_finished());
+    }
+
+    #[test]
+    fn started_and_finished() {
+        let mut keyframes = Keyframes::new_at([Keyframe::new(0, 0, Linear), Keyframe::new(2, 2, Linear)], -1);
+        assert!(!keyframes.is_started());
+
+        keyframes.move_by(1);
+        assert!(keyframes.is_started());
+        assert!(!keyframes.is_finished());
+
+        keyframes.move_by(2);
+        assert!(keyframes.is_finished());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn erased_vec() {
+        use crate::{CubicBezier, Tween};
+        use alloc::{boxed::Box, vec};
+
+        let mut keyframes: KeyframesVec<f32, f32, Box<dyn Tween<f32>>> = Keyframes::new(vec![
+            Keyframe::new(0.0, 0.0, Box::new(Linear) as Box<dyn Tween<f32>>),
+            Keyframe::new(1.0, 10.0, Box::new(Linear)),
+            Keyframe::new(2.0, 0.0, Box::new(CubicBezier::EASE_OUT)),
+        ]);
+
+        assert_eq!(keyframes.move_to(0.5), 5.0);
+        assert_eq!(keyframes.move_to(2.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsorted() {
+        Keyframes::new([Keyframe::new(1, 0, Linear), Keyframe::new(0, 1, Linear)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_checks() {
+        let keyframes = Keyframes::new([Keyframe::new(0.0, 0.0, Linear), Keyframe::new(1.0, 2.0, Linear)]);
+        let json = serde_json::to_string(&keyframes).unwrap();
+        assert_eq!(
+            serde_json::from_str::<KeyframesArray<f32, f32, Linear, 2>>(&json).unwrap(),
+            keyframes
+        );
+
+        let empty = r#"{"current_time":0.0,"keyframes":[],"_marker":null}"#;
+        assert!(serde_json::from_str::<KeyframesArray<f32, f32, Linear, 0>>(empty).is_err());
+
+        let unsorted = json.replace("1.0", "-1.0");
+        assert!(serde_json::from_str::<KeyframesArray<f32, f32, Linear, 2>>(&unsorted).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{Keyframe, Keyframes, Tween, TweenTime, TweenValue};
+
+/// How a [ReloadableTimeline] carries its playback position over to newly loaded keyframes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReloadAnchor {
+    /// Keeps the same absolute time. This is right when only values or easings changed.
+    Time,
+
+    /// Keeps the same progress through the whole timeline, from its first keyframe to its last.
+    /// This is right when the timeline was stretched or squashed.
+    #[default]
+    Progress,
+
+    /// Keeps the same progress between the same two keyframes, treating each keyframe as a
+    /// marker. This is right when individual segments were retimed.
+    Keyframe,
+}
+
+/// A ReloadableTimeline is a set of [Keyframes] which can have new keyframe data swapped in
+/// while it plays, keeping its place according to a [ReloadAnchor].
+///
+/// This is for hot-reloading animation files: a designer can tweak a curve and see it straight
+/// away, without restarting the game or the animation. It derefs to its [Keyframes], so it moves
+/// and samples just like them.
+///
+/// ```
+/// # use tween::{Keyframe, Linear, ReloadAnchor, ReloadableTimeline};
+/// let mut timeline = ReloadableTimeline::new(vec![
+///     Keyframe::new(0.0, 0.0, Linear),
+///     Keyframe::new(10.0, 100.0, Linear),
+/// ]);
+/// assert_eq!(timeline.move_to(5.0), 50.0);
+///
+/// // the designer made the animation twice as long...
+/// let edited = vec![Keyframe::new(0.0, 0.0, Linear), Keyframe::new(20.0, 100.0, Linear)];
+/// timeline.reload(edited, ReloadAnchor::Progress).unwrap();
+///
+/// // ...and we're still halfway through it
+/// assert_eq!(timeline.current_time, 10.0);
+/// assert_eq!(timeline.move_by(0.0), 50.0);
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "Keyframes<Value, Time, T, S>: serde::Deserialize<'de>"))
+)]
+pub struct ReloadableTimeline<Value, Time, T, S> {
+    /// The keyframes which are currently playing.
+    pub keyframes: Keyframes<Value, Time, T, S>,
+}
+
+impl<Value, Time, T, S> ReloadableTimeline<Value, Time, T, S>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+    S: AsRef<[Keyframe<Value, Time, T>]> + AsMut<[Keyframe<Value, Time, T>]>,
+{
+    /// Creates a new ReloadableTimeline at time `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keyframes` is empty, or if its keyframes are not sorted by time, just like
+    /// [Keyframes::new].
+    pub fn new(keyframes: S) -> Self {
+        Self {
+            keyframes: Keyframes::new(keyframes),
+        }
+    }
+
+    /// Swaps in new keyframe data, moving to the matching place in it, and returns the old data.
+    ///
+    /// If the new keyframes are empty or aren't sorted by time, nothing changes, and they are
+    /// handed back as an `Err`. A half-edited animation file won't stop the old one from playing.
+    pub fn reload(&mut self, keyframes: S, anchor: ReloadAnchor) -> Result<S, S> {
+        let keys = keyframes.as_ref();
+        if keys.is_empty() || !keys.windows(2).all(|w| w[0].time <= w[1].time) {
+            return Err(keyframes);
+        }
+
+        let current_time = self.keyframes.current_time;
+        let time = match anchor {
+            ReloadAnchor::Time => current_time,
+            ReloadAnchor::Progress => {
+                let progress = Self::progress(self.keyframes.keyframes(), current_time);
+                let (first, last) = (keys[0].time.to_f32(), keys[keys.len() - 1].time.to_f32());
+
+                Time::from_f32(first + (last - first) * progress)
+            }
+            ReloadAnchor::Keyframe => Self::by_keyframe(self.keyframes.keyframes(), keys, current_time),
+        };
+
+        let old = core::mem::replace(&mut self.keyframes, Keyframes::new_at(keyframes, time));
+        Ok(old.into_inner())
+    }
+
+    /// Consumes this, returning the keyframes which are currently playing.
+    pub fn into_inner(self) -> Keyframes<Value, Time, T, S> {
+        self.keyframes
+    }
+
+    /// How far `time` is from the first keyframe to the last, where `0.0` is the first and `1.0`
+    /// is the last. This isn't clamped, so times outside of the keyframes stay outside.
+    fn progress(keys: &[Keyframe<Value, Time, T>], time: Time) -> f32 {
+        let first = keys[0].time.to_f32();
+        let span = keys[keys.len() - 1].time.to_f32() - first;
+
+        if span <= 0.0 {
+            0.0
+        } else {
+            (time.to_f32() - first) / span
+        }
+    }
+
+    /// Finds the time in `new` which is as far between the same two keyframes as `time` is in
+    /// `old`. Times before the first keyframe or after the last keep their distance from it.
+    fn by_keyframe(old: &[Keyframe<Value, Time, T>], new: &[Keyframe<Value, Time, T>], time: Time) -> Time {
+        let (old_first, old_last) = (old[0].time, old[old.len() - 1].time);
+        let (new_first, new_last) = (new[0].time, new[new.len() - 1].time);
+
+        if time <= old_first {
+            return Time::from_f32(new_first.to_f32() + (time.to_f32() - old_first.to_f32()));
+        }
+        if time >= old_last {
+            return Time::from_f32(new_last.to_f32() + (time.to_f32() - old_last.to_f32()));
+        }
+
+        // we're strictly inside the old keyframes, so this is a real segment
+        let segment = old.partition_point(|k| k.time <= time) - 1;
+        let (from, to) = (old[segment].time.to_f32(), old[segment + 1].time.to_f32());
+        let fraction = (time.to_f32() - from) / (to - from);
+
+        match new.get(segment..=segment + 1) {
+            Some([from, to]) => {
+                let (from, to) = (from.time.to_f32(), to.time.to_f32());
+                Time::from_f32(from + (to - from) * fraction)
+            }
+            // the new keyframes don't have this segment, so we go to their end
+            _ => new_last,
+        }
+    }
+}
+
+impl<Value, Time, T, S> core::ops::Deref for ReloadableTimeline<Value, Time, T, S> {
+    type Target = Keyframes<Value, Time, T, S>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.keyframes
+    }
+}
+
+impl<Value, Time, T, S> core::ops::DerefMut for ReloadableTimeline<Value, Time, T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.keyframes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    fn keys(times: [f32; 3]) -> [Keyframe<f32, f32, Linear>; 3] {
+        [
+            Keyframe::new(times[0], 0.0, Linear),
+            Keyframe::new(times[1], 10.0, Linear),
+            Keyframe::new(times[2], 20.0, Linear),
+        ]
+    }
+
+    #[test]
+    fn anchors() {
+        let mut timeline = ReloadableTimeline::new(keys([0.0, 2.0, 10.0]));
+        timeline.move_to(1.0);
+
+        let old = timeline.reload(keys([0.0, 4.0, 6.0]), ReloadAnchor::Time).unwrap();
+        assert_eq!(old, keys([0.0, 2.0, 10.0]));
+        assert_eq!(timeline.current_time, 1.0);
+
+        timeline.reload(keys([0.0, 2.0, 12.0]), ReloadAnchor::Progress).unwrap();
+        assert_eq!(timeline.current_time, 2.0);
+
+        // halfway between the first two keyframes
+        timeline.move_to(1.0);
+        timeline.reload(keys([0.0, 8.0, 12.0]), ReloadAnchor::Keyframe).unwrap();
+        assert_eq!(timeline.current_time, 4.0);
+        assert_eq!(timeline.move_by(0.0), 5.0);
+    }
+
+    #[test]
+    fn past_the_ends() {
+        let mut timeline = ReloadableTimeline::new(keys([0.0, 1.0, 2.0]));
+
+        timeline.move_to(3.0);
+        timeline.reload(keys([0.0, 1.0, 5.0]), ReloadAnchor::Keyframe).unwrap();
+        assert_eq!(timeline.current_time, 6.0);
+
+        timeline.move_to(-1.0);
+        timeline.reload(keys([1.0, 2.0, 3.0]), ReloadAnchor::Keyframe).unwrap();
+        assert_eq!(timeline.current_time, 0.0);
+    }
+
+    #[test]
+    fn rejects_bad_data() {
+        let mut timeline = ReloadableTimeline::new(keys([0.0, 1.0, 2.0]));
+        timeline.move_to(0.5);
+
+        let bad = keys([0.0, 3.0, 2.0]);
+        assert_eq!(timeline.reload(bad, ReloadAnchor::Progress), Err(bad));
+        assert_eq!(timeline.keyframes(), &keys([0.0, 1.0, 2.0]));
+        assert_eq!(timeline.current_time, 0.5);
+    }
+}
@@ -0,0 +1,216 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Keyframe, Keyframes, KeyframesVec, Linear, Tween, TweenLength, TweenTime, TweenValue};
+
+impl<Value, Time, T, S> Keyframes<Value, Time, T, S>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+    S: AsRef<[Keyframe<Value, Time, T>]> + AsMut<[Keyframe<Value, Time, T>]>,
+{
+    /// Samples these keyframes at `count` evenly spaced times, from the first keyframe to the last,
+    /// into a new set of [Linear] keyframes.
+    ///
+    /// This is for turning a curve with uneven or awkward keys into a plain, regular one, which can
+    /// then be [simplified](Self::simplify). Anything that happens between samples is lost, so
+    /// pick a `count` high enough to catch the sharpest motion. A `count` below `2` is treated as
+    /// `2`, unless there's only one keyframe to begin with.
+    ///
+    /// ```
+    /// # #[cfg(feature = "quad")] {
+    /// # use tween::{Keyframe, Keyframes, QuadIn};
+    /// let mut curve = Keyframes::new([Keyframe::new(0.0, 0.0, QuadIn), Keyframe::new(4.0, 16.0, QuadIn)]);
+    /// let resampled = curve.resample(5);
+    ///
+    /// let values: Vec<f32> = resampled.keyframes().iter().map(|k| k.value).collect();
+    /// assert_eq!(values, [0.0, 1.0, 4.0, 9.0, 16.0]);
+    /// # }
+    /// ```
+    pub fn resample(&mut self, count: usize) -> KeyframesVec<Value, Time, Linear> {
+        let current_time = self.current_time;
+        let keys = self.keyframes();
+        let (first, last) = (keys[0].time, keys[keys.len() - 1].time);
+
+        let resampled = if keys.len() == 1 {
+            vec![Keyframe::new(first, keys[0].value.clone(), Linear)]
+        } else {
+            let count = count.max(2);
+            let (start, span) = (first.to_f32(), (last - first).to_f32());
+
+            (0..count)
+                .map(|i| {
+                    let time = match i {
+                        0 => first,
+                        i if i == count - 1 => last,
+                        i => Time::from_f32(start + span * i as f32 / (count - 1) as f32),
+                    };
+
+                    Keyframe::new(time, self.move_to(time), Linear)
+                })
+                .collect()
+        };
+
+        self.current_time = current_time;
+        Keyframes::new(resampled)
+    }
+
+    /// Drops every keyframe which can be left out without the curve moving more than `tolerance`
+    /// away from it, using the Ramer-Douglas-Peucker algorithm, and returns the ones which are
+    /// left. The first and last keyframes are always kept.
+    ///
+    /// The error is measured at each dropped keyframe's time, as the
+    /// [length](TweenLength::length) of the difference between its value and the value of the
+    /// simplified curve there. Kept keyframes keep their tweens, so a dense, [resampled](Self::resample)
+    /// curve simplifies into one with the same tween all the way along.
+    ///
+    /// ```
+    /// # use tween::{Keyframe, Keyframes, Linear};
+    /// // a straight line with a little noise, and one real corner
+    /// let curve = Keyframes::new([
+    ///     Keyframe::new(0.0, 0.0, Linear),
+    ///     Keyframe::new(1.0, 1.01, Linear),
+    ///     Keyframe::new(2.0, 2.0, Linear),
+    ///     Keyframe::new(3.0, 0.0, Linear),
+    /// ]);
+    /// let simple = curve.simplify(0.1);
+    ///
+    /// let times: Vec<f32> = simple.keyframes().iter().map(|k| k.time).collect();
+    /// assert_eq!(times, [0.0, 2.0, 3.0]);
+    /// ```
+    pub fn simplify(&self, tolerance: f32) -> KeyframesVec<Value, Time, T>
+    where
+        Value: TweenLength,
+        T: Clone,
+    {
+        let keys = self.keyframes();
+        let mut kept = vec![false; keys.len()];
+        kept[0] = true;
+        kept[keys.len() - 1] = true;
+
+        let mut segments: Vec<(usize, usize)> = vec![(0, keys.len() - 1)];
+        while let Some((from, to)) = segments.pop() {
+            let mut worst = None;
+            let mut worst_error = tolerance;
+
+            for index in from + 1..to {
+                let error =
+                    (Self::span_value(&keys[from], &keys[to], keys[index].time) - keys[index].value.clone()).length();
+                if error > worst_error {
+                    worst = Some(index);
+                    worst_error = error;
+                }
+            }
+
+            if let Some(index) = worst {
+                kept[index] = true;
+                segments.push((from, index));
+                segments.push((index, to));
+            }
+        }
+
+        let simplified: Vec<_> = keys
+            .iter()
+            .zip(kept)
+            .filter(|(_, kept)| *kept)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        Keyframes::new(simplified)
+    }
+
+    /// The value at `time` of a segment which runs straight from `from` to `to`.
+    fn span_value(from: &Keyframe<Value, Time, T>, to: &Keyframe<Value, Time, T>, time: Time) -> Value
+    where
+        T: Clone,
+    {
+        let span = (to.time - from.time).to_f32();
+        let pct = if span > 0.0 {
+            (time - from.time).to_f32() / span
+        } else {
+            1.0
+        };
+
+        to.tween.clone().tween(to.value.clone() - from.value.clone(), pct) + from.value.clone()
+    }
+}
+
+#[cfg(any(feature = "sine", feature = "quad"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "quad")]
+    use crate::QuadIn;
+    #[cfg(feature = "sine")]
+    use crate::SineInOut;
+    #[cfg(feature = "sine")]
+    use approx::assert_relative_eq;
+
+    #[cfg(feature = "sine")]
+    #[test]
+    fn resample_matches_original() {
+        let mut original = Keyframes::new(vec![
+            Keyframe::new(1.0, 0.0, SineInOut),
+            Keyframe::new(3.0, 10.0, SineInOut),
+            Keyframe::new(4.0, -5.0, SineInOut),
+        ]);
+        original.move_to(2.5);
+
+        let mut resampled = original.resample(31);
+        assert_eq!(original.current_time, 2.5);
+        assert_eq!(resampled.keyframes().len(), 31);
+        assert_eq!(resampled.keyframes()[0].time, 1.0);
+        assert_eq!(resampled.duration(), 4.0);
+
+        for i in 0..=30 {
+            let t = 1.0 + i as f32 / 10.0;
+            assert_relative_eq!(resampled.move_to(t), original.move_to(t), epsilon = 1e-4);
+        }
+
+        let mut single = Keyframes::new([Keyframe::new(2, 5, Linear)]);
+        assert_eq!(single.resample(10).keyframes(), [Keyframe::new(2, 5, Linear)]);
+        assert_eq!(
+            Keyframes::new([Keyframe::new(0, 0, Linear), Keyframe::new(4, 8, Linear)])
+                .resample(0)
+                .keyframes()
+                .len(),
+            2
+        );
+    }
+
+    #[cfg(feature = "sine")]
+    #[test]
+    fn simplify_stays_within_tolerance() {
+        let mut dense = Keyframes::new([
+            Keyframe::new(0.0f32, 0.0f32, SineInOut),
+            Keyframe::new(1.0, 10.0, SineInOut),
+            Keyframe::new(2.0, 0.0, SineInOut),
+        ])
+        .resample(201);
+
+        let mut simple = dense.simplify(0.05);
+        let kept = simple.keyframes().len();
+        assert!(kept < 40, "kept {kept} keyframes");
+
+        for i in 0..=200 {
+            let t = i as f32 / 100.0;
+            assert!((simple.move_to(t) - dense.move_to(t)).abs() <= 0.05);
+        }
+
+        // tighter tolerances keep more
+        assert!(dense.simplify(0.001).keyframes().len() > kept);
+        assert_eq!(dense.simplify(100.0).keyframes().len(), 2);
+    }
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn simplify_uses_kept_tweens() {
+        // every point lies on one QuadIn curve, so only the ends are needed
+        let keys: Vec<_> = (0..=10)
+            .map(|i| Keyframe::new(i as f32, (i * i) as f32, QuadIn))
+            .collect();
+        let simple = Keyframes::new(keys).simplify(1e-3);
+
+        assert_eq!(simple.keyframes().len(), 2);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{Easing, Keyframe, Keyframes, KeyframesVec};
+use alloc::vec;
+
+/// Compiles a small animation script into [Keyframes], for quick iteration, or for animations
+/// kept in data files, where a full timeline would be more than is needed.
+///
+/// A script is a list of statements, separated by `;` or new lines, which run from `start` at
+/// time `0.0`:
+///
+/// - `to <value> over <duration> [ease <easing>]` tweens to `value`. Without an `ease`, the tween
+///   is linear. Easings are named as they are for [Easing], like `quad_out`
+/// - `by <delta> over <duration> [ease <easing>]` tweens by `delta` from the current value
+/// - `wait <duration>` holds the current value
+/// - `from <value>` jumps straight to `value`
+/// - `repeat <count>` plays everything before it `count` times in all, jumping back to the
+///   beginning between each
+///
+/// Durations are in seconds, and can be written as `0.5`, `0.5s` or `500ms`. Empty statements
+/// are ignored.
+///
+/// Repeats multiply, so a script which would compile to more than [MAX_SCRIPT_KEYFRAMES]
+/// keyframes is an error, rather than something to allocate for.
+///
+/// ```
+/// # #[cfg(all(feature = "quad", feature = "bounce"))] {
+/// # use tween::parse_script;
+/// let mut bounce = parse_script(
+///     "to 100 over 0.5s ease quad_out; wait 0.2s; to 0 over 300ms ease bounce_out; repeat 3",
+///     0.0,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(bounce.duration(), 3.0);
+/// assert_eq!(bounce.move_to(0.6), 100.0);
+/// assert_eq!(bounce.move_to(1.0), 0.0);
+/// assert_eq!(bounce.move_to(1.6), 100.0);
+/// # }
+/// ```
+pub fn parse_script(script: &str, start: f32) -> Result<KeyframesVec<f32, f32, Easing>, ParseScriptError> {
+    let mut keys = vec![Keyframe::new(0.0, start, Easing::Linear)];
+    let (mut time, mut value) = (0.0f32, start);
+
+    for (index, statement) in script.split([';', '\n']).enumerate() {
+        let error = |reason| ParseScriptError {
+            statement: index + 1,
+            reason,
+        };
+        let mut words = statement.split_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+
+        match command {
+            "to" | "by" => {
+                let amount = number(words.next()).ok_or(error("expected a value"))?;
+                if words.next() != Some("over") {
+                    return Err(error("expected `over` and a duration"));
+                }
+                let duration = duration(words.next()).ok_or(error("expected a duration"))?;
+                let easing = match words.next() {
+                    Some("ease") => words
+                        .next()
+                        .and_then(|name| name.parse().ok())
+                        .ok_or(error("expected the name of an easing"))?,
+                    Some(_) => return Err(error("expected `ease`, or the end of the statement")),
+                    None => Easing::Linear,
+                };
+
+                value = if command == "to" { amount } else { value + amount };
+                time += duration;
+                keys.push(Keyframe::new(time, value, easing));
+            }
+            "wait" => {
+                time += duration(words.next()).ok_or(error("expected a duration"))?;
+                keys.push(Keyframe::new(time, value, Easing::Linear));
+            }
+            "from" => {
+                value = number(words.next()).ok_or(error("expected a value"))?;
+                keys.push(Keyframe::new(time, value, Easing::Linear));
+            }
+            "repeat" => {
+                let count: usize = words
+                    .next()
+                    .and_then(|count| count.parse().ok())
+                    .filter(|&count| count > 0)
+                    .ok_or(error("expected a count of at least 1"))?;
+                if keys
+                    .len()
+                    .checked_mul(count)
+                    .is_none_or(|total| total > MAX_SCRIPT_KEYFRAMES)
+                {
+                    return Err(error("too many keyframes"));
+                }
+
+                let body = keys.clone();
+                for lap in 1..count {
+                    let offset = time * lap as f32;
+                    for key in &body {
+                        // rounding mustn't let a lap start before the last one finished
+                        let at = (offset + key.time).max(keys[keys.len() - 1].time);
+                        keys.push(Keyframe::new(at, key.value, key.tween));
+                    }
+                }
+                time = keys[keys.len() - 1].time;
+            }
+            _ => return Err(error("expected `to`, `by`, `wait`, `from` or `repeat`")),
+        }
+
+        if words.next().is_some() {
+            return Err(error("expected the end of the statement"));
+        }
+    }
+
+    Ok(Keyframes::new(keys))
+}
+
+/// The most keyframes [parse_script] will compile a script into.
+pub const MAX_SCRIPT_KEYFRAMES: usize = 1 << 16;
+
+/// Parses a finite number.
+fn number(word: Option<&str>) -> Option<f32> {
+    word?.parse().ok().filter(|number: &f32| number.is_finite())
+}
+
+/// Parses a duration in seconds, which can have an `s` or `ms` suffix, and can't be negative.
+fn duration(word: Option<&str>) -> Option<f32> {
+    let word = word?;
+    let seconds = match word.strip_suffix("ms") {
+        Some(millis) => number(Some(millis))? / 1000.0,
+        None => number(Some(word.strip_suffix('s').unwrap_or(word)))?,
+    };
+
+    (seconds >= 0.0).then_some(seconds)
+}
+
+/// The error returned when a script given to [parse_script] can't be compiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParseScriptError {
+    /// Which statement was wrong, counting from `1`. Empty statements are counted too.
+    pub statement: usize,
+
+    /// What was wrong with it.
+    pub reason: &'static str,
+}
+
+impl core::fmt::Display for ParseScriptError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "in statement {}: {}", self.statement, self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseScriptError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn compiles_statements() {
+        let mut keyframes = parse_script("from 10\nby 5 over 2; wait 500ms;; to -1 over 1s ease linear", 0.0).unwrap();
+
+        assert_eq!(keyframes.duration(), 3.5);
+        assert_eq!(keyframes.move_to(0.0), 10.0);
+        assert_eq!(keyframes.move_to(1.0), 12.5);
+        assert_eq!(keyframes.move_to(2.25), 15.0);
+        assert_relative_eq!(keyframes.move_to(3.0), 7.0);
+        assert_eq!(keyframes.keyframes()[4].tween, Easing::Linear);
+    }
+
+    #[test]
+    fn repeats_nest() {
+        let mut keyframes = parse_script("to 1 over 1; repeat 2; to 0 over 1; repeat 2", 0.0).unwrap();
+
+        assert_eq!(keyframes.duration(), 6.0);
+        for (time, value) in [(0.5, 0.5), (1.5, 0.5), (2.5, 0.5), (3.5, 0.5), (4.5, 0.5), (5.5, 0.5)] {
+            assert_eq!(keyframes.move_to(time), value);
+        }
+        assert_eq!(keyframes.move_to(3.0), 0.0);
+    }
+
+    #[test]
+    fn points_at_mistakes() {
+        for (script, statement) in [
+            ("jump 10", 1),
+            ("to 10", 1),
+            ("to 10 over 1; to 5 over -1s", 2),
+            ("wait 1; ; to 5 over 1 ease wobbly", 3),
+            ("to 5 over 1 quad_in", 1),
+            ("wait 1 2", 1),
+            ("to 5 over 1; repeat 0", 2),
+            ("from nan", 1),
+            ("to 1 over 1; repeat 4000000000", 2),
+            ("to 1 over 1; repeat 1000; repeat 1000", 3),
+        ] {
+            assert_eq!(parse_script(script, 0.0).unwrap_err().statement, statement, "{script}");
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Keyframe, Keyframes, Tween, TweenTime, TweenValue};
+
+/// A SlavedTimeline is a set of [Keyframes] which doesn't keep its own time, but follows a clock
+/// owned by something else, like an audio engine's sample clock, a video's presentation time, or
+/// a server's time.
+///
+/// Rather than being moved by deltas, which would drift from the clock it follows, it is told
+/// what time the external clock reads with [follow](Self::follow), and puts itself at the
+/// matching place. The `origin` is the external time at which the timeline's time `0` happens.
+///
+/// External clocks can jump backwards, when their media is seeked or looped, or when a server
+/// corrects itself. The timeline simply follows, and reports it with
+/// [seeked_back](Self::seeked_back), so anything which builds up state from frame to frame
+/// (like particles or trails) knows to start over.
+///
+/// It derefs to its [Keyframes], but only immutably, since moving them directly would put them out
+/// of step with the clock.
+///
+/// ```
+/// # use tween::{Keyframe, Linear, SlavedTimeline};
+/// // the song started at 1000ms, and the animation follows it
+/// let mut timeline = SlavedTimeline::new(
+///     [Keyframe::new(0, 0, Linear), Keyframe::new(500, 100, Linear)],
+///     1000u64,
+/// );
+///
+/// assert_eq!(timeline.follow(1250), 50);
+/// assert!(!timeline.seeked_back());
+///
+/// // the player scrubbed back towards the start of the song
+/// assert_eq!(timeline.follow(1100), 20);
+/// assert!(timeline.seeked_back());
+///
+/// // before the origin, we wait at the first keyframe
+/// assert_eq!(timeline.follow(200), 0);
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "Time: serde::Deserialize<'de>, Keyframes<Value, Time, T, S>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct SlavedTimeline<Value, Time, T, S> {
+    /// The external time at which the timeline's time `0` happens.
+    pub origin: Time,
+
+    keyframes: Keyframes<Value, Time, T, S>,
+    last_external: Option<Time>,
+    seeked_back: bool,
+}
+
+impl<Value, Time, T, S> SlavedTimeline<Value, Time, T, S>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+    S: AsRef<[Keyframe<Value, Time, T>]> + AsMut<[Keyframe<Value, Time, T>]>,
+{
+    /// Creates a new SlavedTimeline, whose time `0` is at `origin` on the external clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keyframes` is empty, or if its keyframes are not sorted by time, just like
+    /// [Keyframes::new].
+    pub fn new(keyframes: S, origin: Time) -> Self {
+        Self {
+            origin,
+            keyframes: Keyframes::new(keyframes),
+            last_external: None,
+            seeked_back: false,
+        }
+    }
+
+    /// Moves the timeline to match the external clock reading `external`, and returns the value
+    /// there.
+    pub fn follow(&mut self, external: Time) -> Value {
+        self.seeked_back = matches!(self.last_external, Some(last) if external < last);
+        self.last_external = Some(external);
+
+        let local = self.local_time(external);
+        self.keyframes.move_to(local)
+    }
+
+    /// Returns `true` if the external clock went backwards on the last call to
+    /// [follow](Self::follow).
+    #[inline]
+    pub fn seeked_back(&self) -> bool {
+        self.seeked_back
+    }
+
+    /// The last time the external clock was read at, if it has been followed yet.
+    #[inline]
+    pub fn last_external(&self) -> Option<Time> {
+        self.last_external
+    }
+
+    /// Converts a reading of the external clock into the timeline's own time.
+    ///
+    /// Readings before the `origin` become negative times, or, for times which can't be
+    /// negative, [ZERO](TweenTime::ZERO).
+    pub fn local_time(&self, external: Time) -> Time {
+        if external >= self.origin {
+            external - self.origin
+        } else {
+            // going through `f32` keeps unsigned times from underflowing
+            Time::from_f32(external.to_f32() - self.origin.to_f32())
+        }
+    }
+
+    /// Moves the timeline's time `0` to `origin` on the external clock, such as when the media it
+    /// follows is restarted. This forgets the last external time, so the next
+    /// [follow](Self::follow) won't count as seeking back.
+    pub fn rebase(&mut self, origin: Time) {
+        self.origin = origin;
+        self.last_external = None;
+        self.seeked_back = false;
+    }
+
+    /// Consumes this, returning its keyframes.
+    pub fn into_inner(self) -> Keyframes<Value, Time, T, S> {
+        self.keyframes
+    }
+}
+
+impl<Value, Time, T, S> core::ops::Deref for SlavedTimeline<Value, Time, T, S> {
+    type Target = Keyframes<Value, Time, T, S>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.keyframes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    use core::time::Duration;
+
+    #[test]
+    fn follows_seeks_and_loops() {
+        let mut timeline =
+            SlavedTimeline::new([Keyframe::new(0.0, 0.0, Linear), Keyframe::new(4.0, 8.0, Linear)], 10.0);
+
+        assert_eq!(timeline.follow(12.0), 4.0);
+        assert_eq!(timeline.follow(15.0), 8.0);
+        assert!(timeline.is_finished());
+
+        // the media looped
+        assert_eq!(timeline.follow(10.5), 1.0);
+        assert!(timeline.seeked_back());
+        assert!(!timeline.is_finished());
+
+        assert_eq!(timeline.follow(11.0), 2.0);
+        assert!(!timeline.seeked_back());
+    }
+
+    #[test]
+    fn durations_before_origin() {
+        let keys = [
+            Keyframe::new(Duration::ZERO, 0, Linear),
+            Keyframe::new(Duration::from_secs(2), 10, Linear),
+        ];
+        let mut timeline = SlavedTimeline::new(keys, Duration::from_secs(5));
+
+        assert_eq!(timeline.follow(Duration::from_secs(1)), 0);
+        assert_eq!(timeline.current_time, Duration::ZERO);
+        assert_eq!(timeline.follow(Duration::from_secs(6)), 5);
+    }
+
+    #[test]
+    fn rebase_forgets_the_clock() {
+        let mut timeline = SlavedTimeline::new([Keyframe::new(0, 0, Linear), Keyframe::new(10, 10, Linear)], 100);
+        timeline.follow(105);
+
+        timeline.rebase(0);
+        assert_eq!(timeline.last_external(), None);
+        assert_eq!(timeline.follow(3), 3);
+        assert!(!timeline.seeked_back());
+    }
+}
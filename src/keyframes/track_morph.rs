@@ -0,0 +1,237 @@
+use core::marker::PhantomData;
+
+use crate::{Keyframe, Tween, TweenTime, TweenValue};
+
+/// A TrackMorph plays two sets of keyframes with the same shape at once, like a calm walk and an
+/// injured walk, and mixes them together with a `weight`.
+///
+/// Both the values and the times of matching keyframes are blended, so a limp which hits its
+/// keys later than the calm walk does drifts towards those later times as the weight rises. The
+/// tweens between keyframes are blended like a [Blend](crate::Blend). Changing the `weight` while
+/// it plays moves smoothly from one variant to the other, with no need to line their clocks up.
+///
+/// Like [Keyframes](crate::Keyframes), a TrackMorph clamps before its first keyframe and after its
+/// last.
+///
+/// ```
+/// # use tween::{Keyframe, Linear, TrackMorph};
+/// let calm = [Keyframe::new(0.0, 0.0, Linear), Keyframe::new(10.0, 100.0, Linear)];
+/// let injured = [Keyframe::new(0.0, 0.0, Linear), Keyframe::new(20.0, 50.0, Linear)];
+/// let mut walk = TrackMorph::new(calm, injured, 0.0);
+///
+/// assert_eq!(walk.move_to(5.0), 50.0);
+///
+/// // halfway to injured, the last key is at 15.0 and 75.0
+/// walk.weight = 0.5;
+/// assert_eq!(walk.duration(), 15.0);
+/// assert_eq!(walk.move_to(15.0), 75.0);
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackMorph<Value, Time, T, S> {
+    /// The current time of the TrackMorph. You can change this value at will, or change it with
+    /// `move_by`.
+    pub current_time: Time,
+
+    /// How much of track `b` is in the mix, from `0.0` to `1.0`.
+    pub weight: f32,
+
+    a: S,
+    b: S,
+    _marker: PhantomData<fn() -> (Value, T)>,
+}
+
+impl<Value, Time, T, S> TrackMorph<Value, Time, T, S>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+    S: AsRef<[Keyframe<Value, Time, T>]> + AsMut<[Keyframe<Value, Time, T>]>,
+{
+    /// Creates a new TrackMorph at time `0`, mixing `weight` of `b` into `a`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either track is empty or not sorted by time, or if the tracks don't have the same
+    /// number of keyframes.
+    pub fn new(a: S, b: S, weight: f32) -> Self {
+        let (keys_a, keys_b) = (a.as_ref(), b.as_ref());
+        assert!(!keys_a.is_empty(), "keyframes cannot be empty");
+        assert_eq!(
+            keys_a.len(),
+            keys_b.len(),
+            "tracks must have the same number of keyframes"
+        );
+        assert!(
+            [keys_a, keys_b]
+                .iter()
+                .all(|keys| keys.windows(2).all(|w| w[0].time <= w[1].time)),
+            "keyframes must be sorted by time"
+        );
+
+        Self {
+            current_time: Time::ZERO,
+            weight,
+            a,
+            b,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Moves the TrackMorph to a given time, returning the mixed value there. Times before the
+    /// first keyframe or after the last keyframe are clamped.
+    pub fn move_to(&mut self, position: Time) -> Value {
+        self.current_time = position;
+
+        let weight = self.weight;
+        let position = position.to_f32();
+        let len = self.a.as_ref().len();
+        let next = (0..len).position(|i| self.time_at(i) > position).unwrap_or(len);
+
+        if next == 0 {
+            return self.value_at(0);
+        }
+        if next == len {
+            return self.value_at(len - 1);
+        }
+
+        let (start, end) = (self.time_at(next - 1), self.time_at(next));
+        let pct = (position - start) / (end - start);
+
+        let from = self.value_at(next - 1);
+        let value_delta = self.value_at(next) - from.clone();
+        let a = self.a.as_mut()[next].tween.tween(value_delta.clone(), pct);
+        let b = self.b.as_mut()[next].tween.tween(value_delta, pct);
+
+        a.scale(1.0 - weight) + b.scale(weight) + from
+    }
+
+    /// Drives the TrackMorph forward X steps in time.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        self.current_time = self.current_time.saturating_add(delta);
+
+        self.move_to(self.current_time)
+    }
+
+    /// The mixed time of the last keyframe.
+    pub fn duration(&self) -> Time {
+        Time::from_f32(self.time_at(self.a.as_ref().len() - 1))
+    }
+
+    /// Returns `true` if the current time is at or after the last keyframe.
+    pub fn is_finished(&self) -> bool {
+        self.current_time.to_f32() >= self.time_at(self.a.as_ref().len() - 1)
+    }
+
+    /// The keyframes of track `a`, in order.
+    pub fn track_a(&self) -> &[Keyframe<Value, Time, T>] {
+        self.a.as_ref()
+    }
+
+    /// The keyframes of track `b`, in order.
+    pub fn track_b(&self) -> &[Keyframe<Value, Time, T>] {
+        self.b.as_ref()
+    }
+
+    /// Consumes this, returning the storage of both tracks.
+    pub fn into_inner(self) -> (S, S) {
+        (self.a, self.b)
+    }
+
+    /// The mixed time of the keyframe at `index`.
+    fn time_at(&self, index: usize) -> f32 {
+        let (a, b) = (
+            self.a.as_ref()[index].time.to_f32(),
+            self.b.as_ref()[index].time.to_f32(),
+        );
+        a + (b - a) * self.weight
+    }
+
+    /// The mixed value of the keyframe at `index`.
+    fn value_at(&self, index: usize) -> Value {
+        let (a, b) = (&self.a.as_ref()[index].value, &self.b.as_ref()[index].value);
+        a.clone().scale(1.0 - self.weight) + b.clone().scale(self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    #[cfg(feature = "quad")]
+    use crate::{Keyframes, QuadIn};
+    use approx::assert_relative_eq;
+
+    #[cfg(feature = "quad")]
+    fn tracks() -> [[Keyframe<f32, f32, QuadIn>; 3]; 2] {
+        [
+            [
+                Keyframe::new(0.0, 0.0, QuadIn),
+                Keyframe::new(2.0, 4.0, QuadIn),
+                Keyframe::new(4.0, 0.0, QuadIn),
+            ],
+            [
+                Keyframe::new(1.0, 2.0, QuadIn),
+                Keyframe::new(4.0, 8.0, QuadIn),
+                Keyframe::new(6.0, 2.0, QuadIn),
+            ],
+        ]
+    }
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn endpoints_match_each_track() {
+        let [a, b] = tracks();
+        let mut morph = TrackMorph::new(a, b, 0.0);
+        let (mut just_a, mut just_b) = (Keyframes::new(a), Keyframes::new(b));
+
+        for i in -4..=32 {
+            let t = i as f32 / 4.0;
+
+            morph.weight = 0.0;
+            assert_eq!(morph.move_to(t), just_a.move_to(t));
+            morph.weight = 1.0;
+            assert_eq!(morph.move_to(t), just_b.move_to(t));
+        }
+    }
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn blends_times_and_values() {
+        let [a, b] = tracks();
+        let mut morph = TrackMorph::new(a, b, 0.5);
+
+        assert_eq!(morph.duration(), 5.0);
+        assert_eq!(morph.move_to(0.0), 1.0);
+        assert_eq!(morph.move_to(0.5), 1.0);
+        assert_eq!(morph.move_to(3.0), 6.0);
+        assert!(!morph.is_finished());
+        assert_eq!(morph.move_by(2.0), 1.0);
+        assert!(morph.is_finished());
+
+        // a quarter of the way into the first segment, which runs from 0.5 to 3.0
+        assert_relative_eq!(morph.move_to(1.125), 1.0 + 5.0 * 0.0625);
+    }
+
+    #[test]
+    fn weight_changes_are_smooth() {
+        let a = [Keyframe::new(0.0, 0.0, Linear), Keyframe::new(10.0, 100.0, Linear)];
+        let b = [Keyframe::new(0.0, 0.0, Linear), Keyframe::new(10.0, 200.0, Linear)];
+        let mut morph = TrackMorph::new(a, b, 0.0);
+
+        assert_eq!(morph.move_to(5.0), 50.0);
+        morph.weight = 0.1;
+        assert_relative_eq!(morph.move_by(0.0), 55.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "same number")]
+    fn mismatched_tracks() {
+        let a = alloc::vec![Keyframe::new(0, 0, Linear), Keyframe::new(10, 100, Linear)];
+        let b = alloc::vec![Keyframe::new(0, 0, Linear)];
+
+        TrackMorph::new(a, b, 0.0);
+    }
+}
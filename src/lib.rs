@@ -1,5 +1,8 @@
-#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
-#![cfg_attr(not(feature = "std"), doc = "no-std stand in")]
+#![cfg_attr(all(feature = "std", feature = "easings"), doc = include_str!("../README.md"))]
+#![cfg_attr(
+    not(all(feature = "std", feature = "easings")),
+    doc = "stand in for the README, whose examples need `std` and `easings`"
+)]
 #![deny(unsafe_code)]
 #![deny(rust_2018_idioms)]
 #![deny(missing_docs)]
@@ -10,18 +13,52 @@
 #[macro_use]
 extern crate std;
 
-#[cfg(all(feature = "std", feature = "libm"))]
-compile_error!("Please disable feature `libm` or disable default features -- both cannot be active at once.");
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Please enable feature `std` or `libm` -- one of them is needed for floating point math.");
 
 #[macro_use]
 mod macros;
 
+mod easing;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod keyframes;
 mod math;
+#[cfg(feature = "python")]
+mod python;
+mod rng;
+#[cfg(feature = "alloc")]
+mod snapshot;
 mod tweener;
 mod tweens;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+#[cfg(feature = "std")]
+pub use easing::{CustomEasing, RegisterEasingError, register_easing};
+pub use easing::{Easing, ParseEasingError};
+pub use keyframes::*;
+#[cfg(feature = "mint")]
+pub use math::Mint;
+#[cfg(feature = "nalgebra")]
+pub use math::NaPoint;
+#[cfg(feature = "glam")]
+pub use math::Transform2D;
+pub use math::{Angle, BrightnessCurve, EulerAngles, LinearRgba, Oklab, PwmBrightness, Rect, Rotation};
+#[cfg(feature = "python")]
+pub use python::{PyEasing, PyKeyframes, PyTweener, python_module};
+pub use rng::{TweenRng, XorShift64};
+#[cfg(feature = "alloc")]
+pub use snapshot::{SNAPSHOT_TOLERANCE, SnapshotMismatch, compare_snapshot, snapshot};
+#[cfg(feature = "derive")]
+pub use tween_derive::TweenValue;
 pub use tweener::*;
 pub use tweens::*;
+#[cfg(feature = "wasm")]
+pub use wasm::{JsTweener, easing_names};
 
 /// This is the core trait of the Library, which all tweens implement.
 pub trait Tween<Value> {
@@ -50,13 +87,31 @@ pub trait Tween<Value> {
     fn is_finite(&self) -> bool {
         true
     }
+
+    /// How far this tween runs in parametric space, if it is [finite](Tween::is_finite). Almost
+    /// every tween runs from `0.0` to `1.0`, which is this default.
+    ///
+    /// Tweens which play more than once, like [Repeat] and [PingPong], return more than `1.0`: a
+    /// [Tweener] then finishes, and clamps, once it has run `duration * span`.
+    #[inline(always)]
+    fn span(&self) -> f32 {
+        1.0
+    }
+
+    /// Returns `true` if this tween, once it has run its [span](Tween::span), ends back where it
+    /// started, rather than at the end of its `value_delta`. [PingPong] and [Mirror] do this.
+    ///
+    /// A [Tweener] uses this to clamp [finite](Tween::is_finite) tweens to the right end value.
+    #[inline(always)]
+    fn returns_to_start(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
 static_assertions::assert_obj_safe!(Tween<i32>);
 
-#[cfg(feature = "std")]
-impl<'a, Value> Tween<Value> for &'a mut dyn Tween<Value>
+impl<Value> Tween<Value> for &mut dyn Tween<Value>
 where
     Value: TweenValue,
 {
@@ -66,7 +121,15 @@ where
     }
 
     fn is_finite(&self) -> bool {
-        true
+        (**self).is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        (**self).span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        (**self).returns_to_start()
     }
 }
 
@@ -95,16 +158,67 @@ where
 /// If you want to implement your own values to be tweened (for example, your favorite color lib),
 /// then you'll need to implement this trait.
 ///
-/// For now, we require `Copy`, but can reduce this to a `Clone` implementation. Please file an
-/// issue if that is needed for your workflow.
-pub trait TweenValue: Copy + core::fmt::Debug + core::ops::Add<Output = Self> + core::ops::Sub<Output = Self> {
+/// Values only need to be `Clone`, so heap-backed values, like a struct wrapping a `Vec` of
+/// weights, can be tweened too. Values are cloned only where they're needed more than once, and
+/// for `Copy` values, like numbers and vectors, those clones are just copies.
+pub trait TweenValue: Clone + core::fmt::Debug + core::ops::Add<Output = Self> + core::ops::Sub<Output = Self> {
     /// This should be implemented as a simple multiplication. For f64, for example,
-    /// it's implemented as `(self as f32 * scale) as f64`.
+    /// it's implemented as `(self as f32 * scale) as f64`, or, with the `precise-f64` feature,
+    /// as `self * scale as f64`.
     fn scale(self, scale: f32) -> Self;
 }
 
+/// A `TweenLength` is a [TweenValue] which has a size, so that the distance between two values
+/// can be measured as the length of their difference. For numbers, this is their absolute value,
+/// and for vectors, it's their Euclidean length.
+///
+/// This is what lets a [Spline] move at a constant speed.
+pub trait TweenLength: TweenValue {
+    /// Returns how long this value is. This should never be negative.
+    fn length(&self) -> f32;
+}
+
+/// An `Interpolate` value can be blended from one value to another, without needing to be added,
+/// subtracted or scaled the way a [TweenValue] does.
+///
+/// Some values have no sensible difference: a hue on a color wheel, a rotation, or a number which
+/// has to stay inside a range. These can still be eased, by implementing this directly, and
+/// driving them with an [Interpolator], which eases the percent and then interpolates.
+///
+/// Every [TweenValue] is already `Interpolate`, with a plain [lerp].
+///
+/// ```
+/// # use tween::Interpolate;
+/// /// A hue, in degrees, which takes the short way around the wheel.
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Hue(f32);
+///
+/// impl Interpolate for Hue {
+///     fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+///         let turn = (b.0 - a.0 + 540.0).rem_euclid(360.0) - 180.0;
+///         Hue((a.0 + turn * t).rem_euclid(360.0))
+///     }
+/// }
+///
+/// assert_eq!(Hue::lerp(&Hue(350.0), &Hue(30.0), 0.25), Hue(0.0));
+/// ```
+pub trait Interpolate: Clone {
+    /// Returns the value `t` of the way from `a` to `b`. `t` is usually in `0.0..=1.0`, but
+    /// overshooting easings, like `BackOut`, will take it outside of that range, so this should
+    /// extrapolate sensibly too.
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self;
+}
+
+impl<Value: TweenValue> Interpolate for Value {
+    #[inline(always)]
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        lerp(a.clone(), b.clone(), t)
+    }
+}
+
 /// A `TweenTime` is a representation of Time. The two most common will be `f32`/`f64` for
-/// seconds and `u32`/`u64`/`usize` for frames.
+/// seconds and `u32`/`u64`/`usize` for frames. [Duration](core::time::Duration) is also supported,
+/// for those who already measure their frame times with it.
 ///
 /// If you want to implement your own time for duration, then you'll need to implement this
 /// trait.
@@ -115,7 +229,6 @@ pub trait TweenTime:
     + core::fmt::Debug
     + core::ops::Add<Output = Self>
     + core::ops::AddAssign
-    + core::ops::Rem<Output = Self>
     + core::ops::Sub<Output = Self>
 {
     /// The ZERO value. This is 0 or 0.0.
@@ -123,6 +236,20 @@ pub trait TweenTime:
 
     /// Converts the given number to an `f32`.
     fn to_f32(self) -> f32;
+
+    /// Converts an `f32` back into this Time. For integer times, this truncates.
+    fn from_f32(value: f32) -> Self;
+
+    /// Adds `other` to this Time, stopping at the largest or smallest Time there is rather than
+    /// overflowing. Drivers move their clocks forward with this, so that small integer times,
+    /// like a `u8` frame counter, stop at their limit instead of panicking or wrapping around to
+    /// the start.
+    ///
+    /// The default is a plain `+`, which is right for times which can't overflow.
+    #[inline(always)]
+    fn saturating_add(self, other: Self) -> Self {
+        self + other
+    }
 }
 
 declare_time!(u8, i8, i16, u16, i32, i64, u32, u64, i128, u128, usize, isize);
@@ -134,6 +261,11 @@ impl TweenTime for f32 {
     fn to_f32(self) -> f32 {
         self
     }
+
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        value
+    }
 }
 impl TweenTime for f64 {
     const ZERO: Self = 0.0;
@@ -142,9 +274,35 @@ impl TweenTime for f64 {
     fn to_f32(self) -> f32 {
         self as f32
     }
+
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        value as f64
+    }
 }
 
 declare_value!(u8, i8, i16, u16, i32, i64, u32, u64, i128, u128, usize, isize);
+declare_length!(u8, i8, i16, u16, i32, i64, u32, u64, i128, u128, usize, isize, f32, f64);
+
+impl TweenTime for core::time::Duration {
+    const ZERO: Self = core::time::Duration::ZERO;
+
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self.as_secs_f32()
+    }
+
+    /// Durations can't be negative, so negative values become [ZERO](Self::ZERO).
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        core::time::Duration::try_from_secs_f32(value).unwrap_or(Self::ZERO)
+    }
+
+    #[inline(always)]
+    fn saturating_add(self, other: Self) -> Self {
+        core::time::Duration::saturating_add(self, other)
+    }
+}
 
 impl TweenValue for f32 {
     #[inline(always)]
@@ -156,10 +314,49 @@ impl TweenValue for f32 {
 impl TweenValue for f64 {
     #[inline(always)]
     fn scale(self, scale: f32) -> Self {
-        (self as f32 * scale) as Self
+        if cfg!(feature = "precise-f64") {
+            self * scale as f64
+        } else {
+            (self as f32 * scale) as Self
+        }
     }
 }
 
+/// Evaluates a [Tween] once, at `time`, without making a [Tweener] yourself. Times outside of
+/// `0..=duration` are clamped just like they are in [Tweener::move_to].
+///
+/// This is handy for one-off samples, like drawing a gradient or previewing a curve in an editor.
+///
+/// ```
+/// # #[cfg(feature = "quad")] {
+/// # use tween::{tween_at, QuadIn};
+/// assert_eq!(tween_at(QuadIn, 0.0, 100.0, 10.0, 5.0), 25.0);
+/// assert_eq!(tween_at(QuadIn, 0.0, 100.0, 10.0, 20.0), 100.0);
+/// # }
+/// ```
+#[inline]
+pub fn tween_at<Value, Time, T>(tween: T, start: Value, end: Value, duration: Time, time: Time) -> Value
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    Tweener::new(start, end, duration, tween).move_to(time)
+}
+
+/// Linearly interpolates between `start` and `end`, where `t` is a percent. `t` isn't clamped, so
+/// values outside of `0.0..=1.0` extrapolate.
+///
+/// ```
+/// # use tween::lerp;
+/// assert_eq!(lerp(10, 20, 0.5), 15);
+/// assert_eq!(lerp(10.0, 20.0, 1.5), 25.0);
+/// ```
+#[inline(always)]
+pub fn lerp<Value: TweenValue>(start: Value, end: Value, t: f32) -> Value {
+    start.clone() + (end - start).scale(t)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,6 +383,99 @@ mod tests {
         assert!(pct_50_or_over);
     }
 
+    #[test]
+    fn duration_time() {
+        use core::time::Duration;
+
+        let mut tweener = Tweener::new(0, 100, Duration::from_secs(2), Linear);
+
+        assert_eq!(tweener.move_by(Duration::from_millis(500)), 25);
+        assert_eq!(tweener.move_by(Duration::from_secs(2)), 100);
+        assert!(tweener.is_finished());
+
+        assert_eq!(Duration::from_f32(-1.0), Duration::ZERO);
+        assert_eq!(Duration::from_f32(1.5), Duration::from_millis(1500));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn clone_only_value() {
+        use std::{vec, vec::Vec};
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Weights(Vec<f32>);
+
+        impl core::ops::Add for Weights {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.iter().zip(rhs.0).map(|(a, b)| a + b).collect())
+            }
+        }
+
+        impl core::ops::Sub for Weights {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.iter().zip(rhs.0).map(|(a, b)| a - b).collect())
+            }
+        }
+
+        impl TweenValue for Weights {
+            fn scale(self, scale: f32) -> Self {
+                Self(self.0.into_iter().map(|v| v * scale).collect())
+            }
+        }
+
+        let mut tweener = Tweener::linear(Weights(vec![0.0, 10.0]), Weights(vec![10.0, 0.0]), 10.0);
+
+        assert_eq!(tweener.move_to(5.0), Weights(vec![5.0, 5.0]));
+        assert_eq!(tweener.move_to(20.0), Weights(vec![10.0, 0.0]));
+    }
+
+    #[test]
+    fn small_integer_times() {
+        // a whole u8 of frames, stepped one at a time and then past the end
+        let mut fixed = FixedTweener::new(0, 255, 255u8, Linear, 1);
+        assert_eq!(fixed.by_ref().count(), 255);
+        assert_eq!(fixed.tweener.current_time, 255);
+        assert_eq!(fixed.move_next(), 255);
+
+        let mut tweener = Tweener::new(0.0f32, 1.0, 200u8, Linear);
+        tweener.move_to(200);
+        assert_eq!(tweener.move_by(100), 1.0);
+        assert_eq!(tweener.current_time, u8::MAX);
+
+        // i8 counters can start well before the tween, and still run off the end
+        let mut delayed = Tweener::new_at(0, 100, 100i8, Linear, -120);
+        assert_eq!(delayed.move_by(-100), 0);
+        assert_eq!(delayed.current_time, i8::MIN);
+        assert_eq!(delayed.move_by(127), 0);
+        assert_eq!(delayed.move_by(27), 26);
+        assert_eq!(delayed.move_by(127), 100);
+        assert_eq!(delayed.current_time, i8::MAX);
+
+        assert_eq!(u8::from_f32(300.0), u8::MAX);
+        assert_eq!(i8::from_f32(-300.0), i8::MIN);
+        assert_eq!(
+            core::time::Duration::MAX.saturating_add(core::time::Duration::ZERO),
+            core::time::Duration::MAX
+        );
+        assert_eq!(TweenTime::saturating_add(1.5f32, 2.0), 3.5);
+    }
+
+    #[test]
+    fn tween_at_matches_tweener() {
+        let mut tweener = Tweener::new(0, 100, 10, CubicBezier::EASE_IN_OUT);
+
+        for time in -2..=12 {
+            assert_eq!(
+                tween_at(CubicBezier::EASE_IN_OUT, 0, 100, 10, time),
+                tweener.move_to(time)
+            );
+        }
+    }
+
     #[test]
     fn cubic_bezier() {
         use core::ops::{Add, Sub};
@@ -236,4 +526,18 @@ mod tests {
         assert_eq!(tweener.move_to(5.0), Point(5.0, 7.5));
         assert_eq!(tweener.move_to(10.0), Point(10.0, 0.0));
     }
+
+    #[cfg(feature = "precise-f64")]
+    #[test]
+    fn f64_keeps_precision() {
+        // an `f32` only has about 7 digits, so these would all collapse into one value through it
+        let (start, end) = (1_234_567.891_234_5, 1_234_568.891_234_5);
+        let mut tweener = Tweener::linear(start, end, 1.0);
+
+        for pct in [0.125f32, 0.25, 0.5, 0.75] {
+            let value: f64 = tweener.move_to(pct);
+            assert!((value - (start + pct as f64)).abs() < 1e-9);
+        }
+        assert!((0.1f64.scale(0.5) - 0.05).abs() < 1e-12);
+    }
 }
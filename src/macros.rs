@@ -10,6 +10,16 @@ macro_rules! declare_time {
             fn to_f32(self) -> f32 {
                 self as f32
             }
+
+            #[inline(always)]
+            fn from_f32(value: f32) -> Self {
+                value as $t
+            }
+
+            #[inline(always)]
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
         }
         )*
     };
@@ -29,6 +39,61 @@ macro_rules! declare_value {
     };
 }
 
+/// This is internal to the library, but allows for simple numeric
+/// types to be made into a tween_length.
+macro_rules! declare_length {
+    ($($t:ident),*) => {
+        $(
+        impl TweenLength for $t {
+            #[inline(always)]
+            fn length(&self) -> f32 {
+                let v = *self as f32;
+                if v < 0.0 { -v } else { v }
+            }
+        })*
+    };
+}
+
+/// This is internal to the library, and implements the operators which build tweens out of other
+/// tweens: `weight * tween` makes a [Weighted](crate::Weighted), `a + b` makes a
+/// [WeightedSum](crate::WeightedSum), and `outer * inner` makes a [Compose](crate::Compose).
+macro_rules! impl_tween_ops {
+    ($name:ident $(<$($generic:ident),*>)?) => {
+        impl$(<$($generic),*>)? core::ops::Mul<$name$(<$($generic),*>)?> for f32 {
+            type Output = $crate::Weighted<$name$(<$($generic),*>)?>;
+
+            #[inline]
+            fn mul(self, tween: $name$(<$($generic),*>)?) -> Self::Output {
+                $crate::Weighted::new(tween, self)
+            }
+        }
+
+        impl<Rhs $($(, $generic)*)?> core::ops::Add<Rhs> for $name$(<$($generic),*>)?
+        where
+            Rhs: $crate::Tween<f32>,
+        {
+            type Output = $crate::WeightedSum<Self, Rhs>;
+
+            #[inline]
+            fn add(self, rhs: Rhs) -> Self::Output {
+                $crate::WeightedSum::new(self, rhs)
+            }
+        }
+
+        impl<Rhs $($(, $generic)*)?> core::ops::Mul<Rhs> for $name$(<$($generic),*>)?
+        where
+            Rhs: $crate::Tween<f32>,
+        {
+            type Output = $crate::Compose<Self, Rhs>;
+
+            #[inline]
+            fn mul(self, inner: Rhs) -> Self::Output {
+                $crate::Compose::new(self, inner)
+            }
+        }
+    };
+}
+
 /// This is internal to the library, but allows for creating simple ease-style
 /// tweens.
 macro_rules! declare_tween {
@@ -46,6 +111,7 @@ macro_rules! declare_tween {
     ) => {
         $(#[$struct_meta])*
         #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name;
 
         impl $name {
@@ -69,6 +135,8 @@ macro_rules! declare_tween {
             }
         }
 
+        impl_tween_ops!($name);
+
         impl<Value, Time> $crate::Tweener<Value, Time, $crate::$name>
         where
             Time: $crate::TweenTime,
@@ -105,8 +173,8 @@ macro_rules! declare_tween {
 
 macro_rules! impl_tween_for_box {
     ($($trait_bounds:ident),*) => {
-        #[cfg(feature = "std")]
-        impl<Value: TweenValue> Tween<Value> for std::boxed::Box<dyn Tween<Value> $(+ $trait_bounds)*> {
+        #[cfg(feature = "alloc")]
+        impl<Value: TweenValue> Tween<Value> for alloc::boxed::Box<dyn Tween<Value> $(+ $trait_bounds)*> {
             #[inline(always)]
             fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
                 (**self).tween(value_delta, percent)
@@ -115,10 +183,20 @@ macro_rules! impl_tween_for_box {
             fn is_finite(&self) -> bool {
                 (**self).is_finite()
             }
+
+            fn span(&self) -> f32 {
+                (**self).span()
+            }
+
+            fn returns_to_start(&self) -> bool {
+                (**self).returns_to_start()
+            }
         }
     };
 }
 
+// only the easing families use this, and they can all be compiled out
+#[allow(unused_macros)]
 macro_rules! test_tween {
     ($name:ident) => {
         #[cfg(test)]
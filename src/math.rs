@@ -12,3 +12,65 @@ mod vek;
 
 #[cfg(feature = "cgmath")]
 mod cgmath;
+
+#[cfg(feature = "rgb")]
+mod rgb;
+
+#[cfg(feature = "mint")]
+mod mint;
+
+#[cfg(feature = "uom")]
+mod uom;
+
+mod angle;
+mod color;
+mod euler;
+pub(crate) mod float;
+mod pwm;
+mod rect;
+pub use angle::Angle;
+pub use color::{LinearRgba, Oklab};
+pub use euler::EulerAngles;
+pub use pwm::{BrightnessCurve, PwmBrightness};
+pub use rect::Rect;
+
+#[cfg(feature = "glam")]
+pub use self::glam::Transform2D;
+#[cfg(feature = "mint")]
+pub use self::mint::Mint;
+#[cfg(feature = "nalgebra")]
+pub use self::na::NaPoint;
+
+/// Wraps an angle, in radians, into `-π..π`.
+#[inline(always)]
+pub(crate) fn wrap_angle(angle: f32) -> f32 {
+    use core::f32::consts::{PI, TAU};
+
+    let mut wrapped = (angle + PI) % TAU;
+    if wrapped < 0.0 {
+        wrapped += TAU;
+    }
+
+    wrapped - PI
+}
+
+/// A rotation, such as a unit quaternion, which can be tweened.
+///
+/// Rotations can't simply be lerped component by component, so this wrapper gives them a
+/// [TweenValue](crate::TweenValue) implementation built out of rotations instead: subtracting
+/// gives the rotation from one to the other, scaling gives a fraction of that rotation, and
+/// adding applies one rotation after another. A [Tweener](crate::Tweener) over a `Rotation`
+/// therefore slerps, and every easing in this library (even overshooting ones) works on it.
+///
+/// Quaternions double cover rotations, so `q` and `-q` are the same rotation. When subtracting,
+/// the difference is always flipped onto the same hemisphere, so tweens take the shortest path,
+/// even between keyframes imported with opposite signs.
+///
+/// Note that unlike most values, `+` and `-` here are not commutative.
+///
+/// This is implemented for `glam`'s `Quat` and `DQuat` with the `glam` feature, and for
+/// `nalgebra`'s `UnitQuaternion` with the `nalgebra` feature.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Rotation<Q>(pub Q);
@@ -0,0 +1,88 @@
+use super::wrap_angle;
+
+/// A single angle which is tweened along its shortest path, so turning from 350° to 10° goes 20°
+/// forward, rather than 340° backwards.
+///
+/// Angles are stored in radians. Like [EulerAngles](crate::EulerAngles), the output of a tween isn't
+/// wrapped, so it's continuous, but it may leave `-π..=π`; use [wrapped](Self::wrapped) if you
+/// need it back in range.
+///
+/// ```
+/// # use tween::{Angle, Tweener};
+/// let mut tweener = Tweener::linear(Angle::from_degrees(350.0), Angle::from_degrees(10.0), 2.0);
+///
+/// assert!((tweener.move_to(1.0).degrees() - 360.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Angle(f32);
+
+impl Angle {
+    /// An angle of zero.
+    pub const ZERO: Self = Self(0.0);
+
+    /// Creates a new Angle, in radians.
+    pub const fn from_radians(radians: f32) -> Self {
+        Self(radians)
+    }
+
+    /// Creates a new Angle, in degrees.
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self(degrees.to_radians())
+    }
+
+    /// This angle, in radians.
+    pub const fn radians(self) -> f32 {
+        self.0
+    }
+
+    /// This angle, in degrees.
+    pub fn degrees(self) -> f32 {
+        self.0.to_degrees()
+    }
+
+    /// This angle wrapped into `-π..π`.
+    pub fn wrapped(self) -> Self {
+        Self(wrap_angle(self.0))
+    }
+}
+
+impl core::ops::Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl core::ops::Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(wrap_angle(self.0 - rhs.0))
+    }
+}
+
+impl crate::TweenValue for Angle {
+    fn scale(self, scale: f32) -> Self {
+        Self(self.0 * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Tweener};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn shortest_path() {
+        let mut forward = Tweener::new(Angle::from_degrees(350.0), Angle::from_degrees(10.0), 4.0, Linear);
+        let mut backward = Tweener::new(Angle::from_degrees(10.0), Angle::from_degrees(350.0), 4.0, Linear);
+
+        assert_relative_eq!(forward.move_to(1.0).degrees(), 355.0, epsilon = 1e-3);
+        assert_relative_eq!(backward.move_to(1.0).degrees(), 5.0, epsilon = 1e-3);
+        assert_relative_eq!(forward.move_to(3.0).wrapped().degrees(), 5.0, epsilon = 1e-3);
+    }
+}
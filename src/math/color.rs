@@ -0,0 +1,226 @@
+use crate::math::float::{cbrt, powf};
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// A color with alpha, stored in linear RGB, so that fades between colors are physically even
+/// rather than dipping dark in the middle, like they do when sRGB values are lerped directly.
+///
+/// Colors usually come in sRGB, so make them with [from_srgb](Self::from_srgb) and turn them back
+/// with [to_srgb](Self::to_srgb) before handing them to a renderer which expects sRGB. For fades
+/// which look even to the eye, tween an [Oklab] instead.
+///
+/// ```
+/// # use tween::{LinearRgba, Tweener};
+/// let red = LinearRgba::from_srgb_u8([255, 0, 0, 255]);
+/// let blue = LinearRgba::from_srgb_u8([0, 0, 255, 255]);
+/// let mut fade = Tweener::linear(red, blue, 2.0);
+///
+/// assert_eq!(fade.move_to(1.0).to_srgb_u8(), [188, 0, 188, 255]);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRgba {
+    /// The linear red channel.
+    pub r: f32,
+
+    /// The linear green channel.
+    pub g: f32,
+
+    /// The linear blue channel.
+    pub b: f32,
+
+    /// The alpha channel, which is always linear.
+    pub a: f32,
+}
+
+impl LinearRgba {
+    /// Creates a new color out of linear channels.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a new color out of sRGB channels, from `0.0` to `1.0`.
+    pub fn from_srgb([r, g, b, a]: [f32; 4]) -> Self {
+        Self::new(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a)
+    }
+
+    /// Creates a new color out of 8-bit sRGB channels.
+    pub fn from_srgb_u8(srgb: [u8; 4]) -> Self {
+        Self::from_srgb(srgb.map(|c| c as f32 / 255.0))
+    }
+
+    /// Converts this color to sRGB channels, from `0.0` to `1.0`.
+    pub fn to_srgb(self) -> [f32; 4] {
+        [
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+            self.a,
+        ]
+    }
+
+    /// Converts this color to 8-bit sRGB channels, clamping channels which are out of range.
+    pub fn to_srgb_u8(self) -> [u8; 4] {
+        self.to_srgb().map(|c| (c.clamp(0.0, 1.0) * 255.0 + 0.5) as u8)
+    }
+}
+
+impl core::ops::Add for LinearRgba {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b, self.a + rhs.a)
+    }
+}
+
+impl core::ops::Sub for LinearRgba {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b, self.a - rhs.a)
+    }
+}
+
+impl crate::TweenValue for LinearRgba {
+    fn scale(self, scale: f32) -> Self {
+        Self::new(self.r * scale, self.g * scale, self.b * scale, self.a * scale)
+    }
+}
+
+/// A color with alpha in the Oklab color space, which is built so that equal steps look equally
+/// different. Fades tweened in Oklab stay even in lightness and don't pass through muddy hues.
+///
+/// Convert to and from [LinearRgba] with `From`.
+///
+/// ```
+/// # use tween::{LinearRgba, Oklab, Tweener};
+/// let black = Oklab::from(LinearRgba::new(0.0, 0.0, 0.0, 1.0));
+/// let white = Oklab::from(LinearRgba::new(1.0, 1.0, 1.0, 1.0));
+/// let mut fade = Tweener::linear(black, white, 2.0);
+///
+/// // halfway is a perceptual mid-grey, which is much darker in linear light
+/// let grey = LinearRgba::from(fade.move_to(1.0));
+/// assert!((grey.r - 0.125).abs() < 1e-3);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+    /// Perceived lightness, from `0.0` to `1.0`.
+    pub l: f32,
+
+    /// How green (negative) or red (positive) the color is.
+    pub a: f32,
+
+    /// How blue (negative) or yellow (positive) the color is.
+    pub b: f32,
+
+    /// The alpha channel.
+    pub alpha: f32,
+}
+
+impl Oklab {
+    /// Creates a new Oklab color.
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+// these are Björn Ottosson's reference matrices, which are given to more precision than f32 holds
+#[allow(clippy::excessive_precision)]
+impl From<LinearRgba> for Oklab {
+    fn from(c: LinearRgba) -> Self {
+        let l = cbrt(0.4122214708 * c.r + 0.5363325363 * c.g + 0.0514459929 * c.b);
+        let m = cbrt(0.2119034982 * c.r + 0.6806995451 * c.g + 0.1073969566 * c.b);
+        let s = cbrt(0.0883024619 * c.r + 0.2817188376 * c.g + 0.6299787005 * c.b);
+
+        Self::new(
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            c.a,
+        )
+    }
+}
+
+#[allow(clippy::excessive_precision)]
+impl From<Oklab> for LinearRgba {
+    fn from(c: Oklab) -> Self {
+        let l = powf(c.l + 0.3963377774 * c.a + 0.2158037573 * c.b, 3.0);
+        let m = powf(c.l - 0.1055613458 * c.a - 0.0638541728 * c.b, 3.0);
+        let s = powf(c.l - 0.0894841775 * c.a - 1.2914855480 * c.b, 3.0);
+
+        Self::new(
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+            c.alpha,
+        )
+    }
+}
+
+impl core::ops::Add for Oklab {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.l + rhs.l, self.a + rhs.a, self.b + rhs.b, self.alpha + rhs.alpha)
+    }
+}
+
+impl core::ops::Sub for Oklab {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.l - rhs.l, self.a - rhs.a, self.b - rhs.b, self.alpha - rhs.alpha)
+    }
+}
+
+impl crate::TweenValue for Oklab {
+    fn scale(self, scale: f32) -> Self {
+        Self::new(self.l * scale, self.a * scale, self.b * scale, self.alpha * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn srgb_round_trip() {
+        for c in [0u8, 1, 10, 64, 128, 200, 255] {
+            let srgb = [c, 255 - c, c / 2, 255];
+            assert_eq!(LinearRgba::from_srgb_u8(srgb).to_srgb_u8(), srgb);
+        }
+    }
+
+    #[test]
+    fn oklab_round_trip() {
+        let color = LinearRgba::new(0.8, 0.3, 0.05, 0.5);
+        let back = LinearRgba::from(Oklab::from(color));
+
+        assert_relative_eq!(back.r, color.r, epsilon = 1e-4);
+        assert_relative_eq!(back.g, color.g, epsilon = 1e-4);
+        assert_relative_eq!(back.b, color.b, epsilon = 1e-4);
+        assert_eq!(back.a, 0.5);
+
+        // white is as light as it gets, with no hue
+        let white = Oklab::from(LinearRgba::new(1.0, 1.0, 1.0, 1.0));
+        assert_relative_eq!(white.l, 1.0, epsilon = 1e-4);
+        assert_relative_eq!(white.a, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(white.b, 0.0, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,151 @@
+use super::wrap_angle as wrap;
+
+/// A rotation as yaw, pitch and roll, in radians, for tweening things like FPS cameras where
+/// quaternions are inconvenient.
+///
+/// Each axis is tweened along its shortest path, so turning from a yaw of 350° to 10° goes 20°
+/// forward, rather than 340° backwards. Values aren't wrapped after tweening, so the output is
+/// continuous, but it may leave `-π..=π`.
+///
+/// Pitch can also be limited, which both clamps the output and turns off wrapping for pitch, since
+/// a limited pitch can't go the long way around anyway.
+///
+/// ```
+/// # use tween::{EulerAngles, Tweener};
+/// let start = EulerAngles::from_degrees(350.0, 0.0, 0.0);
+/// let end = EulerAngles::from_degrees(10.0, 0.0, 0.0);
+/// let mut tweener = Tweener::linear(start, end, 2.0);
+///
+/// let halfway = tweener.move_to(1.0);
+/// assert!((halfway.yaw.to_degrees() - 360.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EulerAngles {
+    /// Rotation around the vertical axis.
+    pub yaw: f32,
+
+    /// Rotation around the side-to-side axis.
+    pub pitch: f32,
+
+    /// Rotation around the forward axis.
+    pub roll: f32,
+
+    /// The lowest and highest pitch allowed, if any.
+    pub pitch_limits: Option<(f32, f32)>,
+}
+
+impl EulerAngles {
+    /// Creates new EulerAngles, in radians.
+    pub fn new(yaw: f32, pitch: f32, roll: f32) -> Self {
+        Self {
+            yaw,
+            pitch,
+            roll,
+            pitch_limits: None,
+        }
+    }
+
+    /// Creates new EulerAngles, in degrees.
+    pub fn from_degrees(yaw: f32, pitch: f32, roll: f32) -> Self {
+        Self::new(yaw.to_radians(), pitch.to_radians(), roll.to_radians())
+    }
+
+    /// Limits pitch to `min..=max`, in radians. Tweening either end of a tween with limits keeps
+    /// the output within them.
+    pub fn with_pitch_limits(mut self, min: f32, max: f32) -> Self {
+        self.pitch_limits = Some((min, max));
+        self
+    }
+}
+
+impl core::ops::Add for EulerAngles {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let pitch_limits = self.pitch_limits.or(rhs.pitch_limits);
+        let mut pitch = self.pitch + rhs.pitch;
+        if let Some((min, max)) = pitch_limits {
+            pitch = pitch.clamp(min, max);
+        }
+
+        Self {
+            yaw: self.yaw + rhs.yaw,
+            pitch,
+            roll: self.roll + rhs.roll,
+            pitch_limits,
+        }
+    }
+}
+
+impl core::ops::Sub for EulerAngles {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let pitch_limits = self.pitch_limits.or(rhs.pitch_limits);
+        let pitch = if pitch_limits.is_some() {
+            self.pitch - rhs.pitch
+        } else {
+            wrap(self.pitch - rhs.pitch)
+        };
+
+        Self {
+            yaw: wrap(self.yaw - rhs.yaw),
+            pitch,
+            roll: wrap(self.roll - rhs.roll),
+            pitch_limits,
+        }
+    }
+}
+
+impl crate::TweenValue for EulerAngles {
+    fn scale(self, scale: f32) -> Self {
+        Self {
+            yaw: self.yaw * scale,
+            pitch: self.pitch * scale,
+            roll: self.roll * scale,
+            pitch_limits: self.pitch_limits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "back")]
+    use crate::{BackInOut, Tweener};
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn wrapping() {
+        assert_relative_eq!(wrap(0.5), 0.5);
+        assert_relative_eq!(wrap(TAU - 0.5), -0.5, epsilon = 1e-6);
+        assert_relative_eq!(wrap(-TAU - 0.5), -0.5, epsilon = 1e-6);
+        assert_relative_eq!(wrap(3.0 * TAU + 1.0), 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn shortest_path_per_axis() {
+        let start = EulerAngles::from_degrees(10.0, 170.0, -170.0);
+        let end = EulerAngles::from_degrees(350.0, -170.0, 170.0);
+        let delta = end - start;
+
+        assert_relative_eq!(delta.yaw.to_degrees(), -20.0, epsilon = 1e-3);
+        assert_relative_eq!(delta.pitch.to_degrees(), 20.0, epsilon = 1e-3);
+        assert_relative_eq!(delta.roll.to_degrees(), -20.0, epsilon = 1e-3);
+    }
+
+    #[cfg(feature = "back")]
+    #[test]
+    fn pitch_limits() {
+        let start = EulerAngles::from_degrees(0.0, -80.0, 0.0).with_pitch_limits(-1.5, 1.5);
+        let end = EulerAngles::from_degrees(90.0, 80.0, 0.0);
+        let mut tweener = Tweener::new(start, end, 100, BackInOut);
+
+        for time in 0..=100 {
+            let pitch = tweener.move_to(time).pitch;
+            assert!((-1.5..=1.5).contains(&pitch), "{} out of limits", pitch);
+        }
+    }
+}
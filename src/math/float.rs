@@ -0,0 +1,56 @@
+//! The float math the library needs, which comes from `std` when it's enabled, and from `libm`
+//! otherwise. Everything else in the library goes through here, so any combination of the two
+//! features builds.
+
+// some of these are only used by easing families, which can be compiled out
+#![allow(dead_code)]
+
+/// Declares shims which call the `std` method on their first argument when `std` is enabled, and
+/// the `libm` function of the same math otherwise.
+macro_rules! shim {
+    ($(fn $name:ident($first:ident $(, $rest:ident)*) -> $ret:ty => $libm:ident;)*) => {
+        $(
+            #[inline(always)]
+            pub(crate) fn $name($first: f32 $(, $rest: f32)*) -> $ret {
+                #[cfg(feature = "std")]
+                return $first.$name($($rest),*);
+
+                #[cfg(not(feature = "std"))]
+                return libm::$libm($first $(, $rest)*);
+            }
+        )*
+    };
+}
+
+shim! {
+    fn sin(x) -> f32 => sinf;
+    fn cos(x) -> f32 => cosf;
+    fn sin_cos(x) -> (f32, f32) => sincosf;
+    fn asin(x) -> f32 => asinf;
+    fn atan2(y, x) -> f32 => atan2f;
+    fn sqrt(x) -> f32 => sqrtf;
+    fn cbrt(x) -> f32 => cbrtf;
+    fn powf(x, n) -> f32 => powf;
+    fn exp(x) -> f32 => expf;
+    fn exp2(x) -> f32 => exp2f;
+    fn ln(x) -> f32 => logf;
+    fn floor(x) -> f32 => floorf;
+    fn trunc(x) -> f32 => truncf;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn shims() {
+        assert_relative_eq!(sin(core::f32::consts::FRAC_PI_2), 1.0);
+        assert_relative_eq!(atan2(1.0, 1.0), core::f32::consts::FRAC_PI_4);
+        assert_relative_eq!(powf(2.0, 10.0), 1024.0);
+        assert_relative_eq!(exp2(-1.0), 0.5);
+        assert_relative_eq!(cbrt(27.0), 3.0);
+        assert_eq!(trunc(-1.5), -1.0);
+        assert_eq!(floor(-1.5), -2.0);
+    }
+}
@@ -18,7 +18,11 @@ impl crate::TweenValue for glam::IVec2 {
 
 impl crate::TweenValue for glam::DVec2 {
     fn scale(self, scale: f32) -> Self {
-        (self.as_vec2() * scale).as_dvec2()
+        if cfg!(feature = "precise-f64") {
+            self * scale as f64
+        } else {
+            (self.as_vec2() * scale).as_dvec2()
+        }
     }
 }
 
@@ -42,7 +46,11 @@ impl crate::TweenValue for glam::IVec3 {
 
 impl crate::TweenValue for glam::DVec3 {
     fn scale(self, scale: f32) -> Self {
-        (self.as_vec3() * scale).as_dvec3()
+        if cfg!(feature = "precise-f64") {
+            self * scale as f64
+        } else {
+            (self.as_vec3() * scale).as_dvec3()
+        }
     }
 }
 
@@ -66,10 +74,36 @@ impl crate::TweenValue for glam::IVec4 {
 
 impl crate::TweenValue for glam::DVec4 {
     fn scale(self, scale: f32) -> Self {
-        (self.as_vec4() * scale).as_dvec4()
+        if cfg!(feature = "precise-f64") {
+            self * scale as f64
+        } else {
+            (self.as_vec4() * scale).as_dvec4()
+        }
     }
 }
 
+macro_rules! impl_length {
+    ($($t:ty),*) => {
+        $(
+        impl crate::TweenLength for $t {
+            #[inline]
+            fn length(&self) -> f32 {
+                <$t>::length(*self) as f32
+            }
+        }
+        )*
+    };
+}
+
+impl_length!(
+    glam::Vec2,
+    glam::DVec2,
+    glam::Vec3,
+    glam::DVec3,
+    glam::Vec4,
+    glam::DVec4
+);
+
 impl crate::TweenValue for glam::Mat2 {
     fn scale(self, scale: f32) -> Self {
         self * scale
@@ -111,3 +145,224 @@ impl crate::TweenValue for glam::DMat4 {
         self * scale as f64
     }
 }
+
+macro_rules! impl_quat {
+    ($($quat:ty => $t:ty),*) => {
+        $(
+        impl core::ops::Add for crate::Rotation<$quat> {
+            type Output = Self;
+
+            // adding rotations is composing them
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn add(self, rhs: Self) -> Self {
+                crate::Rotation(self.0 * rhs.0)
+            }
+        }
+
+        impl core::ops::Sub for crate::Rotation<$quat> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                let delta = self.0 * rhs.0.inverse();
+
+                // `q` and `-q` are the same rotation, but only one of them is the short way around
+                if delta.w < 0.0 {
+                    crate::Rotation(-delta)
+                } else {
+                    crate::Rotation(delta)
+                }
+            }
+        }
+
+        impl crate::TweenValue for crate::Rotation<$quat> {
+            fn scale(self, scale: f32) -> Self {
+                let (axis, angle) = self.0.to_axis_angle();
+
+                crate::Rotation(<$quat>::from_axis_angle(axis, angle * scale as $t))
+            }
+        }
+        )*
+    };
+}
+
+impl_quat!(glam::Quat => f32, glam::DQuat => f64);
+
+/// A 2D transform -- translation, rotation and scale -- which can be tweened as one value.
+///
+/// The rotation is in radians, and is tweened along its shortest path, so turning from 350° to 10°
+/// goes 20° forward, rather than 340° backwards. Like [EulerAngles](crate::EulerAngles), the
+/// tweened rotation isn't wrapped, so it may leave `-π..=π`.
+///
+/// ```
+/// # use tween::{Transform2D, Tweener};
+/// # use glam::Vec2;
+/// let start = Transform2D::new(Vec2::ZERO, 350f32.to_radians(), Vec2::ONE);
+/// let end = Transform2D::new(Vec2::new(10.0, 0.0), 10f32.to_radians(), Vec2::splat(2.0));
+/// let mut tweener = Tweener::linear(start, end, 2.0);
+///
+/// let halfway = tweener.move_to(1.0);
+/// assert_eq!(halfway.translation, Vec2::new(5.0, 0.0));
+/// assert!((halfway.rotation.to_degrees() - 360.0).abs() < 1e-3);
+/// assert_eq!(halfway.scale, Vec2::splat(1.5));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2D {
+    /// The position.
+    pub translation: glam::Vec2,
+
+    /// The rotation, in radians.
+    pub rotation: f32,
+
+    /// The scale on each axis.
+    pub scale: glam::Vec2,
+}
+
+impl Transform2D {
+    /// The identity transform, which doesn't move, rotate or scale anything.
+    pub const IDENTITY: Self = Self::new(glam::Vec2::ZERO, 0.0, glam::Vec2::ONE);
+
+    /// Creates a new Transform2D.
+    pub const fn new(translation: glam::Vec2, rotation: f32, scale: glam::Vec2) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Converts this transform into an [Affine2](glam::Affine2), which scales, then rotates, then
+    /// translates.
+    pub fn to_affine(self) -> glam::Affine2 {
+        glam::Affine2::from_scale_angle_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform2D> for glam::Affine2 {
+    fn from(transform: Transform2D) -> Self {
+        transform.to_affine()
+    }
+}
+
+impl core::ops::Add for Transform2D {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.translation + rhs.translation,
+            self.rotation + rhs.rotation,
+            self.scale + rhs.scale,
+        )
+    }
+}
+
+impl core::ops::Sub for Transform2D {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.translation - rhs.translation,
+            super::wrap_angle(self.rotation - rhs.rotation),
+            self.scale - rhs.scale,
+        )
+    }
+}
+
+impl crate::TweenValue for Transform2D {
+    fn scale(self, scale: f32) -> Self {
+        Self::new(self.translation * scale, self.rotation * scale, self.scale * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Tweener};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn transform_shortest_rotation() {
+        let start = Transform2D::new(glam::Vec2::ZERO, 3.0, glam::Vec2::ONE);
+        let end = Transform2D::new(glam::Vec2::ZERO, -3.0, glam::Vec2::ONE);
+        let mut tweener = Tweener::new(start, end, 1.0, Linear);
+
+        // the short way from 3.0 to -3.0 goes through π, not 0
+        let halfway = tweener.move_to(0.5);
+        assert_relative_eq!(halfway.rotation, core::f32::consts::PI, epsilon = 1e-5);
+
+        // rotation isn't wrapped, but it ends facing the same way as `end`
+        let rotation = tweener.move_to(1.0).rotation;
+        assert_relative_eq!(crate::math::wrap_angle(rotation), end.rotation, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn transform_affine() {
+        let transform = Transform2D::new(
+            glam::Vec2::new(1.0, 2.0),
+            core::f32::consts::FRAC_PI_2,
+            glam::Vec2::splat(2.0),
+        );
+        let point = transform.to_affine().transform_point2(glam::Vec2::X);
+
+        assert_relative_eq!(point.x, 1.0, epsilon = 1e-5);
+        assert_relative_eq!(point.y, 4.0, epsilon = 1e-5);
+        assert_eq!(glam::Affine2::from(Transform2D::IDENTITY), glam::Affine2::IDENTITY);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn transform_serde_round_trip() {
+        let transform = Transform2D::new(glam::Vec2::new(1.0, -2.0), 0.5, glam::Vec2::splat(3.0));
+
+        let json = serde_json::to_string(&transform).unwrap();
+        assert_eq!(serde_json::from_str::<Transform2D>(&json).unwrap(), transform);
+    }
+
+    #[test]
+    fn quat_matches_slerp() {
+        let start = glam::Quat::from_rotation_z(0.2);
+        let end = glam::Quat::from_euler(glam::EulerRot::YXZ, 0.3, 1.2, -0.4);
+        let mut tweener = Tweener::new(crate::Rotation(start), crate::Rotation(end), 1.0, Linear);
+
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+            let expected = start.slerp(end, pct);
+            assert_relative_eq!(tweener.move_to(pct).0.angle_between(expected), 0.0, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn quat_shortest_path() {
+        // 350° to 10° goes 20° forward, even when `end` has the opposite sign
+        let start = glam::DQuat::from_rotation_z(350f64.to_radians());
+        let end = -glam::DQuat::from_rotation_z(10f64.to_radians());
+        let mut tweener = Tweener::new(crate::Rotation(start), crate::Rotation(end), 1.0, Linear);
+
+        let halfway = tweener.move_to(0.5).0;
+        assert_relative_eq!(halfway.angle_between(glam::DQuat::IDENTITY), 0.0, epsilon = 1e-3);
+    }
+
+    #[cfg(feature = "precise-f64")]
+    #[test]
+    fn dvec_keeps_precision() {
+        use crate::TweenValue;
+
+        let start = glam::DVec3::new(1e8 + 0.123_456_789, -2.0e-9, 0.1);
+        let end = glam::DVec3::new(1e8 + 1.123_456_789, 4.0e-9, 0.3);
+        let mut tweener = Tweener::linear(start, end, 1.0);
+
+        for pct in [0.25f32, 0.5, 0.75] {
+            let expected = start + (end - start) * pct as f64;
+            assert!(tweener.move_to(pct).abs_diff_eq(expected, 1e-12));
+        }
+
+        let half = glam::DVec2::new(0.1, 1e-10).scale(0.5);
+        assert!(half.abs_diff_eq(glam::DVec2::new(0.05, 5e-11), 1e-12));
+    }
+}
@@ -0,0 +1,77 @@
+/// A wrapper which lets [mint] vectors and points be tweened.
+///
+/// mint types are only meant for passing values between math libraries, so they don't implement
+/// `Add` or `Sub`, and the orphan rule stops this crate from adding them. This wrapper adds them
+/// instead, component by component. It's implemented for `Vector2`, `Vector3`, `Vector4`, `Point2`
+/// and `Point3` with the `mint` feature.
+///
+/// ```
+/// # use tween::{Mint, Tweener};
+/// let start = Mint(mint::Point2 { x: 0.0, y: 10.0 });
+/// let end = Mint(mint::Point2 { x: 10.0, y: 0.0 });
+/// let mut tweener = Tweener::linear(start, end, 2.0);
+///
+/// let halfway: mint::Point2<f32> = tweener.move_to(1.0).0;
+/// assert_eq!(halfway, mint::Point2 { x: 5.0, y: 5.0 });
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Mint<T>(pub T);
+
+macro_rules! impl_mint {
+    ($($ty:ident { $($field:ident),* }),*) => {
+        $(
+        impl<T: crate::TweenValue> core::ops::Add for Mint<mint::$ty<T>> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Mint(mint::$ty { $($field: self.0.$field + rhs.0.$field),* })
+            }
+        }
+
+        impl<T: crate::TweenValue> core::ops::Sub for Mint<mint::$ty<T>> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Mint(mint::$ty { $($field: self.0.$field - rhs.0.$field),* })
+            }
+        }
+
+        impl<T: crate::TweenValue> crate::TweenValue for Mint<mint::$ty<T>> {
+            fn scale(self, scale: f32) -> Self {
+                Mint(mint::$ty { $($field: self.0.$field.scale(scale)),* })
+            }
+        }
+
+        impl<T> From<mint::$ty<T>> for Mint<mint::$ty<T>> {
+            fn from(value: mint::$ty<T>) -> Self {
+                Mint(value)
+            }
+        }
+        )*
+    };
+}
+
+impl_mint!(
+    Vector2 { x, y },
+    Vector3 { x, y, z },
+    Vector4 { x, y, z, w },
+    Point2 { x, y },
+    Point3 { x, y, z }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Tweener};
+
+    #[test]
+    fn vectors() {
+        let start = Mint(mint::Vector3 { x: 0, y: 10, z: 20 });
+        let end = Mint(mint::Vector3 { x: 10, y: 10, z: 0 });
+        let mut tweener = Tweener::new(start, end, 10, Linear);
+
+        assert_eq!(tweener.move_to(5).0, mint::Vector3 { x: 5, y: 10, z: 10 });
+    }
+}
@@ -3,7 +3,7 @@ where
     T: crate::TweenValue + nalgebra::ClosedAdd + nalgebra::ClosedSub + nalgebra::Scalar,
 {
     fn scale(self, scale: f32) -> Self {
-        nalgebra::Vector2::new(self[0].scale(scale), self[1].scale(scale))
+        self.map(|v| v.scale(scale))
     }
 }
 
@@ -12,7 +12,7 @@ where
     T: crate::TweenValue + nalgebra::ClosedAdd + nalgebra::ClosedSub + nalgebra::Scalar,
 {
     fn scale(self, scale: f32) -> Self {
-        nalgebra::Vector3::new(self[0].scale(scale), self[1].scale(scale), self[2].scale(scale))
+        self.map(|v| v.scale(scale))
     }
 }
 
@@ -21,11 +21,227 @@ where
     T: crate::TweenValue + nalgebra::ClosedAdd + nalgebra::ClosedSub + nalgebra::Scalar,
 {
     fn scale(self, scale: f32) -> Self {
-        nalgebra::Vector4::new(
-            self[0].scale(scale),
-            self[1].scale(scale),
-            self[2].scale(scale),
-            self[3].scale(scale),
-        )
+        self.map(|v| v.scale(scale))
+    }
+}
+
+macro_rules! impl_length {
+    ($($t:ty),*) => {
+        $(
+        impl crate::TweenLength for nalgebra::Vector2<$t> {
+            fn length(&self) -> f32 {
+                self.norm() as f32
+            }
+        }
+
+        impl crate::TweenLength for nalgebra::Vector3<$t> {
+            fn length(&self) -> f32 {
+                self.norm() as f32
+            }
+        }
+
+        impl crate::TweenLength for nalgebra::Vector4<$t> {
+            fn length(&self) -> f32 {
+                self.norm() as f32
+            }
+        }
+        )*
+    };
+}
+
+impl_length!(f32, f64);
+
+/// A wrapper which lets [nalgebra] points be tweened.
+///
+/// Points can't be [TweenValue](crate::TweenValue)s themselves, since subtracting two points gives
+/// a vector rather than another point. This wrapper tweens them through their `coords` instead.
+/// It's implemented for `Point2` and `Point3` with the `nalgebra` feature.
+///
+/// ```
+/// # use tween::{NaPoint, Tweener};
+/// use nalgebra::Point2;
+///
+/// let mut tweener = Tweener::linear(NaPoint(Point2::new(0.0, 10.0)), NaPoint(Point2::new(10.0, 0.0)), 2.0);
+///
+/// let halfway: Point2<f32> = tweener.move_to(1.0).0;
+/// assert_eq!(halfway, Point2::new(5.0, 5.0));
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct NaPoint<P>(pub P);
+
+macro_rules! impl_point {
+    ($($point:ident),*) => {
+        $(
+        impl<T> core::ops::Add for NaPoint<nalgebra::$point<T>>
+        where
+            T: crate::TweenValue + nalgebra::ClosedAdd + nalgebra::ClosedSub + nalgebra::Scalar,
+        {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                NaPoint(nalgebra::$point::from(self.0.coords + rhs.0.coords))
+            }
+        }
+
+        impl<T> core::ops::Sub for NaPoint<nalgebra::$point<T>>
+        where
+            T: crate::TweenValue + nalgebra::ClosedAdd + nalgebra::ClosedSub + nalgebra::Scalar,
+        {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                NaPoint(nalgebra::$point::from(self.0.coords - rhs.0.coords))
+            }
+        }
+
+        impl<T> crate::TweenValue for NaPoint<nalgebra::$point<T>>
+        where
+            T: crate::TweenValue + nalgebra::ClosedAdd + nalgebra::ClosedSub + nalgebra::Scalar,
+        {
+            fn scale(self, scale: f32) -> Self {
+                NaPoint(self.0.map(|v| v.scale(scale)))
+            }
+        }
+
+        impl crate::TweenLength for NaPoint<nalgebra::$point<f32>> {
+            fn length(&self) -> f32 {
+                self.0.coords.norm()
+            }
+        }
+
+        impl crate::TweenLength for NaPoint<nalgebra::$point<f64>> {
+            fn length(&self) -> f32 {
+                self.0.coords.norm() as f32
+            }
+        }
+
+        impl<T: nalgebra::Scalar> From<nalgebra::$point<T>> for NaPoint<nalgebra::$point<T>> {
+            fn from(point: nalgebra::$point<T>) -> Self {
+                NaPoint(point)
+            }
+        }
+        )*
+    };
+}
+
+impl_point!(Point2, Point3);
+
+macro_rules! impl_unit_quaternion {
+
+    ($($t:ty),*) => {
+        $(
+        impl core::ops::Add for crate::Rotation<nalgebra::UnitQuaternion<$t>> {
+            type Output = Self;
+
+            // adding rotations is composing them
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn add(self, rhs: Self) -> Self {
+                crate::Rotation(self.0 * rhs.0)
+            }
+        }
+
+        impl core::ops::Sub for crate::Rotation<nalgebra::UnitQuaternion<$t>> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                let delta = self.0 * rhs.0.inverse();
+
+                // `q` and `-q` are the same rotation, but only one of them is the short way around
+                if delta.w < 0.0 {
+                    crate::Rotation(nalgebra::UnitQuaternion::new_unchecked(-delta.into_inner()))
+                } else {
+                    crate::Rotation(delta)
+                }
+            }
+        }
+
+        impl crate::TweenValue for crate::Rotation<nalgebra::UnitQuaternion<$t>> {
+            fn scale(self, scale: f32) -> Self {
+                crate::Rotation(self.0.powf(scale as $t))
+            }
+        }
+        )*
+    };
+}
+
+impl_unit_quaternion!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::NaPoint;
+    use crate::{Linear, Rotation, TweenLength, Tweener};
+    use approx::assert_relative_eq;
+    use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+    fn about_z(degrees: f32) -> UnitQuaternion<f32> {
+        UnitQuaternion::from_axis_angle(&Vector3::z_axis(), degrees.to_radians())
+    }
+
+    fn negated(q: UnitQuaternion<f32>) -> UnitQuaternion<f32> {
+        UnitQuaternion::new_unchecked(-q.into_inner())
+    }
+
+    #[test]
+    fn matches_slerp() {
+        let (start, end) = (about_z(10.0), UnitQuaternion::from_euler_angles(0.3, 1.2, -0.4));
+        let mut tweener = Tweener::new(Rotation(start), Rotation(end), 1.0, Linear);
+
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+            assert_relative_eq!(tweener.move_to(pct).0, start.slerp(&end, pct), epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn double_cover() {
+        // -q is the same rotation as q, so tweening between them shouldn't rotate at all
+        let q = about_z(60.0);
+        let mut tweener = Tweener::new(Rotation(q), Rotation(negated(q)), 1.0, Linear);
+        assert_relative_eq!(tweener.move_to(0.5).0.angle_to(&q), 0.0, epsilon = 1e-3);
+
+        // and flipping the sign of a keyframe doesn't change the path
+        let end = about_z(120.0);
+        let mut positive = Tweener::new(Rotation(q), Rotation(end), 1.0, Linear);
+        let mut negative = Tweener::new(Rotation(q), Rotation(negated(end)), 1.0, Linear);
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+            let (a, b) = (positive.move_to(pct).0, negative.move_to(pct).0);
+            assert_relative_eq!(a.angle_to(&b), 0.0, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn shortest_path() {
+        // 350 degrees to 10 degrees should pass through 0, not 180
+        let mut tweener = Tweener::new(Rotation(about_z(350.0)), Rotation(negated(about_z(10.0))), 1.0, Linear);
+
+        let halfway = tweener.move_to(0.5).0;
+        assert_relative_eq!(halfway.angle(), 0.0, epsilon = 1e-3);
+    }
+
+    #[cfg(feature = "precise-f64")]
+    #[test]
+    fn f64_vectors_keep_precision() {
+        let start = Vector3::new(1e8 + 0.123_456_789, -2.0e-9, 0.1f64);
+        let end = Vector3::new(1e8 + 1.123_456_789, 4.0e-9, 0.3);
+        let mut tweener = Tweener::linear(start, end, 1.0);
+
+        for pct in [0.25f32, 0.5, 0.75] {
+            let expected = start + (end - start) * pct as f64;
+            assert_relative_eq!(tweener.move_to(pct), expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn points_through_coords() {
+        let start = NaPoint(Point3::new(1.0f64, 2.0, 3.0));
+        let end = NaPoint::from(Point3::new(3.0, 2.0, -1.0));
+        let mut tweener = Tweener::new(start, end, 4, Linear);
+
+        assert_eq!(tweener.move_to(2).0, Point3::new(2.0, 2.0, 1.0));
+        assert_eq!(tweener.move_to(4), end);
+        assert_relative_eq!((end - start).length(), 20f32.sqrt());
     }
 }
@@ -0,0 +1,169 @@
+use crate::math::float::{cbrt, powf};
+
+/// How a [PwmBrightness] maps the brightness we perceive onto the light an LED actually gives off,
+/// which is proportional to its PWM duty cycle.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BrightnessCurve {
+    /// The CIE 1931 lightness curve, which is the most accurate model of how we see brightness.
+    #[default]
+    Cie,
+
+    /// A simple power curve, raising the perceived brightness to the given gamma. `2.2` is a
+    /// common choice.
+    Gamma(f32),
+}
+
+impl BrightnessCurve {
+    /// Converts a perceived brightness, from `0.0` to `1.0`, into the fraction of light needed to
+    /// show it.
+    pub fn to_linear(self, perceived: f32) -> f32 {
+        let perceived = perceived.clamp(0.0, 1.0);
+
+        match self {
+            Self::Cie => {
+                let lightness = perceived * 100.0;
+                if lightness <= 8.0 {
+                    lightness / 903.3
+                } else {
+                    powf((lightness + 16.0) / 116.0, 3.0)
+                }
+            }
+            Self::Gamma(gamma) => powf(perceived, gamma),
+        }
+    }
+
+    /// Converts a fraction of light, from `0.0` to `1.0`, into how bright it looks. This is the
+    /// inverse of [to_linear](Self::to_linear).
+    pub fn to_perceived(self, linear: f32) -> f32 {
+        let linear = linear.clamp(0.0, 1.0);
+
+        match self {
+            Self::Cie => {
+                let lightness = if linear <= 8.0 / 903.3 {
+                    linear * 903.3
+                } else {
+                    116.0 * cbrt(linear) - 16.0
+                };
+
+                lightness / 100.0
+            }
+            Self::Gamma(gamma) => powf(linear, 1.0 / gamma),
+        }
+    }
+}
+
+/// The brightness of an LED, which tweens in perceived brightness but is read out as a PWM duty
+/// cycle.
+///
+/// Our eyes are far more sensitive to changes in dim light than in bright light, so an LED whose
+/// duty cycle is faded linearly seems to jump up at the start and crawl at the end. Tweening a
+/// PwmBrightness instead fades evenly to the eye, and [duty](Self::duty) turns it into the duty
+/// cycle to write to the timer. This pairs well with a [FixedTweener](crate::FixedTweener) ticking
+/// along with the hardware:
+///
+/// ```
+/// # use tween::{BrightnessCurve, FixedTweener, PwmBrightness};
+/// let fade = FixedTweener::linear(PwmBrightness::OFF, PwmBrightness::FULL, 4, 1);
+/// let duties: Vec<u16> = fade.map(|b| b.duty(BrightnessCurve::Cie, 255)).collect();
+///
+/// // a linear fade of the duty cycle would go 64, 128, 191, 255
+/// assert_eq!(duties, [11, 47, 123, 255]);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PwmBrightness {
+    /// The perceived brightness, from `0.0` for off to `1.0` for full brightness.
+    pub perceived: f32,
+}
+
+impl PwmBrightness {
+    /// An LED which is off.
+    pub const OFF: Self = Self::new(0.0);
+
+    /// An LED at full brightness.
+    pub const FULL: Self = Self::new(1.0);
+
+    /// Creates a new PwmBrightness out of a perceived brightness, from `0.0` to `1.0`.
+    pub const fn new(perceived: f32) -> Self {
+        Self { perceived }
+    }
+
+    /// Creates a new PwmBrightness which shows as bright as a duty cycle of `duty` out of
+    /// `max_duty` does.
+    pub fn from_duty(duty: u16, curve: BrightnessCurve, max_duty: u16) -> Self {
+        Self::new(curve.to_perceived(duty as f32 / max_duty as f32))
+    }
+
+    /// Returns the duty cycle, out of `max_duty`, which shows this brightness. Brightnesses outside
+    /// of `0.0..=1.0`, such as from overshooting tweens, are clamped.
+    pub fn duty(self, curve: BrightnessCurve, max_duty: u16) -> u16 {
+        (curve.to_linear(self.perceived) * max_duty as f32 + 0.5) as u16
+    }
+}
+
+impl core::ops::Add for PwmBrightness {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.perceived + rhs.perceived)
+    }
+}
+
+impl core::ops::Sub for PwmBrightness {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.perceived - rhs.perceived)
+    }
+}
+
+impl crate::TweenValue for PwmBrightness {
+    fn scale(self, scale: f32) -> Self {
+        Self::new(self.perceived * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tweener;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn curves_round_trip() {
+        for curve in [BrightnessCurve::Cie, BrightnessCurve::Gamma(2.2)] {
+            assert_eq!(curve.to_linear(0.0), 0.0);
+            assert_relative_eq!(curve.to_linear(1.0), 1.0, epsilon = 1e-5);
+
+            for i in 0..=20 {
+                let perceived = i as f32 / 20.0;
+                assert_relative_eq!(
+                    curve.to_perceived(curve.to_linear(perceived)),
+                    perceived,
+                    epsilon = 1e-4
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn dim_end_is_gentle() {
+        // half as bright to the eye is much less than half the light
+        let half = PwmBrightness::new(0.5);
+        assert_eq!(half.duty(BrightnessCurve::Cie, 1000), 184);
+        assert_eq!(half.duty(BrightnessCurve::Gamma(2.0), 1000), 250);
+
+        assert_eq!(PwmBrightness::new(1.5).duty(BrightnessCurve::Cie, 255), 255);
+        assert_eq!(PwmBrightness::new(-0.5).duty(BrightnessCurve::Cie, 255), 0);
+    }
+
+    #[test]
+    fn from_duty() {
+        let brightness = PwmBrightness::from_duty(184, BrightnessCurve::Cie, 1000);
+        assert_eq!(brightness.duty(BrightnessCurve::Cie, 1000), 184);
+
+        let mut fade = Tweener::linear(brightness, PwmBrightness::FULL, 2);
+        assert_relative_eq!(fade.move_to(1).perceived, 0.75, epsilon = 1e-3);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::TweenValue;
+
+/// A rectangle, from its `min` corner to its `max` corner, which can be tweened for UI panel
+/// transitions and camera viewports.
+///
+/// `V` is any [TweenValue], such as a `glam::Vec2` or a point type of your own. Tweening moves both
+/// corners together, which is the same as tweening the rectangle's center and its size, so a
+/// rectangle which grows while it moves never distorts.
+///
+/// ```
+/// # use tween::{Rect, Tweener};
+/// let panel = Rect::new(0.0, 100.0);
+/// let expanded = Rect::from_center_size(100.0, 200.0);
+/// let mut tweener = Tweener::linear(panel, expanded, 2.0);
+///
+/// let halfway = tweener.move_to(1.0);
+/// assert_eq!(halfway.center(), 75.0);
+/// assert_eq!(halfway.size(), 150.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect<V> {
+    /// The corner with the lowest coordinates.
+    pub min: V,
+
+    /// The corner with the highest coordinates.
+    pub max: V,
+}
+
+impl<V> Rect<V> {
+    /// Creates a new Rect from its corners.
+    pub const fn new(min: V, max: V) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<V: TweenValue> Rect<V> {
+    /// Creates a new Rect from its center and size.
+    pub fn from_center_size(center: V, size: V) -> Self {
+        let half = size.scale(0.5);
+
+        Self::new(center.clone() - half.clone(), center + half)
+    }
+
+    /// The center of this Rect.
+    pub fn center(&self) -> V {
+        self.min.clone() + self.size().scale(0.5)
+    }
+
+    /// The size of this Rect.
+    pub fn size(&self) -> V {
+        self.max.clone() - self.min.clone()
+    }
+}
+
+impl<V: core::ops::Add<Output = V>> core::ops::Add for Rect<V> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.min + rhs.min, self.max + rhs.max)
+    }
+}
+
+impl<V: core::ops::Sub<Output = V>> core::ops::Sub for Rect<V> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.min - rhs.min, self.max - rhs.max)
+    }
+}
+
+impl<V: TweenValue> TweenValue for Rect<V> {
+    fn scale(self, scale: f32) -> Self {
+        Self::new(self.min.scale(scale), self.max.scale(scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Tweener};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn corners_and_center_agree() {
+        let start = Rect::from_center_size(10.0f32, 4.0);
+        let end = Rect::from_center_size(-20.0f32, 40.0);
+        let mut rects = Tweener::new(start, end, 10, CubicBezier::EASE_IN_OUT);
+        let mut centers = Tweener::new(start.center(), end.center(), 10, CubicBezier::EASE_IN_OUT);
+        let mut sizes = Tweener::new(start.size(), end.size(), 10, CubicBezier::EASE_IN_OUT);
+
+        for time in 0..=10 {
+            let rect = rects.move_to(time);
+            assert_relative_eq!(rect.center(), centers.move_to(time), epsilon = 1e-4);
+            assert_relative_eq!(rect.size(), sizes.move_to(time), epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn integer_rect() {
+        let mut tweener = Tweener::new(Rect::new(0, 10), Rect::new(10, 30), 2, CubicBezier::EASE_IN_OUT);
+
+        assert_eq!(tweener.move_to(2), Rect::new(10, 30));
+    }
+}
@@ -0,0 +1,68 @@
+// `rgb` colors are sRGB, whose channels are gamma encoded, so they aren't `TweenValue`s: scaling
+// those channels directly gives muddy, dark fades. Instead, they're `Interpolate`, through
+// `LinearRgba`, so an `Interpolator` fades them in linear light and hands back sRGB.
+
+impl crate::Interpolate for rgb::RGBA<f32> {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        crate::lerp(crate::LinearRgba::from(*a), crate::LinearRgba::from(*b), t).into()
+    }
+}
+
+impl crate::Interpolate for rgb::RGB<f32> {
+    fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let c = <rgb::RGBA<f32> as crate::Interpolate>::lerp(&a.with_alpha(1.0), &b.with_alpha(1.0), t);
+
+        rgb::RGB::new(c.r, c.g, c.b)
+    }
+}
+
+impl From<rgb::RGBA<f32>> for crate::LinearRgba {
+    /// Converts an sRGB color into linear space.
+    fn from(c: rgb::RGBA<f32>) -> Self {
+        Self::from_srgb([c.r, c.g, c.b, c.a])
+    }
+}
+
+impl From<crate::LinearRgba> for rgb::RGBA<f32> {
+    /// Converts a linear color back into sRGB.
+    fn from(c: crate::LinearRgba) -> Self {
+        let [r, g, b, a] = c.to_srgb();
+
+        rgb::RGBA::new(r, g, b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Interpolator, Linear, LinearRgba, Tweener};
+    use approx::assert_relative_eq;
+    use rgb::{RGB, RGBA};
+
+    #[test]
+    fn fades_in_linear_light() {
+        let (red, blue) = (RGBA::new(1.0f32, 0.0, 0.0, 1.0), RGBA::new(0.0, 0.0, 1.0, 0.0));
+        let mut fade = Interpolator::new(red, blue, 2.0, Linear);
+        let mut linear = Tweener::linear(LinearRgba::from(red), LinearRgba::from(blue), 2.0);
+
+        let halfway = fade.move_to(1.0);
+        let expected = RGBA::from(linear.move_to(1.0));
+        assert_relative_eq!(halfway.r, expected.r, epsilon = 1e-6);
+        assert_relative_eq!(halfway.b, expected.b, epsilon = 1e-6);
+        assert_relative_eq!(halfway.a, 0.5);
+
+        // a gamma space fade would be 0.5 here, which looks too dark
+        assert!(halfway.r > 0.7);
+
+        let mut opaque = Interpolator::new(RGB::new(1.0f32, 0.0, 0.0), RGB::new(0.0, 0.0, 1.0), 2.0, Linear);
+        assert_relative_eq!(opaque.move_to(1.0).r, halfway.r, epsilon = 1e-6);
+        assert_relative_eq!(opaque.move_to(2.0).b, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn linear_round_trip() {
+        let color = RGBA::new(0.25f32, 0.5, 0.75, 1.0);
+        let back = RGBA::from(LinearRgba::from(color));
+
+        assert!((back.r - color.r).abs() < 1e-5 && (back.b - color.b).abs() < 1e-5);
+    }
+}
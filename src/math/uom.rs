@@ -0,0 +1,109 @@
+// Every `uom` quantity stored as an `f32` or `f64` can be tweened, with its dimension kept in the
+// type, so a tween between two lengths can only ever give a length. Quantities are scaled in their
+// base units, which is why the units a value was made in never matter.
+//
+// `Time` quantities can also be used as the time of a tween, where they're converted to and from
+// seconds.
+
+use core::marker::PhantomData;
+use uom::si::{Dimension, Quantity, Units};
+
+macro_rules! impl_quantity {
+    ($($t:ident),*) => {
+        $(
+        impl<D, U> crate::TweenValue for Quantity<D, U, $t>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: uom::marker::Add + uom::marker::Sub,
+            U: Units<$t> + ?Sized,
+        {
+            fn scale(self, scale: f32) -> Self {
+                Quantity {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: crate::TweenValue::scale(self.value, scale),
+                }
+            }
+        }
+
+        impl<D, U> crate::TweenLength for Quantity<D, U, $t>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: uom::marker::Add + uom::marker::Sub,
+            U: Units<$t> + ?Sized,
+        {
+            fn length(&self) -> f32 {
+                crate::TweenLength::length(&self.value)
+            }
+        }
+
+        impl crate::TweenTime for uom::si::$t::Time {
+            const ZERO: Self = Quantity {
+                dimension: PhantomData,
+                units: PhantomData,
+                value: 0.0,
+            };
+
+            #[inline(always)]
+            fn to_f32(self) -> f32 {
+                crate::TweenTime::to_f32(self.value)
+            }
+
+            #[inline(always)]
+            fn from_f32(value: f32) -> Self {
+                Quantity {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: <$t as crate::TweenTime>::from_f32(value),
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_quantity!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, Tweener};
+    use approx::assert_relative_eq;
+    use uom::si::{
+        angle::{degree, radian},
+        f32::{Angle, Length, Time},
+        f64,
+        length::{centimeter, meter},
+        time::{millisecond, second},
+        velocity::meter_per_second,
+    };
+
+    #[test]
+    fn lengths_over_time() {
+        let start = Length::new::<meter>(1.0);
+        let end = Length::new::<centimeter>(300.0);
+        let mut tweener = Tweener::new(start, end, Time::new::<second>(2.0), Linear);
+
+        let halfway = tweener.move_by(Time::new::<millisecond>(1000.0));
+        assert_relative_eq!(halfway.get::<meter>(), 2.0);
+        assert!(!tweener.is_finished());
+
+        tweener.move_by(Time::new::<second>(1.0));
+        assert!(tweener.is_finished());
+    }
+
+    #[test]
+    fn angles_and_velocities() {
+        let mut turn = Tweener::new(Angle::new::<degree>(0.0), Angle::new::<degree>(180.0), 1.0, Linear);
+        assert_relative_eq!(turn.move_to(1.0).get::<radian>(), core::f32::consts::PI);
+
+        let mut speed = Tweener::linear(
+            f64::Velocity::new::<meter_per_second>(0.0),
+            f64::Velocity::new::<meter_per_second>(10.0),
+            f64::Time::new::<second>(4.0),
+        );
+        assert_relative_eq!(
+            speed.move_to(f64::Time::new::<second>(1.0)).get::<meter_per_second>(),
+            2.5
+        );
+    }
+}
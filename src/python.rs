@@ -0,0 +1,224 @@
+use crate::{Easing, Keyframe, KeyframesVec, Tweener};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::{format, string::String, vec::Vec};
+
+/// An [Easing], picked by name from Python.
+#[pyclass(name = "Easing", module = "tween", frozen, eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PyEasing(Easing);
+
+#[pymethods]
+impl PyEasing {
+    #[new]
+    fn py_new(name: &str) -> PyResult<Self> {
+        parse_easing(name).map(Self)
+    }
+
+    /// The names of every easing, in the order they are declared.
+    #[staticmethod]
+    fn names() -> Vec<&'static str> {
+        Easing::ALL.iter().filter_map(|easing| easing.name()).collect()
+    }
+
+    #[getter]
+    fn name(&self) -> Option<&'static str> {
+        self.0.name()
+    }
+
+    /// Eases `percent` (from `0.0` to `1.0`), scaled by `value_delta`.
+    #[pyo3(signature = (percent, value_delta = 1.0))]
+    fn ease(&self, percent: f32, value_delta: f64) -> f64 {
+        let mut easing = self.0;
+        easing.tween(value_delta, percent)
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0.name() {
+            Some(name) => format!("Easing({name:?})"),
+            None => format!("{:?}", self.0),
+        }
+    }
+}
+
+impl From<Easing> for PyEasing {
+    fn from(easing: Easing) -> Self {
+        Self(easing)
+    }
+}
+
+/// Anywhere Python hands us an easing, it can be an `Easing` or just its name.
+#[derive(FromPyObject)]
+enum EasingArg {
+    Easing(PyEasing),
+    Name(String),
+}
+
+impl EasingArg {
+    fn into_easing(self) -> PyResult<Easing> {
+        match self {
+            EasingArg::Easing(easing) => Ok(easing.0),
+            EasingArg::Name(name) => parse_easing(&name),
+        }
+    }
+}
+
+fn parse_easing(name: &str) -> PyResult<Easing> {
+    name.parse()
+        .map_err(|e| PyValueError::new_err(format!("{e}: `{name}`")))
+}
+
+/// A [Tweener] over floats, for Python.
+#[pyclass(name = "Tweener", module = "tween")]
+#[derive(Debug, Clone)]
+pub struct PyTweener(Tweener<f64, f64, Easing>);
+
+#[pymethods]
+impl PyTweener {
+    #[new]
+    #[pyo3(signature = (start, end, duration, easing = EasingArg::Name(String::from("linear"))))]
+    fn py_new(start: f64, end: f64, duration: f64, easing: EasingArg) -> PyResult<Self> {
+        Ok(Self(Tweener::new(start, end, duration, easing.into_easing()?)))
+    }
+
+    /// Moves to `position`, returning the value there.
+    fn move_to(&mut self, position: f64) -> f64 {
+        self.0.move_to(position)
+    }
+
+    /// Moves forward by `delta`, returning the new value.
+    fn move_by(&mut self, delta: f64) -> f64 {
+        self.0.move_by(delta)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+
+    #[getter]
+    fn current_time(&self) -> f64 {
+        self.0.current_time
+    }
+
+    #[getter]
+    fn duration(&self) -> f64 {
+        self.0.duration
+    }
+
+    /// Returns the value at each of `times`, without moving this tweener.
+    fn sample(&self, times: Vec<f64>) -> Vec<f64> {
+        let mut tweener = self.0;
+        times.into_iter().map(|time| tweener.move_to(time)).collect()
+    }
+}
+
+/// [Keyframes](crate::Keyframes) over floats, for Python. These are made from a list of `(time,
+/// value, easing)` tuples, sorted by time.
+#[pyclass(name = "Keyframes", module = "tween")]
+#[derive(Debug, Clone)]
+pub struct PyKeyframes(KeyframesVec<f64, f64, Easing>);
+
+#[pymethods]
+impl PyKeyframes {
+    #[new]
+    fn py_new(keyframes: Vec<(f64, f64, EasingArg)>) -> PyResult<Self> {
+        let keyframes = keyframes
+            .into_iter()
+            .map(|(time, value, easing)| Ok(Keyframe::new(time, value, easing.into_easing()?)))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        // `Keyframes::new` panics on these, but Python should get an exception instead
+        if keyframes.is_empty() {
+            return Err(PyValueError::new_err("keyframes cannot be empty"));
+        }
+        if !keyframes.windows(2).all(|w| w[0].time <= w[1].time) {
+            return Err(PyValueError::new_err("keyframes must be sorted by time"));
+        }
+
+        Ok(Self(KeyframesVec::new(keyframes)))
+    }
+
+    /// Moves to `position`, returning the value there.
+    fn move_to(&mut self, position: f64) -> f64 {
+        self.0.move_to(position)
+    }
+
+    /// Moves forward by `delta`, returning the new value.
+    fn move_by(&mut self, delta: f64) -> f64 {
+        self.0.move_by(delta)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+
+    #[getter]
+    fn duration(&self) -> f64 {
+        self.0.duration()
+    }
+
+    /// Returns the value at each of `times`, without moving these keyframes.
+    fn sample(&self, times: Vec<f64>) -> Vec<f64> {
+        let mut keyframes = self.0.clone();
+        times.into_iter().map(|time| keyframes.move_to(time)).collect()
+    }
+}
+
+/// The `tween` Python module.
+#[pymodule]
+#[pyo3(name = "tween")]
+pub fn python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyEasing>()?;
+    m.add_class::<PyTweener>()?;
+    m.add_class::<PyKeyframes>()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn run(code: &str) -> PyResult<()> {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "tween")?;
+            python_module(&module)?;
+
+            let globals = PyDict::new_bound(py);
+            globals.set_item("tween", module)?;
+            py.run_bound(code, Some(&globals), None)
+        })
+    }
+
+    #[test]
+    fn tweener_parity() {
+        let expected = Tweener::quad_in(0.0, 100.0, 10.0).move_to(5.0);
+
+        run(&format!(
+            "t = tween.Tweener(0.0, 100.0, 10.0, 'quad_in')\n\
+             assert t.move_to(5.0) == {expected}\n\
+             assert t.sample([0.0, 10.0]) == [0.0, 100.0]\n\
+             assert t.current_time == 5.0\n\
+             assert tween.Easing('quad_in').ease(0.5) == 0.25\n\
+             assert 'sine_in_out' in tween.Easing.names()"
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn keyframes() {
+        run("k = tween.Keyframes([(0.0, 0.0, 'linear'), (10.0, 100.0, tween.Easing('linear')), (20.0, 50.0, 'linear')])\n\
+             assert k.sample([5.0, 15.0, 30.0]) == [50.0, 75.0, 50.0]\n\
+             assert k.duration == 20.0")
+            .unwrap();
+    }
+
+    #[test]
+    fn errors() {
+        run("try:\n    tween.Tweener(0.0, 1.0, 1.0, 'wobbly')\n    assert False\nexcept ValueError:\n    pass")
+            .unwrap();
+        run("try:\n    tween.Keyframes([])\n    assert False\nexcept ValueError:\n    pass").unwrap();
+    }
+}
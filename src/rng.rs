@@ -0,0 +1,96 @@
+/// A source of random numbers for the parts of the library which use randomness, such as
+/// [Tweener::scatter_with](crate::Tweener::scatter_with).
+///
+/// Everything random in this library goes through this trait, so you can make it reproducible by
+/// passing in a seeded generator. [XorShift64] is a tiny, `no_std` implementation which is used
+/// whenever you just pass a seed. With the `rand` feature, every `rand_core::RngCore` is a
+/// TweenRng too.
+pub trait TweenRng {
+    /// Returns the next `u64` in the sequence.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns an `f32` in the range `0.0..1.0`.
+    fn next_f32(&mut self) -> f32 {
+        // the top 24 bits fit exactly into an f32's mantissa
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<R: rand_core::RngCore> TweenRng for R {
+    fn next_u64(&mut self) -> u64 {
+        rand_core::RngCore::next_u64(self)
+    }
+}
+
+/// A tiny, seedable xorshift generator. This is the default [TweenRng], used wherever we need
+/// "random but reproducible" numbers without pulling in `rand`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XorShift64(u64);
+
+impl XorShift64 {
+    /// Creates a new generator out of a seed. Any seed is valid, including `0`.
+    pub fn new(seed: u64) -> Self {
+        // run the seed through a splitmix step so that nearby seeds (0, 1, 2...) give
+        // wildly different sequences, and so that we never end up with a zero state.
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        Self(if z == 0 { 0x9E37_79B9_7F4A_7C15 } else { z })
+    }
+}
+
+impl TweenRng for XorShift64 {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let mut a = XorShift64::new(42);
+        let mut b = XorShift64::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn unit_range() {
+        let mut rng = XorShift64::new(0);
+
+        for _ in 0..10_000 {
+            let v = rng.next_f32();
+            assert!((0.0..1.0).contains(&v));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rand_adapter() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let mut a = StdRng::seed_from_u64(7);
+        let mut b = StdRng::seed_from_u64(7);
+
+        for _ in 0..100 {
+            let v = TweenRng::next_f32(&mut a);
+            assert!((0.0..1.0).contains(&v));
+            assert_eq!(v, TweenRng::next_f32(&mut b));
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::Tween;
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The tolerance [assert_snapshot](crate::assert_snapshot) uses when none is given.
+pub const SNAPSHOT_TOLERANCE: f32 = 1e-5;
+
+/// Samples `tween` at `samples` evenly spaced percents, from `0.0` to `1.0`, and writes them out as
+/// a stable, human readable dump, with one `percent value` pair per line.
+///
+/// This is for pinning custom curves against accidental changes: check the dump into your tests,
+/// and compare against it with [assert_snapshot](crate::assert_snapshot). The tween is run with a
+/// `value_delta` of `1.0`, so the values are the curve itself.
+///
+/// ```
+/// # #[cfg(feature = "quad")] {
+/// # use tween::{snapshot, QuadIn};
+/// assert_eq!(snapshot(QuadIn, 3), "0.0000 0.000000\n0.5000 0.250000\n1.0000 1.000000\n");
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `samples` is less than `2`.
+pub fn snapshot<T: Tween<f32>>(mut tween: T, samples: usize) -> String {
+    assert!(samples >= 2, "a snapshot needs at least two samples");
+
+    let mut dump = String::new();
+    for i in 0..samples {
+        let percent = i as f32 / (samples - 1) as f32;
+        let mut value = tween.tween(1.0, percent);
+
+        // `-0.0` and `0.0` are the same sample, so they should be written the same
+        if value == 0.0 {
+            value = 0.0;
+        }
+
+        // writing to a `String` can't fail
+        let _ = writeln!(dump, "{percent:.4} {value:.6}");
+    }
+
+    dump
+}
+
+/// Where a snapshot differs from the one it was compared against, returned by
+/// [compare_snapshot].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SnapshotMismatch {
+    /// The line where the snapshots differ, counting from `1`.
+    pub line: usize,
+
+    /// The expected line, or an empty string if there wasn't one.
+    pub expected: String,
+
+    /// The actual line, or an empty string if there wasn't one.
+    pub actual: String,
+}
+
+impl core::fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "snapshot differs at line {}: expected `{}`, got `{}`",
+            self.line, self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotMismatch {}
+
+/// Compares two snapshots made by [snapshot], allowing each value to differ by up to `tolerance`.
+/// Blank lines are ignored, so trailing newlines and line endings don't matter.
+///
+/// Returns the first line which differs, if any do.
+pub fn compare_snapshot(actual: &str, expected: &str, tolerance: f32) -> Result<(), SnapshotMismatch> {
+    let mut actual_lines = actual.lines().map(str::trim).filter(|line| !line.is_empty());
+    let mut expected_lines = expected.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    for line in 1.. {
+        let (actual, expected) = match (actual_lines.next(), expected_lines.next()) {
+            (None, None) => return Ok(()),
+            (actual, expected) => (actual.unwrap_or_default(), expected.unwrap_or_default()),
+        };
+
+        let matches = match (parse_sample(actual), parse_sample(expected)) {
+            (Some((actual_pct, actual_value)), Some((expected_pct, expected_value))) => {
+                (actual_pct - expected_pct).abs() <= 1e-4 && (actual_value - expected_value).abs() <= tolerance
+            }
+            // lines which aren't samples have to match exactly
+            _ => actual == expected,
+        };
+
+        if !matches {
+            return Err(SnapshotMismatch {
+                line,
+                expected: String::from(expected),
+                actual: String::from(actual),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_sample(line: &str) -> Option<(f32, f32)> {
+    let (percent, value) = line.split_once(' ')?;
+
+    Some((percent.parse().ok()?, value.trim().parse().ok()?))
+}
+
+/// Asserts that a [snapshot](crate::snapshot()) matches an expected one, usually checked in and
+/// loaded with `include_str!`. Each value may differ by up to the tolerance, which defaults to
+/// [SNAPSHOT_TOLERANCE].
+///
+/// On failure, this panics with the first line which differs, followed by the whole new snapshot,
+/// so it can be copied over the old one if the change was intended.
+///
+/// ```
+/// # #[cfg(feature = "sine")] {
+/// # use tween::{assert_snapshot, snapshot, SineOut};
+/// let expected = "0.0000 0.000000\n0.5000 0.707107\n1.0000 1.000000\n";
+///
+/// assert_snapshot!(snapshot(SineOut, 3), expected);
+/// assert_snapshot!(snapshot(SineOut, 3), "0.0 0.0\n0.5 0.7071\n1.0 1.0", 1e-4);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assert_snapshot!($actual, $expected, $crate::SNAPSHOT_TOLERANCE)
+    };
+    ($actual:expr, $expected:expr, $tolerance:expr $(,)?) => {{
+        let actual: &str = &$actual;
+        if let ::core::result::Result::Err(mismatch) = $crate::compare_snapshot(actual, &$expected, $tolerance) {
+            ::core::panic!("{}\n\nthe new snapshot is:\n{}", mismatch, actual);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear};
+
+    #[test]
+    fn stable_format() {
+        let dump = snapshot(Linear, 5);
+
+        assert_eq!(dump.lines().count(), 5);
+        assert_eq!(dump.lines().nth(1), Some("0.2500 0.250000"));
+        assert_eq!(snapshot(|_: f32, _| -0.0, 2), "0.0000 0.000000\n1.0000 0.000000\n");
+    }
+
+    #[test]
+    fn tolerance() {
+        let dump = snapshot(CubicBezier::EASE_OUT, 11);
+        let nudged = dump.replace("1.000000", "1.000004");
+
+        assert_eq!(compare_snapshot(&dump, &nudged, 1e-5), Ok(()));
+        assert_eq!(compare_snapshot(&dump, &(dump.clone() + "\r\n\n"), 0.0), Ok(()));
+        assert!(compare_snapshot(&dump, &nudged, 1e-7).is_err());
+    }
+
+    #[test]
+    fn mismatch_line() {
+        let mismatch = compare_snapshot("0.0 0.0\n1.0 1.0", "0.0 0.0\n1.0 0.5", 1e-5).unwrap_err();
+        assert_eq!(mismatch.line, 2);
+        assert_eq!(mismatch.expected, "1.0 0.5");
+
+        let mismatch = compare_snapshot("0.0 0.0", "0.0 0.0\n1.0 1.0", 1e-5).unwrap_err();
+        assert_eq!((mismatch.line, mismatch.actual.as_str()), (2, ""));
+    }
+
+    #[test]
+    #[should_panic(expected = "the new snapshot is")]
+    fn assertion_panics() {
+        assert_snapshot!(snapshot(Linear, 2), "0.0 0.0\n1.0 0.9");
+    }
+}
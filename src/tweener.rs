@@ -1,12 +1,81 @@
-use crate::{Tween, TweenTime, TweenValue};
+use crate::{Tween, TweenLength, TweenTime, TweenValue};
 
+mod blend;
+mod chunks;
+mod clamped;
+mod crossfade;
+mod cycle;
+mod delay;
+mod dither;
 mod extrapolator;
+mod fade_pair;
+mod handoff;
+#[cfg(feature = "std")]
+mod instant;
+mod interpolator;
 mod looper;
+#[cfg(feature = "alloc")]
+mod manager;
+mod map_value;
+mod mirror;
 mod oscillator;
+mod output_clamp;
+mod param_smoother;
+mod phase_shift;
+mod pid;
+mod playback;
+mod relative;
+mod repeat;
+mod reverse;
+#[cfg(feature = "alloc")]
+mod rewind;
+mod scatter;
+mod smoother;
+mod sync_group;
+mod threshold;
+mod time_scale;
+mod weighted;
 
+pub use blend::{Blend, Morph};
+pub use chunks::ByChunks;
+pub use clamped::{ClampedTween, ENDPOINT_TOLERANCE, clamp_percent, percent_at_end, percent_at_start};
+pub use crossfade::Crossfade;
+pub use cycle::Cyclic;
+pub use delay::Delay;
+pub use dither::Dithered;
 pub use extrapolator::Extrapolator;
+pub use fade_pair::FadePair;
+pub use handoff::{HandoffError, bridge_handoffs, check_handoffs};
+#[cfg(feature = "std")]
+pub use instant::InstantTweener;
+pub use interpolator::Interpolator;
 pub use looper::Looper;
+#[cfg(feature = "profile")]
+pub use manager::UpdateProfile;
+#[cfg(feature = "alloc")]
+pub use manager::{
+    Blackboard, Completion, Layer, Preemption, TimedCommand, TweenCommand, TweenHandle, TweenLog, TweenManager,
+    TweenRecorder, TypedHandle, UnrecordedChangesError,
+};
+pub use map_value::MapValue;
+pub use mirror::Mirror;
 pub use oscillator::Oscillator;
+pub use output_clamp::OutputClamp;
+pub use param_smoother::{ParamSmoother, RampShape};
+pub use phase_shift::PhaseShift;
+pub use pid::{PidFollower, PidGains};
+pub use playback::Direction;
+pub use relative::RelativeTo;
+pub use repeat::{PingPong, Repeat};
+pub use reverse::Reverse;
+#[cfg(feature = "alloc")]
+pub use rewind::{Rewind, Step};
+pub use scatter::{Jitter, Scatter};
+pub use smoother::Smoother;
+pub use sync_group::{SyncGroup, SyncWindow};
+pub use threshold::Threshold;
+pub use time_scale::TimeScale;
+pub use weighted::{Compose, Weighted, WeightedSum};
 
 /// A Tweener is a wrapper around a Tween. Although you can tween dynamically using just a raw
 /// Tween, this struct will manage state and allow for more naturalistic handling.
@@ -92,6 +161,7 @@ pub use oscillator::Oscillator;
 /// [is_finished]: Tweener::is_finished
 /// [is_valid]: Tweener::is_valid
 #[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tweener<Value, Time, T: ?Sized> {
     /// The current time of the Tweener. You can change this value at will without running the
     /// Tween, or change it with `move_by`.
@@ -103,6 +173,11 @@ pub struct Tweener<Value, Time, T: ?Sized> {
     values: (Value, Value),
     value_delta: Value,
 
+    #[cfg_attr(feature = "serde", serde(default))]
+    paused: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    direction: Direction,
+
     /// The actual underlying Tween.
     pub tween: T,
 }
@@ -117,9 +192,11 @@ where
     /// duration.
     pub fn new(start: Value, end: Value, duration: Time, tween: T) -> Self {
         Self {
+            value_delta: end.clone() - start.clone(),
             values: (start, end),
-            value_delta: end - start,
             duration,
+            paused: false,
+            direction: Direction::Forward,
             tween,
             current_time: Time::ZERO,
         }
@@ -132,9 +209,11 @@ where
     /// a delay". See the example in `examples/delayed_tween.rs`
     pub fn new_at(start: Value, end: Value, duration: Time, tween: T, current_time: Time) -> Self {
         Self {
+            value_delta: end.clone() - start.clone(),
             values: (start, end),
-            value_delta: end - start,
             duration,
+            paused: false,
+            direction: Direction::Forward,
             tween,
             current_time,
         }
@@ -148,10 +227,35 @@ where
             duration: self.duration,
             values: self.values,
             value_delta: self.value_delta,
+            paused: self.paused,
+            direction: self.direction,
             tween: f(self.tween),
         }
     }
 
+    /// Swaps in a new [Tween], returning the old one. The Tweener keeps its place, so it carries on
+    /// from the same time, start and end, but follows the new curve from there on.
+    ///
+    /// This is for tools which let users change the easing of an animation as it plays, and see
+    /// the difference straight away. The output jumps to the new curve on the next move, so it's
+    /// not meant for switching easings mid-animation in a game. To switch between different kinds
+    /// of tween, use an [Easing](crate::Easing) or a `Box<dyn Tween<Value>>`, or use [map](Self::map)
+    /// to change the type outright.
+    ///
+    /// ```
+    /// # #[cfg(feature = "quad")] {
+    /// # use tween::{Easing, Tweener};
+    /// let mut tweener = Tweener::new(0.0, 100.0, 10.0, Easing::Linear);
+    /// assert_eq!(tweener.move_to(5.0), 50.0);
+    ///
+    /// assert_eq!(tweener.set_tween(Easing::QuadIn), Easing::Linear);
+    /// assert_eq!(tweener.move_by(0.0), 25.0);
+    /// # }
+    /// ```
+    pub fn set_tween(&mut self, tween: T) -> T {
+        core::mem::replace(&mut self.tween, tween)
+    }
+
     /// Moves the tween to a given Time. If this Tween previously was outside
     /// `0..=1` in parametric (percentage) space, ie. outside the duration of the tween or in
     /// negative time, this can move it back into bounds.
@@ -162,41 +266,207 @@ where
     /// always clamp the output time**.
     #[inline(always)]
     pub fn move_to(&mut self, position: Time) -> Value {
+        #[cfg(feature = "tracing")]
+        self.trace_move(position);
+
         self.current_time = position;
 
-        let pct = position.to_f32() / self.duration.to_f32();
-        if self.tween.is_finite() {
-            if pct < 0.0 {
-                return self.values.0;
-            } else if pct > 1.0 {
-                return self.values.1;
+        self.value_at(position.to_f32() / self.duration.to_f32())
+    }
+
+    /// Returns the value at `pct` of the way through the tween, clamping like
+    /// [move_to](Self::move_to), without moving the Tweener.
+    #[inline(always)]
+    fn value_at(&mut self, pct: f32) -> Value {
+        Self::value_with(&mut self.tween, &self.values, &self.value_delta, pct)
+    }
+
+    /// [value_at](Self::value_at), but with any `tween`, so that it can be run on a copy.
+    #[inline(always)]
+    fn value_with(tween: &mut T, values: &(Value, Value), value_delta: &Value, pct: f32) -> Value {
+        if tween.is_finite() {
+            if pct < 0.0 || (pct > tween.span() && tween.returns_to_start()) {
+                return values.0.clone();
+            } else if pct > tween.span() {
+                return values.1.clone();
             }
         }
 
-        self.tween.tween(self.value_delta, pct) + self.values.0
+        tween.tween(value_delta.clone(), pct) + values.0.clone()
     }
 
     /// Drives the [Tweener] forward X steps in time.
     ///
     /// If an input higher than the tween's `duration` is given, you will
     /// receive the max value of the tween.
+    ///
+    /// While the Tweener is [paused](Self::pause), this doesn't move it at all, and while its
+    /// [direction](Self::set_direction) is [Direction::Backward], this moves it back towards its
+    /// start instead.
     #[inline]
     pub fn move_by(&mut self, delta: Time) -> Value {
-        self.current_time += delta;
+        if self.paused {
+            return self.move_to(self.current_time);
+        }
 
-        self.move_to(self.current_time)
+        match self.direction {
+            Direction::Forward => self.move_to(self.current_time.saturating_add(delta)),
+            Direction::Backward => self.move_to(self.rewound_by(delta)),
+        }
+    }
+
+    /// Drives the [Tweener] by `delta`, just like [move_by](Self::move_by), but returns how much
+    /// the value changed, rather than the value itself.
+    ///
+    /// This is for consumers which apply changes rather than set values, like physics impulses or
+    /// scroll offsets. Summing every delta gives back the distance from where the Tweener was
+    /// to where it is now, so nothing drifts, even with uneven frame times.
+    ///
+    /// The change is measured from the Tweener's [current_time](Self::current_time), so moving it
+    /// yourself in between calls is taken into account. Since the change can be negative, this is
+    /// best used with signed values.
+    ///
+    /// The value at the starting time is found on a copy of the tween, so tweens which keep state
+    /// between calls, like a [Threshold](crate::Threshold), only step once per call.
+    ///
+    /// ```
+    /// # #[cfg(feature = "quad")] {
+    /// # use tween::Tweener;
+    /// let mut scroll = Tweener::quad_out(0, 100, 10);
+    /// let mut offset = 0;
+    ///
+    /// offset += scroll.advance_delta(5);
+    /// assert_eq!(offset, 75);
+    /// assert_eq!(scroll.advance_delta(5), 25);
+    /// assert_eq!(scroll.advance_delta(5), 0);
+    /// # }
+    /// ```
+    pub fn advance_delta(&mut self, delta: Time) -> Value
+    where
+        T: Clone,
+    {
+        let pct = self.current_time.to_f32() / self.duration.to_f32();
+        let before = Self::value_with(&mut self.tween.clone(), &self.values, &self.value_delta, pct);
+
+        self.move_by(delta) - before
+    }
+
+    /// Returns approximately how far the value has travelled along its path, from the start of the
+    /// tween up to the `current_time`. Unlike the straight-line distance between the start and
+    /// the current value, this counts every curve, overshoot and bounce along the way.
+    ///
+    /// This is handy for effects which depend on distance rather than time, like spawning dust
+    /// every meter or spacing out footsteps.
+    ///
+    /// The path is measured by sampling the tween, 64 times for every `duration` it has run, so
+    /// this gets slower the longer an infinite tween (like a [Looper]) runs. It measures the path
+    /// up to the current time, so running [backward](Direction::Backward) makes it shrink.
+    ///
+    /// The samples are taken from a copy of the tween, so tweens which keep state between calls,
+    /// like a [Threshold](crate::Threshold), carry on exactly as they would have.
+    ///
+    /// ```
+    /// # #[cfg(feature = "back")] {
+    /// # use tween::{Tweener, BackOut};
+    /// let mut tweener = Tweener::new(0.0, 10.0, 1.0, BackOut);
+    /// tweener.move_to(1.0);
+    ///
+    /// // it overshoots past 10, and then comes back
+    /// assert!(tweener.arc_length_so_far() > 10.5);
+    /// # }
+    /// ```
+    pub fn arc_length_so_far(&self) -> f32
+    where
+        Value: TweenLength,
+        T: Clone,
+    {
+        const SAMPLES_PER_DURATION: f32 = 64.0;
+
+        let mut elapsed = self.current_time.to_f32() / self.duration.to_f32();
+        if self.tween.is_finite() {
+            elapsed = elapsed.min(self.tween.span());
+        }
+        if elapsed.is_nan() || elapsed <= 0.0 {
+            return 0.0;
+        }
+
+        // rounding up keeps whole durations on sample points, so turnarounds aren't cut short
+        let scaled = elapsed * SAMPLES_PER_DURATION;
+        let mut samples = (scaled as usize).max(1);
+        if (samples as f32) < scaled {
+            samples += 1;
+        }
+
+        let mut probe = self.clone();
+        let mut last = probe.value_at(0.0);
+        let mut length = 0.0;
+        for i in 1..=samples {
+            let next = probe.value_at(elapsed * i as f32 / samples as f32);
+            length += (next.clone() - last).length();
+            last = next;
+        }
+
+        length
     }
 
     /// The initial value a tween was set to start at.
     #[inline]
     pub fn initial_value(&self) -> Value {
-        self.values.0
+        self.values.0.clone()
     }
 
     /// The final value the tween should end at.
     #[inline]
     pub fn final_value(&self) -> Value {
-        self.values.1
+        self.values.1.clone()
+    }
+
+    /// Redirects this Tweener towards a new end value, starting from wherever it is right now, so
+    /// that its output doesn't jump. The tween then restarts, running over the whole `duration`
+    /// again.
+    ///
+    /// This is for tweens whose target moves while they're in flight, like a follow-camera.
+    ///
+    /// ```
+    /// # use tween::Tweener;
+    /// let mut tweener = Tweener::linear(0.0, 10.0, 10.0);
+    /// assert_eq!(tweener.move_to(5.0), 5.0);
+    ///
+    /// tweener.retarget(-5.0);
+    /// assert_eq!(tweener.move_by(0.0), 5.0);
+    /// assert_eq!(tweener.move_by(5.0), 0.0);
+    /// ```
+    pub fn retarget(&mut self, end: Value) {
+        let current = self.move_to(self.current_time);
+
+        self.value_delta = end.clone() - current.clone();
+        self.values = (current, end);
+        self.current_time = Time::ZERO;
+    }
+
+    /// Changes the duration of this Tweener, scaling its [current_time] so that it keeps its
+    /// progress, and so that its output doesn't jump. For example, a Tweener halfway through is
+    /// still halfway through, but will take more or less time to finish.
+    ///
+    /// For integer [TweenTime]s, the new `current_time` is rounded towards zero.
+    ///
+    /// A Tweener with a duration of zero has no progress to keep, since it's finished as soon as
+    /// it starts. Giving it a new duration leaves a waiting Tweener (with a negative
+    /// `current_time`) alone, and otherwise moves `current_time` to the new duration, so it stays
+    /// finished.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn set_duration(&mut self, duration: Time) {
+        if self.duration == Time::ZERO {
+            if self.current_time >= Time::ZERO {
+                self.current_time = duration;
+            }
+        } else {
+            let pct = self.current_time.to_f32() / self.duration.to_f32();
+            self.current_time = Time::from_f32(pct * duration.to_f32());
+        }
+
+        self.duration = duration;
     }
 
     /// Returns `true` is the Tweener's [current_time] is greater than or equal to `0`. Only
@@ -240,16 +510,23 @@ where
     /// Returns `CurrentTimeState` based on the Tweener's [current_time].
     ///
     /// Note that for tweens without bounds (in this library, [Looper], [Oscillator], and
-    /// [Extrapolator]), this method will always return `CurrentTimeState::Valid`.
+    /// [Extrapolator]), this method will always return `CurrentTimeState::Valid`. A finite tween
+    /// with a duration of zero is `Finished` as soon as it isn't `Waiting`.
     ///
     /// [current_time]: Self::current_time
     pub fn current_time_state(&self) -> CurrentTimeState {
-        if self.tween.is_finite() {
+        if self.tween.is_finite() && self.duration == Time::ZERO {
+            if self.current_time < Time::ZERO {
+                CurrentTimeState::Waiting
+            } else {
+                CurrentTimeState::Finished
+            }
+        } else if self.tween.is_finite() {
             let pct = self.current_time.to_f32() / self.duration.to_f32();
 
             if pct < 0.0 {
                 CurrentTimeState::Waiting
-            } else if pct >= 1.0 {
+            } else if pct >= self.tween.span() {
                 CurrentTimeState::Finished
             } else {
                 CurrentTimeState::Valid
@@ -263,6 +540,28 @@ where
     pub fn into_fixed(self, delta: Time) -> FixedTweener<Value, Time, T> {
         FixedTweener::from_tweener(self, delta)
     }
+
+    /// Emits an event when moving to `position` starts, loops, or finishes the tween.
+    #[cfg(feature = "tracing")]
+    fn trace_move(&self, position: Time) {
+        let duration = self.duration.to_f32();
+        let (from, to) = (self.current_time.to_f32() / duration, position.to_f32() / duration);
+
+        if from <= 0.0 && to > 0.0 {
+            tracing::debug!(duration = ?self.duration, start = ?self.values.0, end = ?self.values.1, "tween started");
+        }
+
+        // every whole percent is the start of another loop, for tweens which go past `1.0`
+        let (from_loop, to_loop) = (from.max(0.0) as u32, to.max(0.0) as u32);
+        if to_loop > from_loop && (!self.tween.is_finite() || to < self.tween.span()) {
+            tracing::trace!(iteration = to_loop, "tween looped");
+        }
+
+        let span = self.tween.span();
+        if self.tween.is_finite() && from < span && to >= span {
+            tracing::debug!(duration = ?self.duration, end = ?self.values.1, "tween finished");
+        }
+    }
 }
 
 /// A FixedTweener is a [Tweener] wrapper which implements [Iterator]. To do this,
@@ -312,6 +611,7 @@ where
 /// assert_eq!(fixed_tweener.move_next(), 1);
 /// ```
 #[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedTweener<Value, Time, T: ?Sized> {
     /// The delta upon which we move.
     pub delta: Time,
@@ -353,6 +653,66 @@ where
     pub fn move_next(&mut self) -> Value {
         self.tweener.move_by(self.delta)
     }
+
+    /// Changes the delta this FixedTweener moves by on each step, without changing where it
+    /// currently is. This is useful when a display's refresh rate changes mid-tween.
+    #[inline]
+    pub fn set_delta(&mut self, delta: Time) {
+        self.delta = delta;
+    }
+
+    /// Fills `buffer` with the next `buffer.len()` steps of this tween, as if by calling
+    /// [move_next](Self::move_next) for each slot. Like `move_next`, this clamps, so once the
+    /// tween is finished the rest of the buffer is filled with its final value.
+    ///
+    /// ```
+    /// # use tween::FixedTweener;
+    /// let mut fixed = FixedTweener::linear(0, 4, 4, 1);
+    /// let mut buffer = [0; 6];
+    ///
+    /// fixed.sample_into(&mut buffer);
+    /// assert_eq!(buffer, [1, 2, 3, 4, 4, 4]);
+    /// ```
+    pub fn sample_into(&mut self, buffer: &mut [Value]) {
+        for slot in buffer {
+            *slot = self.move_next();
+        }
+    }
+
+    /// Returns `true` if stepping this FixedTweener will yield another value, taking its
+    /// [direction](Tweener::direction) into account.
+    pub(crate) fn has_more(&self) -> bool {
+        match self.tweener.direction() {
+            Direction::Forward => self.tweener.is_valid(),
+            Direction::Backward => !self.tweener.has_arrived(),
+        }
+    }
+
+    /// Returns how many more steps it will take to finish this tween at the current delta. A
+    /// partial step at the end counts as a whole step. When running [Direction::Backward], this
+    /// counts the steps back to the start instead.
+    ///
+    /// This returns `None` for infinite tweens (like [Looper]), which never finish, for paused
+    /// tweens, and for deltas which aren't positive.
+    pub fn remaining_steps(&self) -> Option<usize> {
+        let delta = self.delta.to_f32();
+        if !self.tweener.tween.is_finite() || delta <= 0.0 || self.tweener.is_paused() {
+            return None;
+        }
+
+        let total = self.tweener.duration.to_f32() * self.tweener.tween.span();
+        let current_time = self.tweener.current_time.to_f32();
+        let remaining = match self.tweener.direction() {
+            Direction::Forward => total - current_time,
+            Direction::Backward => current_time.min(total),
+        } / delta;
+        if remaining <= 0.0 {
+            return Some(0);
+        }
+
+        let whole = remaining as usize;
+        Some(if (whole as f32) < remaining { whole + 1 } else { whole })
+    }
 }
 
 impl<Value, Time, T> core::ops::Deref for FixedTweener<Value, Time, T> {
@@ -381,11 +741,7 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.tweener.is_valid() {
-            Some(self.move_next())
-        } else {
-            None
-        }
+        if self.has_more() { Some(self.move_next()) } else { None }
     }
 }
 
@@ -406,7 +762,7 @@ pub enum CurrentTimeState {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{BounceIn, BounceInOut, BounceOut, ElasticIn, Linear};
+    use crate::{CubicBezier, Linear};
 
     #[test]
     fn tweener() {
@@ -432,6 +788,45 @@ mod tests {
         assert_eq!(fixed_tweener.next(), None);
     }
 
+    #[test]
+    fn fixed_tweener_set_delta() {
+        let mut fixed_tweener = Tweener::new(0, 120, 120, Linear).into_fixed(2);
+        assert_eq!(fixed_tweener.remaining_steps(), Some(60));
+
+        for _ in 0..30 {
+            fixed_tweener.move_next();
+        }
+        assert_eq!(fixed_tweener.remaining_steps(), Some(30));
+
+        // and now we're running at twice the frame rate!
+        fixed_tweener.set_delta(1);
+        assert_eq!(fixed_tweener.remaining_steps(), Some(60));
+        assert_eq!(fixed_tweener.next(), Some(61));
+
+        fixed_tweener.set_delta(7);
+        assert_eq!(fixed_tweener.remaining_steps(), Some(9));
+        assert_eq!(fixed_tweener.by_ref().count(), 9);
+        assert_eq!(fixed_tweener.remaining_steps(), Some(0));
+
+        let looper = FixedTweener::new(0, 1, 1, crate::Looper::new(Linear), 1);
+        assert_eq!(looper.remaining_steps(), None);
+    }
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn set_tween_keeps_progress() {
+        use crate::Easing;
+
+        let mut tweener = Tweener::new(0.0f32, 10.0, 4, Easing::Linear);
+        tweener.move_to(2);
+        tweener.set_tween(Easing::QuadOut);
+
+        assert_eq!(tweener.current_time, 2);
+        assert_eq!(tweener.move_by(0), 7.5);
+        assert_eq!(tweener.move_by(2), 10.0);
+        assert!(tweener.is_finished());
+    }
+
     #[test]
     fn tweener_weird() {
         let mut tweener = Tweener::new(0, 2, 2, Linear);
@@ -481,12 +876,18 @@ mod tests {
         }
 
         checker(Tweener::new(0, 2, 2, Linear));
-        checker(Tweener::new(0, 2, 2, ElasticIn));
-        checker(Tweener::new(0, 2, 2, BounceInOut));
-        checker(Tweener::new(0, 2, 2, BounceIn));
-        checker(Tweener::new(0, 2, 2, BounceOut));
+        checker(Tweener::new(0, 2, 2, CubicBezier::EASE));
+        #[cfg(feature = "elastic")]
+        checker(Tweener::new(0, 2, 2, crate::ElasticIn));
+        #[cfg(feature = "bounce")]
+        {
+            checker(Tweener::new(0, 2, 2, crate::BounceInOut));
+            checker(Tweener::new(0, 2, 2, crate::BounceIn));
+            checker(Tweener::new(0, 2, 2, crate::BounceOut));
+        }
     }
 
+    #[cfg(feature = "easings")]
     #[test]
     fn shortcuts() {
         Tweener::back_in(0, 0, 0);
@@ -553,4 +954,202 @@ mod tests {
         Tweener::sine_out_at(0, 0, 0, 0);
         Tweener::sine_in_out_at(0, 0, 0, 0);
     }
+
+    #[test]
+    fn retarget_is_continuous() {
+        let mut tweener = Tweener::new(0.0, 100.0, 10.0, CubicBezier::EASE_IN_OUT);
+        let before = tweener.move_to(4.0);
+
+        tweener.retarget(-50.0);
+        assert_eq!(tweener.initial_value(), before);
+        assert_eq!(tweener.move_by(0.0), before);
+        assert_eq!(tweener.move_by(11.0), -50.0);
+        assert!(tweener.is_finished());
+    }
+
+    #[test]
+    fn advance_delta_sums_to_total() {
+        let mut tweener = Tweener::linear(0.0, 8.0, 1.0);
+        let mut offset = 0.0;
+        for dt in [0.25, 0.125, 0.375, 0.5] {
+            offset += tweener.advance_delta(dt);
+        }
+        assert_eq!(offset, 8.0);
+
+        tweener.reverse();
+        assert_eq!(tweener.advance_delta(0.5), -4.0);
+    }
+
+    #[test]
+    fn advance_delta_steps_once() {
+        #[derive(Clone)]
+        struct Counted(u32);
+
+        impl Tween<f32> for Counted {
+            fn tween(&mut self, value_delta: f32, percent: f32) -> f32 {
+                self.0 += 1;
+                value_delta * percent
+            }
+        }
+
+        let mut tweener = Tweener::new(0.0, 8.0, 1.0, Counted(0));
+        assert_eq!(tweener.advance_delta(0.25), 2.0);
+        assert_eq!(tweener.advance_delta(0.25), 2.0);
+        assert_eq!(tweener.tween.0, 2);
+    }
+
+    #[test]
+    fn arc_length_counts_the_whole_path() {
+        let mut tweener = Tweener::linear(0.0, 8.0, 2.0);
+        assert_eq!(tweener.arc_length_so_far(), 0.0);
+
+        tweener.move_to(1.0);
+        assert_eq!(tweener.arc_length_so_far(), 4.0);
+        tweener.move_to(5.0);
+        assert_eq!(tweener.arc_length_so_far(), 8.0);
+
+        // a full ping pong goes there and back
+        let mut tweener = Tweener::new(0.0, 8.0, 1.0, PingPong::new(Linear, 1));
+        tweener.move_to(2.0);
+        assert_eq!(tweener.arc_length_so_far(), 16.0);
+    }
+
+    #[test]
+    fn arc_length_leaves_stateful_tweens_alone() {
+        let mut measured = Tweener::new(0.0f32, 100.0, 100.0, Linear).threshold(5.0);
+        let mut untouched = measured;
+
+        for time in [50.0, 52.0] {
+            assert_eq!(measured.move_to(time), untouched.move_to(time));
+        }
+        assert!(measured.arc_length_so_far() > 0.0);
+
+        for time in [53.0, 54.0, 56.0, 100.0] {
+            assert_eq!(measured.move_to(time), untouched.move_to(time));
+        }
+    }
+
+    #[test]
+    fn set_duration_keeps_progress() {
+        let mut tweener = Tweener::new(0.0, 100.0, 10.0, CubicBezier::EASE_IN);
+        let before = tweener.move_to(4.0);
+
+        tweener.set_duration(20.0);
+        assert_eq!(tweener.current_time, 8.0);
+        assert_eq!(tweener.move_by(0.0), before);
+
+        assert_eq!(tweener.move_by(6.0), tweener.clone().move_to(14.0));
+        assert!(!tweener.is_finished());
+    }
+
+    #[test]
+    fn set_duration_through_zero() {
+        let mut tweener = Tweener::new(0.0, 100.0, 10.0, Linear);
+        tweener.move_to(4.0);
+
+        tweener.set_duration(0.0);
+        assert_eq!(tweener.current_time, 0.0);
+        assert!(tweener.is_finished());
+
+        tweener.set_duration(10.0);
+        assert_eq!(tweener.current_time, 10.0);
+        assert_eq!(tweener.move_by(0.0), 100.0);
+
+        let mut waiting = Tweener::new_at(0.0, 100.0, 0.0, Linear, -2.0);
+        assert_eq!(waiting.current_time_state(), CurrentTimeState::Waiting);
+        waiting.set_duration(10.0);
+        assert_eq!(waiting.current_time, -2.0);
+
+        let mut tweener = Tweener::new(0, 100, 10, Linear);
+        tweener.move_to(4);
+
+        tweener.set_duration(0);
+        tweener.set_duration(10);
+        assert_eq!(tweener.current_time, 10);
+        assert!(tweener.is_finished());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use crate::{Easing, Looper};
+
+        let mut tweener = FixedTweener::new(
+            0.0,
+            10.0,
+            10.0,
+            Looper::new(Easing::CubicBezier(CubicBezier::EASE_IN)),
+            1.0,
+        );
+        tweener.next();
+        tweener.next();
+
+        let json = serde_json::to_string(&tweener).unwrap();
+        let mut restored: FixedTweener<f32, f32, Looper<Easing>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, tweener);
+        assert_eq!(restored.next(), tweener.next());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_events() {
+        use crate::Looper;
+        use std::{string::String, sync::Mutex, vec::Vec};
+        use tracing::{Event, Metadata, Subscriber, field::Field, span};
+
+        #[derive(Default)]
+        struct Messages(Mutex<Vec<String>>);
+
+        struct Message<'a>(&'a mut String);
+
+        impl tracing::field::Visit for Message<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+                if field.name() == "message" {
+                    *self.0 = format!("{:?}", value);
+                }
+            }
+        }
+
+        impl Subscriber for &'static Messages {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut message = String::new();
+                event.record(&mut Message(&mut message));
+                self.0.lock().unwrap().push(message);
+            }
+
+            fn enter(&self, _: &span::Id) {}
+
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let messages: &'static Messages = std::boxed::Box::leak(std::boxed::Box::default());
+        tracing::subscriber::with_default(messages, || {
+            let mut tweener = Tweener::new(0, 10, 10, Linear);
+            tweener.move_by(5);
+            tweener.move_by(5);
+            tweener.move_by(5);
+
+            let mut looper = Tweener::new(0, 10, 10, Looper::new(Linear));
+            looper.move_to(15);
+        });
+
+        let messages = messages.0.lock().unwrap();
+        assert_eq!(
+            *messages,
+            ["tween started", "tween finished", "tween started", "tween looped"]
+        );
+    }
 }
@@ -0,0 +1,182 @@
+use crate::{Tween, TweenValue};
+
+/// A [Blend] is a tween which mixes the outputs of two inner tweens with a fixed `weight`.
+///
+/// At a weight of `0.0`, it is just `a`; at `1.0`, it is just `b`. Anything in between gives a
+/// curve in between the two, so, for example, a little bit of [BounceOut](crate::BounceOut) can be
+/// mixed into a [SineOut](crate::SineOut).
+///
+/// To change the weight as the tween plays, use [Morph]. To fade between two [Tweener]s which are
+/// already running, use a [Crossfade](crate::Crossfade).
+///
+/// ```
+/// # #[cfg(feature = "quad")] {
+/// # use tween::{Blend, Linear, QuadIn, Tweener};
+/// let mut tweener = Tweener::new(0.0, 100.0, 10.0, Blend::new(Linear, QuadIn, 0.5));
+///
+/// // halfway between 50.0 and 25.0
+/// assert_eq!(tweener.move_to(5.0), 37.5);
+/// # }
+/// ```
+///
+/// [Tweener]: crate::Tweener
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blend<A, B> {
+    /// How much of `b` is in the mix, from `0.0` to `1.0`.
+    pub weight: f32,
+
+    /// The first Tween.
+    pub a: A,
+
+    /// The second Tween.
+    pub b: B,
+}
+
+impl<A, B> Blend<A, B> {
+    /// Creates a new Blend of two [Tween]s.
+    pub fn new(a: A, b: B, weight: f32) -> Self {
+        Self { weight, a, b }
+    }
+}
+
+impl<Value, A, B> Tween<Value> for Blend<A, B>
+where
+    Value: TweenValue,
+    A: Tween<Value>,
+    B: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        mix(&mut self.a, &mut self.b, self.weight, value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.a.is_finite() && self.b.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        Tween::<Value>::span(&self.a).max(Tween::<Value>::span(&self.b))
+    }
+
+    fn returns_to_start(&self) -> bool {
+        Tween::<Value>::returns_to_start(&self.a) && Tween::<Value>::returns_to_start(&self.b)
+    }
+}
+
+/// A [Morph] is a [Blend] whose weight is itself driven by a third tween, so that it moves from
+/// one curve to the other as it plays.
+///
+/// The `weight` tween runs over the whole Morph, from `0.0` (all `a`) to `1.0` (all `b`). This
+/// lets a motion start out in one easing style and finish in another, without a visible pop.
+///
+/// ```
+/// # #[cfg(feature = "quad")] {
+/// # use tween::{Linear, Morph, QuadIn, QuadOut, Tweener};
+/// let mut tweener = Tweener::new(0.0, 100.0, 10.0, Morph::new(QuadIn, QuadOut, Linear));
+///
+/// assert_eq!(tweener.move_to(0.0), 0.0);
+/// assert_eq!(tweener.move_to(5.0), 50.0);
+/// assert_eq!(tweener.move_to(10.0), 100.0);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Morph<A, B, W> {
+    /// The first Tween.
+    pub a: A,
+
+    /// The second Tween.
+    pub b: B,
+
+    /// The Tween which drives how much of `b` is in the mix.
+    pub weight: W,
+}
+
+impl<A, B, W> Morph<A, B, W> {
+    /// Creates a new Morph from `a` to `b`, driven by the `weight` [Tween].
+    pub fn new(a: A, b: B, weight: W) -> Self {
+        Self { a, b, weight }
+    }
+}
+
+impl<Value, A, B, W> Tween<Value> for Morph<A, B, W>
+where
+    Value: TweenValue,
+    A: Tween<Value>,
+    B: Tween<Value>,
+    W: Tween<f32>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let span = Tween::<Value>::span(self);
+        let weight = self.weight.tween(1.0, percent / span);
+
+        mix(&mut self.a, &mut self.b, weight, value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.a.is_finite() && self.b.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        Tween::<Value>::span(&self.a).max(Tween::<Value>::span(&self.b))
+    }
+
+    fn returns_to_start(&self) -> bool {
+        Tween::<Value>::returns_to_start(&self.a) && Tween::<Value>::returns_to_start(&self.b)
+    }
+}
+
+#[inline(always)]
+fn mix<Value, A, B>(a: &mut A, b: &mut B, weight: f32, value_delta: Value, percent: f32) -> Value
+where
+    Value: TweenValue,
+    A: Tween<Value>,
+    B: Tween<Value>,
+{
+    a.tween(value_delta.clone(), percent).scale(1.0 - weight) + b.tween(value_delta, percent).scale(weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear, Looper};
+
+    #[test]
+    fn blend_weights() {
+        let (mut ease_in, mut ease_out) = (CubicBezier::EASE_IN, CubicBezier::EASE_OUT);
+
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+
+            assert_eq!(
+                Blend::new(ease_in, ease_out, 0.0).tween(10.0f32, pct),
+                ease_in.tween(10.0, pct)
+            );
+            assert_eq!(
+                Blend::new(ease_in, ease_out, 1.0).tween(10.0f32, pct),
+                ease_out.tween(10.0, pct)
+            );
+        }
+    }
+
+    #[cfg(all(feature = "sine", feature = "quad"))]
+    #[test]
+    fn morph_moves_between() {
+        use crate::{QuadIn, SineIn, SineOut};
+        use approx::assert_relative_eq;
+
+        let mut morph = Morph::new(SineIn, SineOut, QuadIn);
+
+        // early on, it's nearly all SineIn, and late, nearly all SineOut
+        assert_relative_eq!(morph.tween(1.0, 0.1), SineIn.tween(1.0, 0.1), epsilon = 0.01);
+        assert_relative_eq!(morph.tween(1.0, 0.9), SineOut.tween(1.0, 0.9), epsilon = 0.05);
+    }
+
+    #[test]
+    fn finiteness() {
+        assert!(Tween::<f32>::is_finite(&Blend::new(Linear, Linear, 0.5)));
+        assert!(!Tween::<f32>::is_finite(&Blend::new(Linear, Looper::new(Linear), 0.5)));
+    }
+}
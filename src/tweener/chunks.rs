@@ -0,0 +1,145 @@
+use crate::{FixedTweener, Tween, TweenTime, TweenValue};
+
+impl<Value, Time, T> FixedTweener<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Fills `buffer` with the next values of this FixedTweener, exactly as if `next` had been
+    /// called once per slot, and returns how many were written.
+    ///
+    /// If the tween finishes part way through, this stops early (just as [Iterator] returns
+    /// `None`), and the rest of `buffer` is left untouched.
+    ///
+    /// ```
+    /// # use tween::FixedTweener;
+    /// let mut fixed_tweener = FixedTweener::linear(0, 6, 6, 1);
+    /// let mut buffer = [0; 4];
+    ///
+    /// assert_eq!(fixed_tweener.next_chunk(&mut buffer), 4);
+    /// assert_eq!(buffer, [1, 2, 3, 4]);
+    ///
+    /// assert_eq!(fixed_tweener.next_chunk(&mut buffer), 2);
+    /// assert_eq!(buffer, [5, 6, 3, 4]);
+    /// ```
+    pub fn next_chunk(&mut self, buffer: &mut [Value]) -> usize {
+        let mut written = 0;
+        for slot in buffer.iter_mut() {
+            if !self.has_more() {
+                break;
+            }
+
+            *slot = self.move_next();
+            written += 1;
+        }
+
+        written
+    }
+
+    /// Returns an iterator which yields the next `N` values of this FixedTweener at once, as an
+    /// array. This is handy for feeding audio blocks or SIMD consumers.
+    ///
+    /// Iteration stops when the tween is finished. If the tween finishes part way through a chunk,
+    /// the rest of that final chunk is filled with the clamped final value, as if with
+    /// [move_next](Self::move_next).
+    ///
+    /// ```
+    /// # use tween::FixedTweener;
+    /// let mut fixed_tweener = FixedTweener::linear(0, 5, 5, 1);
+    /// let mut chunks = fixed_tweener.by_chunks::<2>();
+    ///
+    /// assert_eq!(chunks.next(), Some([1, 2]));
+    /// assert_eq!(chunks.next(), Some([3, 4]));
+    /// assert_eq!(chunks.next(), Some([5, 5]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn by_chunks<const N: usize>(&mut self) -> ByChunks<'_, Value, Time, T, N> {
+        ByChunks { fixed_tweener: self }
+    }
+}
+
+/// An iterator over arrays of a [FixedTweener]'s values. See [FixedTweener::by_chunks].
+#[derive(Debug)]
+pub struct ByChunks<'a, Value, Time, T, const N: usize> {
+    fixed_tweener: &'a mut FixedTweener<Value, Time, T>,
+}
+
+impl<Value, Time, T, const N: usize> Iterator for ByChunks<'_, Value, Time, T, N>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    type Item = [Value; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.fixed_tweener.has_more() {
+            return None;
+        }
+
+        Some(core::array::from_fn(|_| self.fixed_tweener.move_next()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, FixedTweener, Linear, Looper};
+
+    #[test]
+    fn chunks_match_next() {
+        let mut by_one = FixedTweener::new(0, 1000, 100, Linear, 1);
+        let mut by_chunks = by_one;
+
+        for chunk in by_chunks.by_chunks::<10>() {
+            for value in chunk {
+                assert_eq!(Some(value), by_one.next());
+            }
+        }
+        assert_eq!(by_one.next(), None);
+    }
+
+    #[test]
+    fn infinite_chunks() {
+        let mut looper = FixedTweener::new(0, 2, 2, Looper::new(Linear), 1);
+
+        let chunks: [[i32; 3]; 2] = {
+            let mut iter = looper.by_chunks::<3>();
+            [iter.next().unwrap(), iter.next().unwrap()]
+        };
+        assert_eq!(chunks, [[1, 2, 1], [2, 1, 2]]);
+    }
+
+    #[test]
+    fn empty_buffer() {
+        let mut fixed = FixedTweener::new(0, 2, 2, Linear, 1);
+
+        assert_eq!(fixed.next_chunk(&mut []), 0);
+        assert_eq!(fixed.next(), Some(1));
+    }
+
+    #[test]
+    fn backward_chunks_match_next() {
+        let mut by_one = FixedTweener::new(0, 3, 3, Linear, 1);
+        by_one.by_ref().for_each(drop);
+        by_one.set_direction(Direction::Backward);
+        let mut by_buffer = by_one;
+        let mut by_array = by_one;
+
+        let expected = [2, 1, 0];
+        for value in expected {
+            assert_eq!(by_one.next(), Some(value));
+        }
+        assert_eq!(by_one.next(), None);
+
+        let mut buffer = [0; 6];
+        assert_eq!(by_buffer.next_chunk(&mut buffer), 3);
+        assert_eq!(buffer[..3], expected);
+
+        let mut chunks = by_array.by_chunks::<2>();
+        assert_eq!(chunks.next(), Some([2, 1]));
+        assert_eq!(chunks.next(), Some([0, 0]));
+        assert_eq!(chunks.next(), None);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{Tween, TweenValue};
+
+/// Clamps a percent into `0.0..=1.0`, which is the range finite tweens are defined over.
+///
+/// If you are driving a [Tween] yourself, rather than with a [Tweener](crate::Tweener), you
+/// probably want [ClampedTween] instead, which only clamps finite tweens and which returns the
+/// exact endpoints when clamping.
+#[inline(always)]
+pub fn clamp_percent(percent: f32) -> f32 {
+    percent.clamp(0.0, 1.0)
+}
+
+/// How close to `0.0` or `1.0` a percent has to be for [percent_at_start] and [percent_at_end] to
+/// count it as an endpoint.
+///
+/// Times which are built up out of many small steps rarely land exactly on the end of a tween:
+/// sixty frames of `1.0 / 60.0` add up to a hair under `1.0`. This is loose enough to catch that,
+/// and tight enough that no built-in tween visibly jumps.
+pub const ENDPOINT_TOLERANCE: f32 = 1e-5;
+
+/// Returns `true` if `percent` is the start of a tween, give or take [ENDPOINT_TOLERANCE].
+///
+/// Tweens which need to special case their endpoints, because their formula doesn't quite reach
+/// them, should check with this rather than comparing with `0.0` exactly.
+#[inline(always)]
+pub fn percent_at_start(percent: f32) -> bool {
+    percent.abs() <= ENDPOINT_TOLERANCE
+}
+
+/// Returns `true` if `percent` is the end of a tween, give or take [ENDPOINT_TOLERANCE]. See
+/// [percent_at_start].
+#[inline(always)]
+pub fn percent_at_end(percent: f32) -> bool {
+    (percent - 1.0).abs() <= ENDPOINT_TOLERANCE
+}
+
+/// A [ClampedTween] is a wrapper around a [Tween] which clamps it exactly like a
+/// [Tweener](crate::Tweener) does. It is the opposite of an [Extrapolator](crate::Extrapolator).
+///
+/// This is for custom drivers (audio threads, job systems, and the like) which call
+/// [Tween::tween] directly, but still want identical boundary behavior to a `Tweener`: if the
+/// inner tween [is_finite](Tween::is_finite), percents below `0.0` give no change at all, and
+/// percents above `1.0` give the whole `value_delta`. Infinite tweens, like
+/// [Looper](crate::Looper), are passed through untouched.
+///
+/// ```
+/// # #[cfg(feature = "elastic")] {
+/// # use tween::{ClampedTween, ElasticOut, Tween};
+/// let mut tween = ClampedTween::new(ElasticOut);
+///
+/// assert_eq!(tween.tween(10.0, -1.0), 0.0);
+/// assert_eq!(tween.tween(10.0, 2.0), 10.0);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct ClampedTween<T: ?Sized>(pub T);
+
+impl<T> ClampedTween<T> {
+    /// Creates a new ClampedTween around a [Tween].
+    pub fn new(tween: T) -> Self {
+        Self(tween)
+    }
+}
+
+impl<Value, T> Tween<Value> for ClampedTween<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        if self.0.is_finite() {
+            if percent < 0.0 || (percent > self.0.span() && self.0.returns_to_start()) {
+                return value_delta.scale(0.0);
+            } else if percent > self.0.span() {
+                return value_delta;
+            }
+        }
+
+        self.0.tween(value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.0.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.0.returns_to_start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear, Looper, Tweener};
+
+    #[test]
+    fn percent() {
+        assert_eq!(clamp_percent(-0.5), 0.0);
+        assert_eq!(clamp_percent(0.5), 0.5);
+        assert_eq!(clamp_percent(1.5), 1.0);
+    }
+
+    #[test]
+    fn endpoints() {
+        let mut time = 0.0f32;
+        for _ in 0..60 {
+            time += 1.0 / 60.0;
+        }
+
+        assert_ne!(time, 1.0);
+        assert!(percent_at_end(time));
+        assert!(percent_at_start(-1e-7));
+        assert!(!percent_at_start(0.01));
+        assert!(!percent_at_end(1.01));
+    }
+
+    #[test]
+    fn matches_tweener() {
+        let mut tweener = Tweener::new(0, 100, 10, CubicBezier::EASE_IN);
+        let mut clamped = ClampedTween::new(CubicBezier::EASE_IN);
+
+        for time in -5..=15 {
+            let pct = time as f32 / 10.0;
+            assert_eq!(tweener.move_to(time), clamped.tween(100, pct));
+        }
+    }
+
+    #[test]
+    fn infinite_passthrough() {
+        let mut clamped = ClampedTween::new(Looper::new(Linear));
+
+        assert_eq!(clamped.tween(10, 1.5), 5);
+        assert!(!Tween::<i32>::is_finite(&clamped));
+    }
+}
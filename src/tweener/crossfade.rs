@@ -0,0 +1,106 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// A Crossfade is a driver which runs two [Tweener]s side by side and mixes their outputs, moving
+/// from entirely `from` to entirely `to` over its own duration.
+///
+/// This is for transitioning between animation "states" while both are mid-flight -- for example,
+/// from a button's hover curve to its pressed curve -- without a visible pop. The weight of the mix
+/// is itself tweened, with any [Tween] you like.
+///
+/// ```
+/// # use tween::{Crossfade, Linear, Tweener};
+/// let hover = Tweener::linear(0.0f32, 10.0, 10.0);
+/// let pressed = Tweener::linear(100.0, 110.0, 10.0);
+///
+/// let mut crossfade = Crossfade::new(hover, pressed, 4.0, Linear);
+/// assert_eq!(crossfade.move_by(2.0), 52.0); // halfway between 2.0 and 102.0
+/// assert_eq!(crossfade.move_by(2.0), 104.0); // now it's all `pressed`
+/// assert!(crossfade.is_finished());
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crossfade<Value, Time, A, B, W> {
+    /// The Tweener we're fading away from.
+    pub from: Tweener<Value, Time, A>,
+
+    /// The Tweener we're fading towards.
+    pub to: Tweener<Value, Time, B>,
+
+    /// The weight of `to` in the mix, which runs from `0.0` to `1.0`.
+    pub weight: Tweener<f32, Time, W>,
+}
+
+impl<Value, Time, A, B, W> Crossfade<Value, Time, A, B, W>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    A: Tween<Value>,
+    B: Tween<Value>,
+    W: Tween<f32>,
+{
+    /// Creates a new Crossfade out of two [Tweener]s, which fades from `from` to `to` over
+    /// `duration` using the `weight` tween.
+    pub fn new(from: Tweener<Value, Time, A>, to: Tweener<Value, Time, B>, duration: Time, weight: W) -> Self {
+        Self {
+            from,
+            to,
+            weight: Tweener::new(0.0, 1.0, duration, weight),
+        }
+    }
+
+    /// Drives both Tweeners, and the crossfade itself, forward X steps in time, returning the mixed
+    /// value.
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        let from = self.from.move_by(delta);
+        let to = self.to.move_by(delta);
+        let weight = self.weight.move_by(delta);
+
+        Self::mix(from, to, weight)
+    }
+
+    /// Returns `true` once the crossfade has fully moved over to `to`. From then on, you can
+    /// replace this Crossfade with [into_to](Self::into_to).
+    pub fn is_finished(&self) -> bool {
+        self.weight.is_finished()
+    }
+
+    /// Consumes this Crossfade, returning the Tweener it was fading towards.
+    pub fn into_to(self) -> Tweener<Value, Time, B> {
+        self.to
+    }
+
+    #[inline(always)]
+    fn mix(from: Value, to: Value, weight: f32) -> Value {
+        from.scale(1.0 - weight) + to.scale(weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear};
+
+    #[test]
+    fn endpoints() {
+        let from = Tweener::new(0, 100, 10, Linear);
+        let to = Tweener::new(100, 0, 10, Linear);
+        let mut crossfade = Crossfade::new(from, to, 10, CubicBezier::EASE_IN_OUT);
+
+        assert_eq!(crossfade.move_by(0), 0);
+        assert!(!crossfade.is_finished());
+
+        assert_eq!(crossfade.move_by(10), 0);
+        assert!(crossfade.is_finished());
+        assert_eq!(crossfade.into_to().current_time, 10);
+    }
+
+    #[test]
+    fn mid_flight() {
+        // `from` is already halfway through when we start fading
+        let from = Tweener::new_at(0.0f32, 10.0, 10.0, Linear, 5.0);
+        let to = Tweener::new(20.0, 30.0, 10.0, Linear);
+        let mut crossfade = Crossfade::new(from, to, 2.0, Linear);
+
+        assert_eq!(crossfade.move_by(1.0), 6.0 * 0.5 + 21.0 * 0.5);
+    }
+}
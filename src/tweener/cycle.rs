@@ -0,0 +1,181 @@
+use crate::{
+    Looper, Oscillator, PhaseShift, PingPong, Repeat, TimeScale, Tween, TweenTime, TweenValue, Tweener,
+    math::float::floor,
+};
+
+/// A [Tween] which plays in repeating cycles, like a [Looper] or an [Oscillator].
+///
+/// Tweeners over a Cyclic tween can report how long each cycle takes and where they are in it,
+/// with [cycle_duration](Tweener::cycle_duration) and [phase](Tweener::phase), so schedulers can
+/// line other events up with loop boundaries without redoing the wrapping math themselves.
+pub trait Cyclic {
+    /// The length of one cycle, in parametric space. A [Looper] has a period of `1.0`, and an
+    /// [Oscillator], whose cycle goes there and back again, has a period of `2.0`.
+    fn period(&self) -> f32;
+
+    /// How far into its cycles this is at a percent of `0.0`, in parametric space. This is only
+    /// non-zero for tweens which start partway through a cycle, like a [PhaseShift].
+    fn phase_offset(&self) -> f32 {
+        0.0
+    }
+}
+
+impl<T: ?Sized> Cyclic for Looper<T> {
+    fn period(&self) -> f32 {
+        1.0
+    }
+}
+
+impl<T: ?Sized> Cyclic for Oscillator<T> {
+    fn period(&self) -> f32 {
+        2.0
+    }
+}
+
+impl<T: ?Sized> Cyclic for Repeat<T> {
+    fn period(&self) -> f32 {
+        1.0
+    }
+}
+
+impl<T: ?Sized> Cyclic for PingPong<T> {
+    fn period(&self) -> f32 {
+        2.0
+    }
+}
+
+impl<T: Cyclic + ?Sized> Cyclic for PhaseShift<T> {
+    fn period(&self) -> f32 {
+        self.tween.period()
+    }
+
+    fn phase_offset(&self) -> f32 {
+        self.tween.phase_offset() + self.phase
+    }
+}
+
+impl<T: Cyclic + ?Sized> Cyclic for TimeScale<T> {
+    fn period(&self) -> f32 {
+        self.tween.period() / self.factor
+    }
+
+    fn phase_offset(&self) -> f32 {
+        self.tween.phase_offset() / self.factor
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value> + Cyclic,
+{
+    /// How long one cycle of the tween takes.
+    ///
+    /// ```
+    /// # #[cfg(feature = "sine")] {
+    /// # use tween::{Looper, Oscillator, SineInOut, Tweener};
+    /// let looper = Tweener::new(0.0, 1.0, 2.0, Looper::new(SineInOut));
+    /// let oscillator = Tweener::new(0.0, 1.0, 2.0, Oscillator::new(SineInOut));
+    ///
+    /// assert_eq!(looper.cycle_duration(), 2.0);
+    /// assert_eq!(oscillator.cycle_duration(), 4.0);
+    /// # }
+    /// ```
+    pub fn cycle_duration(&self) -> Time {
+        Time::from_f32(self.duration.to_f32() * self.tween.period())
+    }
+
+    /// Where the Tweener is in its current cycle, from `0.0` at the start of the cycle up to, but
+    /// not including, `1.0` at its end.
+    pub fn phase(&self) -> f32 {
+        let cycles = self.cycles();
+        cycles - floor(cycles)
+    }
+
+    /// Which cycle the Tweener is in, counting from `0`. Times before the first cycle count as
+    /// cycle `0`.
+    pub fn cycle(&self) -> u32 {
+        floor(self.cycles()) as u32
+    }
+
+    /// The time at which the current cycle ends and the next one starts.
+    ///
+    /// ```
+    /// # use tween::{Looper, Linear, Tweener};
+    /// let mut looper = Tweener::new(0.0, 1.0, 4.0, Looper::new(Linear));
+    /// looper.move_to(9.0);
+    ///
+    /// assert_eq!(looper.cycle(), 2);
+    /// assert_eq!(looper.phase(), 0.25);
+    /// assert_eq!(looper.next_cycle_start(), 12.0);
+    /// ```
+    pub fn next_cycle_start(&self) -> Time {
+        let duration = self.duration.to_f32();
+        let period = self.tween.period();
+        let next = (floor(self.cycles()) + 1.0) * period - self.tween.phase_offset();
+
+        Time::from_f32(next * duration)
+    }
+
+    /// How many cycles have played so far, fractional part included.
+    fn cycles(&self) -> f32 {
+        let percent = self.current_time.to_f32() / self.duration.to_f32();
+        (percent + self.tween.phase_offset()) / self.tween.period()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn oscillator_cycles_there_and_back() {
+        let mut oscillator = Tweener::new(0, 10, 5, Oscillator::new(Linear));
+
+        oscillator.move_to(5);
+        assert_eq!(oscillator.cycle(), 0);
+        assert_eq!(oscillator.phase(), 0.5);
+
+        oscillator.move_to(12);
+        assert_eq!(oscillator.cycle(), 1);
+        assert_relative_eq!(oscillator.phase(), 0.2);
+        assert_eq!(oscillator.next_cycle_start(), 20);
+    }
+
+    #[test]
+    fn phase_shift_and_time_scale() {
+        let mut shifted = Tweener::new(0.0, 1.0, 4.0, PhaseShift::new(Looper::new(Linear), 0.5));
+        assert_eq!(shifted.phase(), 0.5);
+        assert_eq!(shifted.next_cycle_start(), 2.0);
+
+        shifted.move_to(3.0);
+        assert_eq!(shifted.cycle(), 1);
+        assert_eq!(shifted.phase(), 0.25);
+
+        let fast = Tweener::new(0.0, 1.0, 4.0, TimeScale::new(Looper::new(Linear), 2.0));
+        assert_eq!(fast.cycle_duration(), 2.0);
+    }
+
+    #[test]
+    fn starts_at_phase() {
+        for fraction in [0.0, 0.25, 0.5, 0.75] {
+            let looper = Tweener::new(0.0, 1.0, 3.0, Looper::new(Linear).with_phase(fraction));
+            let oscillator = Tweener::new(0.0, 1.0, 3.0, Oscillator::new(Linear).with_phase(fraction));
+
+            assert_eq!(looper.phase(), fraction);
+            assert_eq!(oscillator.phase(), fraction);
+        }
+    }
+
+    #[test]
+    fn repeat_is_cyclic() {
+        let mut repeat = Tweener::new(0.0, 1.0, 1.0, Repeat::new(Linear, 3));
+        repeat.move_to(2.5);
+
+        assert_eq!(repeat.cycle(), 2);
+        assert_eq!(repeat.phase(), 0.5);
+    }
+}
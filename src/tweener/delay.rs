@@ -0,0 +1,134 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// A [Delay] is a wrapper around a [Tween] which holds the start value for a lead-in before running
+/// its inner tween.
+///
+/// The lead-in is measured in units of the [Tweener]'s `duration`, so a `delay` of `0.5` waits for
+/// half a duration, and the whole Delay then takes `duration * 1.5`. If you'd rather give the
+/// lead-in as a time, use [Tweener::with_delay].
+///
+/// Unlike starting a Tweener at a negative time (see `examples/delayed_tween.rs`), a Delay is part
+/// of the tween itself, so a delayed Tweener is `Valid` from the start and it finishes only once
+/// the inner tween does. This makes staggered entrance animations for lists and grids easy:
+///
+/// ```
+/// # #[cfg(feature = "sine")] {
+/// # use tween::{Delay, SineOut, Tweener};
+/// let mut rows: [_; 3] =
+///     core::array::from_fn(|i| Tweener::new(0.0, 1.0, 1.0, Delay::staggered(i, 0.25, SineOut)));
+///
+/// let opacities = rows.each_mut().map(|row| row.move_to(0.25));
+/// assert_eq!(opacities[1], 0.0);
+/// assert!(opacities[0] > 0.0);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Delay<T: ?Sized> {
+    /// The lead-in, in units of the tween's duration.
+    pub delay: f32,
+
+    /// The underlying Tween.
+    pub tween: T,
+}
+
+impl<T> Delay<T> {
+    /// Creates a new Delay around a [Tween].
+    pub fn new(tween: T, delay: f32) -> Self {
+        Self { delay, tween }
+    }
+
+    /// Creates a Delay for the `index`th item of a staggered animation, where each item starts
+    /// `step` after the one before it.
+    pub fn staggered(index: usize, step: f32, tween: T) -> Self {
+        Self::new(tween, index as f32 * step)
+    }
+}
+
+impl<Value, T> Tween<Value> for Delay<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        if percent < self.delay {
+            return value_delta.scale(0.0);
+        }
+
+        self.tween.tween(value_delta, percent - self.delay)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.delay + self.tween.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.tween.returns_to_start()
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Holds this Tweener's start value for `delay` before running it. See [Delay].
+    ///
+    /// ```
+    /// # use tween::Tweener;
+    /// let mut tweener = Tweener::linear(0, 10, 10).with_delay(5);
+    ///
+    /// assert_eq!(tweener.move_to(5), 0);
+    /// assert_eq!(tweener.move_to(10), 5);
+    /// assert!(!tweener.is_finished());
+    /// assert_eq!(tweener.move_to(15), 10);
+    /// assert!(tweener.is_finished());
+    /// ```
+    pub fn with_delay(self, delay: Time) -> Tweener<Value, Time, Delay<T>> {
+        let delay = delay.to_f32() / self.duration.to_f32();
+
+        self.map(|tween| Delay::new(tween, delay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Linear, Looper};
+
+    #[test]
+    fn holds_then_runs() {
+        let mut tweener = Tweener::new(10, 20, 4, Delay::new(Linear, 0.5));
+
+        assert_eq!(tweener.move_to(0), 10);
+        assert_eq!(tweener.move_to(2), 10);
+        assert_eq!(tweener.move_to(4), 15);
+        assert_eq!(tweener.move_to(6), 20);
+        assert!(tweener.is_finished());
+        assert_eq!(tweener.move_to(7), 20);
+    }
+
+    #[test]
+    fn fixed_steps() {
+        let fixed = FixedTweener::new(0, 2, 2, Delay::new(Linear, 1.0), 1);
+
+        assert_eq!(fixed.remaining_steps(), Some(4));
+        assert!(fixed.eq([0, 0, 1, 2]));
+    }
+
+    #[test]
+    fn delayed_looper() {
+        let mut tweener = Tweener::new(0, 2, 2, Delay::new(Looper::new(Linear), 1.0));
+
+        assert_eq!(tweener.move_to(1), 0);
+        assert_eq!(tweener.move_to(3), 1);
+        assert_eq!(tweener.move_to(7), 1);
+        assert!(!tweener.is_finished());
+    }
+}
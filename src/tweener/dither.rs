@@ -0,0 +1,130 @@
+use crate::{Tween, TweenRng, TweenValue, XorShift64};
+
+/// A Dithered tween nudges the percent it passes to its inner tween by a small, fixed offset,
+/// which is rolled once for each instance.
+///
+/// When many identical tweens drive a low precision output at once, like a gradient of `u8`
+/// colors or a row of sprites snapped to pixels, they all step from one output value to the next
+/// on the same frame, which shows up as banding. Giving each one a slightly different offset
+/// spreads those steps out. An `amount` of one to a few output steps, as a percent of the whole
+/// tween, is usually enough.
+///
+/// The offset is seeded, so the same seed always dithers the same way. The ends are never
+/// dithered, so every instance still starts and lands exactly where it should.
+///
+/// ```
+/// # use tween::{Dithered, Linear, Tweener};
+/// // 0 to 255 over 1.0 steps about every 0.004, so dither across a few of those steps
+/// let mut tweeners: Vec<_> = (0..16)
+///     .map(|seed| Tweener::new(0u8, 255, 1.0, Dithered::new(Linear, 4.0 / 255.0, seed)))
+///     .collect();
+///
+/// let values: Vec<u8> = tweeners.iter_mut().map(|tweener| tweener.move_to(0.5)).collect();
+/// assert!(values.iter().any(|&value| value != values[0]));
+/// assert!(tweeners.iter_mut().all(|tweener| tweener.move_to(1.0) == 255));
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dithered<T> {
+    /// The inner tween.
+    pub tween: T,
+
+    offset: f32,
+}
+
+impl<T> Dithered<T> {
+    /// Creates a new Dithered tween around `tween`, whose offset is somewhere within `amount / 2`
+    /// of zero, either way, picked by `seed`. Each instance should get its own seed, such as its
+    /// index or entity id.
+    pub fn new(tween: T, amount: f32, seed: u64) -> Self {
+        Self::with_rng(tween, amount, &mut XorShift64::new(seed))
+    }
+
+    /// Like [new](Self::new), but rolls the offset with the given [TweenRng].
+    pub fn with_rng<R: TweenRng>(tween: T, amount: f32, rng: &mut R) -> Self {
+        Self {
+            tween,
+            offset: amount * (rng.next_f32() - 0.5),
+        }
+    }
+
+    /// The offset this instance adds to every percent, away from the ends.
+    #[inline]
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+}
+
+impl<Value, T> Tween<Value> for Dithered<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let span = self.tween.span();
+        let percent = if percent <= 0.0 || (self.tween.is_finite() && percent >= span) {
+            percent
+        } else if self.tween.is_finite() {
+            (percent + self.offset).clamp(0.0, span)
+        } else {
+            (percent + self.offset).max(0.0)
+        };
+
+        self.tween.tween(value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.tween.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.tween.returns_to_start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear, Tweener};
+
+    #[test]
+    fn stays_within_its_amount() {
+        for seed in 0..100 {
+            let mut dithered = Dithered::new(Linear, 0.01, seed);
+            assert!(dithered.offset().abs() <= 0.005);
+            assert_eq!(dithered, Dithered::new(Linear, 0.01, seed));
+
+            assert_eq!(dithered.tween(1.0f32, 0.0), 0.0);
+            assert_eq!(dithered.tween(1.0f32, 1.0), 1.0);
+            assert_eq!(dithered.tween(1.0f32, 0.5), 0.5 + dithered.offset());
+            assert!(dithered.tween(1.0f32, 0.001) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn spreads_out_steps() {
+        // without dithering, every one of these steps on the same frames
+        let mut steps_at = [0usize; 101];
+        for seed in 0..32 {
+            let mut tweener = Tweener::new(0u8, 10, 100, Dithered::new(CubicBezier::EASE_IN_OUT, 0.05, seed));
+            let mut last = tweener.move_to(0);
+
+            for (frame, steps) in steps_at.iter_mut().enumerate().skip(1) {
+                let now = tweener.move_to(frame as i32);
+                if now != last {
+                    *steps += 1;
+                }
+                last = now;
+            }
+            assert_eq!(last, 10);
+        }
+
+        assert!(steps_at.iter().filter(|&&count| count > 0).count() > 20);
+        assert!(steps_at.iter().all(|&count| count < 32));
+    }
+}
@@ -13,6 +13,7 @@ use crate::{Tween, TweenValue};
 /// [Linear]: crate::Linear
 /// [SineIn]: crate::SineIn
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Extrapolator<T: ?Sized>(pub T);
 
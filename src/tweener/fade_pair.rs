@@ -0,0 +1,101 @@
+use crate::{FadeCurve, TweenTime, TweenValue};
+
+/// A FadePair drives a matched fade-out and fade-in off one clock, following a [FadeCurve], so the
+/// two always sum the way the curve promises.
+///
+/// Driving a [FadeOut](crate::FadeOut) and a [FadeIn](crate::FadeIn) with two separate
+/// [Tweener](crate::Tweener)s works too, but if their times ever drift apart, the crossfade
+/// dips or swells. Here there's only one time to move.
+///
+/// ```
+/// # use tween::{FadeCurve, FadePair};
+/// let mut dissolve = FadePair::new(4.0, FadeCurve::EqualGain);
+///
+/// assert_eq!(dissolve.move_by(1.0), (0.75, 0.25));
+/// assert_eq!(dissolve.mix(0.0f32, 100.0), 25.0);
+///
+/// dissolve.move_by(3.0);
+/// assert!(dissolve.is_finished());
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FadePair<Time> {
+    /// The current time of the fade.
+    pub current_time: Time,
+
+    /// How long the fade takes.
+    pub duration: Time,
+
+    /// The law the fade follows.
+    pub curve: FadeCurve,
+}
+
+impl<Time: TweenTime> FadePair<Time> {
+    /// Creates a new FadePair at the start of a fade which takes `duration`.
+    pub fn new(duration: Time, curve: FadeCurve) -> Self {
+        Self {
+            current_time: Time::ZERO,
+            duration,
+            curve,
+        }
+    }
+
+    /// Moves the fade to `position`, and returns the gains of the sound fading out and the sound
+    /// fading in there, in that order.
+    #[inline]
+    pub fn move_to(&mut self, position: Time) -> (f32, f32) {
+        self.current_time = position;
+        self.gains()
+    }
+
+    /// Moves the fade forward by `delta`, and returns the gains of the sound fading out and the
+    /// sound fading in, in that order.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> (f32, f32) {
+        self.move_to(self.current_time.saturating_add(delta))
+    }
+
+    /// The gains of the sound fading out and the sound fading in at the current time, in that
+    /// order.
+    pub fn gains(&self) -> (f32, f32) {
+        let percent = self.current_time.to_f32() / self.duration.to_f32();
+        self.curve.gains(if percent.is_nan() { 1.0 } else { percent })
+    }
+
+    /// Mixes `from` and `to` with the gains at the current time.
+    pub fn mix<Value: TweenValue>(&self, from: Value, to: Value) -> Value {
+        let (out, into) = self.gains();
+        from.scale(out) + to.scale(into)
+    }
+
+    /// Returns `true` once the fade has finished, and only the sound fading in is left.
+    pub fn is_finished(&self) -> bool {
+        self.current_time >= self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn equal_power_holds_steady() {
+        let mut fade = FadePair::new(48, FadeCurve::EqualPower);
+
+        for _ in 0..48 {
+            let (out, into) = fade.move_by(1);
+            assert_relative_eq!(out * out + into * into, 1.0, epsilon = 1e-6);
+        }
+        assert!(fade.is_finished());
+        assert_eq!(fade.gains(), (0.0, 1.0));
+        assert_eq!(fade.move_by(10), (0.0, 1.0));
+    }
+
+    #[test]
+    fn empty_fades_are_done() {
+        let fade = FadePair::new(0.0, FadeCurve::EqualGain);
+        assert!(fade.is_finished());
+        assert_eq!(fade.mix(3.0f32, 5.0), 5.0);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Tween, TweenLength, TweenTime, TweenValue, Tweener};
+
+/// Checks that each of a run of [Tweener]s, meant to play one after another, starts where the one
+/// before it ends, to within `tolerance`. A segment which doesn't makes the animation pop as it
+/// hands off, which is easy to miss when segments are authored by hand.
+///
+/// The check is cheap, so it can go in a `debug_assert!` wherever a sequence is put together, to
+/// catch these in development without costing anything in release builds. To fix them up instead,
+/// use [bridge_handoffs].
+///
+/// ```
+/// # use tween::{HandoffError, Tweener, check_handoffs};
+/// let segments = [
+///     Tweener::linear(0.0f32, 10.0, 1.0),
+///     Tweener::linear(10.0, 4.0, 1.0),
+///     Tweener::linear(5.0, 0.0, 1.0),
+/// ];
+///
+/// assert_eq!(check_handoffs(&segments, 0.01), Err(HandoffError { segment: 2, gap: 1.0 }));
+/// assert!(check_handoffs(&segments, 1.0).is_ok());
+/// ```
+pub fn check_handoffs<Value, Time, T>(segments: &[Tweener<Value, Time, T>], tolerance: f32) -> Result<(), HandoffError>
+where
+    Value: TweenLength,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    for (index, pair) in segments.windows(2).enumerate() {
+        let gap = (pair[1].initial_value() - pair[0].final_value()).length();
+        if gap > tolerance {
+            return Err(HandoffError {
+                segment: index + 1,
+                gap,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves the start of each of a run of [Tweener]s, meant to play one after another, to exactly
+/// where the one before it ends, so nothing pops as they hand off. Each keeps its end value, and
+/// the first keeps its start.
+///
+/// ```
+/// # use tween::{Tweener, bridge_handoffs, check_handoffs};
+/// let mut segments = [Tweener::linear(0.0f32, 10.0, 1.0), Tweener::linear(12.0, 0.0, 1.0)];
+/// bridge_handoffs(&mut segments);
+///
+/// assert!(check_handoffs(&segments, 0.0).is_ok());
+/// assert_eq!(segments[1].move_to(0.5), 5.0);
+/// ```
+pub fn bridge_handoffs<Value, Time, T>(segments: &mut [Tweener<Value, Time, T>])
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    for index in 1..segments.len() {
+        let start = segments[index - 1].final_value();
+        let segment = &mut segments[index];
+
+        segment.value_delta = segment.values.1.clone() - start.clone();
+        segment.values.0 = start;
+    }
+}
+
+/// The error returned by [check_handoffs] for the first segment which doesn't start where the one
+/// before it ends.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct HandoffError {
+    /// The index of the segment which starts in the wrong place. This is never `0`.
+    pub segment: usize,
+
+    /// How far its start is from the end of the segment before it.
+    pub gap: f32,
+}
+
+impl core::fmt::Display for HandoffError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "segment {} starts {} away from where segment {} ends",
+            self.segment,
+            self.gap,
+            self.segment - 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HandoffError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CubicBezier;
+
+    #[test]
+    fn finds_the_first_pop() {
+        let segments = [
+            Tweener::new(0.0f32, 1.0, 1, CubicBezier::EASE_OUT),
+            Tweener::new(1.0, 3.0, 1, CubicBezier::EASE_OUT),
+            Tweener::new(2.5, 0.0, 1, CubicBezier::EASE_OUT),
+            Tweener::new(1.0, 0.0, 1, CubicBezier::EASE_OUT),
+        ];
+
+        assert_eq!(
+            check_handoffs(&segments, 0.1),
+            Err(HandoffError { segment: 2, gap: 0.5 })
+        );
+        assert_eq!(
+            check_handoffs(&segments, 0.5),
+            Err(HandoffError { segment: 3, gap: 1.0 })
+        );
+        assert_eq!(check_handoffs(&segments[..1], 0.0), Ok(()));
+        assert_eq!(check_handoffs::<f32, i32, CubicBezier>(&[], 0.0), Ok(()));
+    }
+
+    #[test]
+    fn bridges_keep_their_ends() {
+        let mut segments = [
+            Tweener::new(0.0f32, 1.0, 1, CubicBezier::EASE_OUT),
+            Tweener::new(2.0, 3.0, 2, CubicBezier::EASE_OUT),
+            Tweener::new(4.0, 0.0, 1, CubicBezier::EASE_OUT),
+        ];
+        bridge_handoffs(&mut segments);
+
+        assert_eq!(check_handoffs(&segments, 0.0), Ok(()));
+        assert_eq!(segments[0].initial_value(), 0.0);
+        assert_eq!(segments[1].move_to(0), 1.0);
+        assert_eq!(segments[1].move_to(2), 3.0);
+        assert_eq!(segments[2].move_to(0), 3.0);
+        assert_eq!(segments[2].move_to(1), 0.0);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Tween, TweenValue, Tweener};
+use std::time::{Duration, Instant};
+
+/// An InstantTweener is a [Tweener] wrapper which keeps time by the wall clock, using
+/// [Instant], so that you can just ask it for its value every frame without measuring and feeding
+/// it deltas yourself.
+///
+/// Its Tweener uses [Duration] for time. Since it measures from when it started, rather than
+/// adding up frame times, it doesn't drift.
+///
+/// ```
+/// # use tween::{InstantTweener, Tweener};
+/// use std::time::Duration;
+///
+/// let mut fade = Tweener::linear(0.0, 1.0, Duration::from_millis(250)).by_instant();
+///
+/// // and then in your main loop...
+/// let alpha = fade.value_now();
+/// assert!((0.0..=1.0).contains(&alpha));
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+pub struct InstantTweener<Value, T> {
+    /// The instant the tween started at.
+    pub started_at: Instant,
+
+    /// The internal tweener, which runs in [Duration]s.
+    pub tweener: Tweener<Value, Duration, T>,
+}
+
+impl<Value, T> InstantTweener<Value, T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    /// Creates a new InstantTweener which starts now. If the Tweener's `current_time` isn't zero,
+    /// it is treated as having started that long ago.
+    pub fn new(tweener: Tweener<Value, Duration, T>) -> Self {
+        Self::starting_at(tweener, Instant::now())
+    }
+
+    /// Creates a new InstantTweener which starts at the given instant.
+    pub fn starting_at(tweener: Tweener<Value, Duration, T>, instant: Instant) -> Self {
+        let started_at = instant.checked_sub(tweener.current_time).unwrap_or(instant);
+
+        Self { started_at, tweener }
+    }
+
+    /// Moves the tween to the current time, and returns its value.
+    #[inline]
+    pub fn value_now(&mut self) -> Value {
+        self.value_at(Instant::now())
+    }
+
+    /// Moves the tween to the given instant, and returns its value. Instants before the tween
+    /// started are clamped to its start.
+    #[inline]
+    pub fn value_at(&mut self, instant: Instant) -> Value {
+        self.tweener.move_to(instant.saturating_duration_since(self.started_at))
+    }
+
+    /// Returns `true` if the tween is finished by now. Unlike [Tweener::is_finished], this checks
+    /// the clock, rather than where the tween was last moved to.
+    pub fn is_finished_now(&self) -> bool {
+        let total = self.tweener.duration.as_secs_f32() * self.tweener.tween.span();
+
+        self.tweener.tween.is_finite() && self.started_at.elapsed().as_secs_f32() >= total
+    }
+}
+
+impl<Value, T> Tweener<Value, Duration, T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    /// Converts this [Tweener] to an [InstantTweener], which starts now. See its documentation for
+    /// more information.
+    pub fn by_instant(self) -> InstantTweener<Value, T> {
+        InstantTweener::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn clock() {
+        let start = Instant::now();
+        let tweener = Tweener::new(0, 100, Duration::from_secs(10), Linear);
+        let mut instant = InstantTweener::starting_at(tweener, start);
+
+        assert_eq!(instant.value_at(start), 0);
+        assert_eq!(instant.value_at(start + Duration::from_secs(5)), 50);
+        assert_eq!(instant.value_at(start + Duration::from_secs(20)), 100);
+        assert!(instant.tweener.is_finished());
+
+        // the past is clamped to the start
+        assert_eq!(
+            instant.value_at(start.checked_sub(Duration::from_secs(1)).unwrap_or(start)),
+            0
+        );
+        assert!(!instant.is_finished_now());
+    }
+
+    #[test]
+    fn already_running() {
+        let now = Instant::now();
+        let mut tweener = Tweener::new(0, 100, Duration::from_secs(10), Linear);
+        tweener.move_to(Duration::from_secs(5));
+
+        let mut instant = InstantTweener::starting_at(tweener, now);
+        assert_eq!(instant.value_at(now), 50);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Interpolate, Tween, TweenTime};
+
+/// An Interpolator is a driver, like a [Tweener](crate::Tweener), for values which are
+/// [Interpolate] rather than [TweenValue](crate::TweenValue).
+///
+/// Rather than handing the tween a difference to scale, it runs the tween over `0.0..=1.0` to
+/// find how far along the motion is, and then interpolates from `start` to `end` by that much. So
+/// any `Tween<f32>` can ease values which can't be subtracted, like hues or clamped numbers, and
+/// for plain values, it gives the same results as a Tweener.
+///
+/// Like a Tweener, it clamps: before it starts, you'll get `start`, and after it finishes, `end`.
+///
+/// ```
+/// # use tween::{Interpolate, Interpolator, Linear};
+/// /// A volume, which can never leave `0..=100`.
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Volume(u8);
+///
+/// impl Interpolate for Volume {
+///     fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+///         let volume = a.0 as f32 + (b.0 as f32 - a.0 as f32) * t;
+///         Volume(volume.round().clamp(0.0, 100.0) as u8)
+///     }
+/// }
+///
+/// let mut fade = Interpolator::new(Volume(100), Volume(0), 10, Linear);
+/// assert_eq!(fade.move_to(5), Volume(50));
+/// assert_eq!(fade.move_by(20), Volume(0));
+/// assert!(fade.is_finished());
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interpolator<Value, Time, T> {
+    /// The current time of the Interpolator. You can change this value at will, or change it with
+    /// `move_by`.
+    pub current_time: Time,
+
+    /// The Interpolator's total duration.
+    pub duration: Time,
+
+    /// The value the Interpolator starts at.
+    pub start: Value,
+
+    /// The value the Interpolator ends at.
+    pub end: Value,
+
+    /// The tween which eases the motion.
+    pub tween: T,
+}
+
+impl<Value, Time, T> Interpolator<Value, Time, T>
+where
+    Value: Interpolate,
+    Time: TweenTime,
+    T: Tween<f32>,
+{
+    /// Creates a new Interpolator at time `0`.
+    pub fn new(start: Value, end: Value, duration: Time, tween: T) -> Self {
+        Self {
+            current_time: Time::ZERO,
+            duration,
+            start,
+            end,
+            tween,
+        }
+    }
+
+    /// Moves the Interpolator to a given time, returning the value there. Times before the start
+    /// or after the end of a finite tween are clamped.
+    pub fn move_to(&mut self, position: Time) -> Value {
+        self.current_time = position;
+
+        let pct = position.to_f32() / self.duration.to_f32();
+        if self.tween.is_finite() {
+            if pct < 0.0 || (pct > self.tween.span() && self.tween.returns_to_start()) {
+                return self.start.clone();
+            } else if pct > self.tween.span() {
+                return self.end.clone();
+            }
+        }
+
+        Value::lerp(&self.start, &self.end, self.tween.tween(1.0, pct))
+    }
+
+    /// Drives the Interpolator forward by `delta`.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        self.move_to(self.current_time.saturating_add(delta))
+    }
+
+    /// Returns `true` if the current time is at or after the start.
+    pub fn is_started(&self) -> bool {
+        self.current_time >= Time::ZERO
+    }
+
+    /// Returns `true` if the Interpolator has finished, which infinite tweens never do.
+    pub fn is_finished(&self) -> bool {
+        self.tween.is_finite() && self.current_time.to_f32() >= self.duration.to_f32() * self.tween.span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear, Looper, Tweener};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn matches_tweener_for_plain_values() {
+        let mut interpolator = Interpolator::new(-3.0f32, 9.0, 20, CubicBezier::EASE_IN_OUT);
+        let mut tweener = Tweener::new(-3.0f32, 9.0, 20, CubicBezier::EASE_IN_OUT);
+
+        for time in -2..=22 {
+            assert_relative_eq!(interpolator.move_to(time), tweener.move_to(time), epsilon = 1e-5);
+            assert_eq!(interpolator.is_finished(), tweener.is_finished());
+        }
+    }
+
+    #[test]
+    fn blends_values_without_subtraction() {
+        /// A label, which can only switch over from one to the other.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Label {
+            Idle,
+            Moving,
+        }
+
+        impl Interpolate for Label {
+            fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+                if t < 0.5 { *a } else { *b }
+            }
+        }
+
+        let mut interpolator = Interpolator::new(Label::Idle, Label::Moving, 4, Linear);
+        assert!(!interpolator.is_finished());
+        assert_eq!(interpolator.move_by(1), Label::Idle);
+        assert_eq!(interpolator.move_by(1), Label::Moving);
+
+        let mut looping = Interpolator::new(Label::Idle, Label::Moving, 4, Looper::new(Linear));
+        assert_eq!(looping.move_to(5), Label::Idle);
+        assert!(!looping.is_finished());
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{Tween, TweenValue};
+use crate::{PhaseShift, Repeat, Tween, TweenValue};
 
 /// A [Looper] is a wrapper around a [Tween], which makes it so that
 /// every time the tweener *would* fuse (end), it loops from the start.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Looper<T: ?Sized>(pub T);
 
@@ -11,6 +12,32 @@ impl<T> Looper<T> {
     pub fn new(tween: T) -> Self {
         Self(tween)
     }
+
+    /// Wraps this Looper in a [PhaseShift] so that it starts `fraction` of the way through its
+    /// loop, like a background element which should already be in motion when a scene loads.
+    ///
+    /// ```
+    /// # use tween::{Linear, Looper, Tweener};
+    /// let mut looper = Tweener::new(0.0, 4.0, 4.0, Looper::new(Linear).with_phase(0.25));
+    ///
+    /// assert_eq!(looper.move_to(0.0), 1.0);
+    /// assert_eq!(looper.move_to(3.0), 4.0);
+    /// assert_eq!(looper.move_to(4.0), 1.0);
+    /// ```
+    pub fn with_phase(self, fraction: f32) -> PhaseShift<Self> {
+        PhaseShift::new(self, fraction)
+    }
+
+    /// Wraps this Looper in a [PhaseShift] with a phase picked from the given seed, so that it
+    /// starts somewhere in the middle of its loop. The same seed always gives the same phase.
+    pub fn with_random_phase(self, seed: u64) -> PhaseShift<Self> {
+        PhaseShift::random(self, seed)
+    }
+
+    /// Makes this Looper finite, so that it plays `count` times and then finishes. See [Repeat].
+    pub fn with_repeats(self, count: u32) -> Repeat<T> {
+        Repeat::new(self.0, count)
+    }
 }
 
 impl<Value, T> Tween<Value> for Looper<T>
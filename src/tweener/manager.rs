@@ -0,0 +1,660 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{any::Any, fmt};
+
+mod blackboard;
+mod group;
+mod layer;
+#[cfg(feature = "profile")]
+mod profile;
+mod record;
+mod typed;
+pub use blackboard::Blackboard;
+pub use group::Completion;
+use group::Group;
+pub use layer::{Layer, Preemption};
+#[cfg(feature = "profile")]
+pub use profile::UpdateProfile;
+pub use record::{TimedCommand, TweenCommand, TweenLog, TweenRecorder, UnrecordedChangesError};
+pub use typed::TypedHandle;
+
+/// A handle to a [Tweener] owned by a [TweenManager], used to pause or cancel it.
+///
+/// Handles are never reused by the manager which gave them out, so a handle to a tweener which
+/// has finished or been cancelled simply stops matching anything.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TweenHandle(u64);
+
+/// A TweenManager owns many [Tweener]s, of any value and tween types, and drives them all with a
+/// single [update](Self::update).
+///
+/// Since the manager can't hand back values of different types, each tweener is added with a
+/// callback which receives its value every time it is moved. Once a tweener finishes, it is
+/// removed, and its handle shows up in [drain_finished](Self::drain_finished).
+///
+/// ```
+/// # use tween::{TweenManager, Tweener};
+/// use std::{cell::Cell, rc::Rc};
+///
+/// let alpha = Rc::new(Cell::new(0.0));
+/// let mut manager = TweenManager::new();
+///
+/// let fade = {
+///     let alpha = alpha.clone();
+///     manager.add(Tweener::linear(0.0, 1.0, 2.0), move |value| alpha.set(value))
+/// };
+/// manager.add(Tweener::linear(0, 100, 10.0), |_offset: i32| {});
+///
+/// manager.update(1.0);
+/// assert_eq!(alpha.get(), 0.5);
+///
+/// manager.update(1.0);
+/// assert_eq!(alpha.get(), 1.0);
+/// assert!(manager.drain_finished().eq([fade]));
+/// assert_eq!(manager.len(), 1);
+/// ```
+pub struct TweenManager<Time> {
+    entries: Vec<Entry<Time>>,
+    finished: Vec<TweenHandle>,
+    groups: Vec<Group>,
+    blackboard: Blackboard,
+    next_id: u64,
+    /// Counts every change made to the manager, other than updates, so that a [TweenRecorder] can
+    /// tell whether any were made behind its back.
+    changes: u64,
+    #[cfg(feature = "profile")]
+    profile: UpdateProfile,
+}
+
+struct Entry<Time> {
+    handle: TweenHandle,
+    paused: bool,
+    layer: Option<Layer>,
+    preempted: bool,
+    waiting_for: Option<String>,
+    on_finish: Option<(String, f32)>,
+    driver: Box<dyn Driver<Time>>,
+}
+
+/// Erases the value and tween types of a [Tweener] and its callback.
+trait Driver<Time> {
+    /// Moves the tweener by `delta`, passing its value on. Returns `true` once it's finished.
+    fn advance(&mut self, delta: Time) -> bool;
+
+    /// Retargets the tweener to the value in `end`, which is an `Option<Value>`. Returns `false`
+    /// if `end` holds a different type of value.
+    fn retarget(&mut self, end: &mut dyn Any) -> bool;
+
+    /// The tweener itself, for [TypedHandle]s to downcast.
+    fn tweener(&self) -> &dyn Any;
+
+    /// The tweener itself, for [TypedHandle]s to downcast.
+    fn tweener_mut(&mut self) -> &mut dyn Any;
+}
+
+struct Managed<Value, Time, T, F> {
+    tweener: Tweener<Value, Time, T>,
+    on_update: F,
+}
+
+impl<Value, Time, T, F> Driver<Time> for Managed<Value, Time, T, F>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value> + 'static,
+    Value: 'static,
+    Time: 'static,
+    F: FnMut(Value),
+{
+    fn advance(&mut self, delta: Time) -> bool {
+        let value = self.tweener.move_by(delta);
+        (self.on_update)(value);
+
+        self.tweener.is_finished()
+    }
+
+    fn retarget(&mut self, end: &mut dyn Any) -> bool {
+        match end.downcast_mut::<Option<Value>>().and_then(Option::take) {
+            Some(end) => {
+                self.tweener.retarget(end);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn tweener(&self) -> &dyn Any {
+        &self.tweener
+    }
+
+    fn tweener_mut(&mut self) -> &mut dyn Any {
+        &mut self.tweener
+    }
+}
+
+impl<Time: TweenTime> TweenManager<Time> {
+    /// Creates a new, empty TweenManager.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            finished: Vec::new(),
+            groups: Vec::new(),
+            blackboard: Blackboard::new(),
+            next_id: 0,
+            changes: 0,
+            #[cfg(feature = "profile")]
+            profile: UpdateProfile::default(),
+        }
+    }
+
+    /// Adds a [Tweener] to the manager. `on_update` is called with its value every time the
+    /// manager moves it, including the final time, when it finishes.
+    pub fn add<Value, T>(
+        &mut self,
+        tweener: Tweener<Value, Time, T>,
+        on_update: impl FnMut(Value) + 'static,
+    ) -> TweenHandle
+    where
+        Value: TweenValue + 'static,
+        Time: 'static,
+        T: Tween<Value> + 'static,
+    {
+        self.insert(Box::new(Managed { tweener, on_update }), None)
+    }
+
+    /// Adds a [Tweener] to the manager on a priority [Layer], so that it can override, or be
+    /// overridden by, other tweeners on the same target.
+    ///
+    /// While a tweener of higher priority is in the manager, one of lower priority on the same
+    /// target is preempted: it's either held until the higher one stops, or cancelled, depending
+    /// on its [Preemption]. This is how a hit reaction can override an idle wobble, and hand
+    /// control back to it afterwards. A tweener added under one of higher priority is preempted
+    /// straight away, so one which cancels is never run at all.
+    ///
+    /// ```
+    /// # #[cfg(feature = "sine")] {
+    /// # use tween::{Layer, Looper, SineInOut, TweenManager, Tweener};
+    /// let mut manager = TweenManager::new();
+    /// let player = 7;
+    ///
+    /// let idle = manager.add_layered(
+    ///     Tweener::new(0.0f32, 1.0, 2.0, Looper::new(SineInOut)),
+    ///     |_wobble| {},
+    ///     Layer::new(player, 0),
+    /// );
+    /// let hit = manager.add_layered(Tweener::linear(0.0f32, 1.0, 0.5), |_flinch| {}, Layer::new(player, 10));
+    ///
+    /// assert!(manager.is_preempted(idle));
+    /// manager.update(0.5);
+    /// assert!(!manager.contains(hit));
+    /// assert!(!manager.is_preempted(idle));
+    /// # }
+    /// ```
+    pub fn add_layered<Value, T>(
+        &mut self,
+        tweener: Tweener<Value, Time, T>,
+        on_update: impl FnMut(Value) + 'static,
+        layer: Layer,
+    ) -> TweenHandle
+    where
+        Value: TweenValue + 'static,
+        Time: 'static,
+        T: Tween<Value> + 'static,
+    {
+        let handle = self.insert(Box::new(Managed { tweener, on_update }), Some(layer));
+        if self.settle_layers() {
+            self.settle_groups();
+        }
+
+        handle
+    }
+
+    fn insert(&mut self, driver: Box<dyn Driver<Time>>, layer: Option<Layer>) -> TweenHandle {
+        let handle = TweenHandle(self.next_id);
+        self.next_id += 1;
+        self.changed();
+
+        self.entries.push(Entry {
+            handle,
+            paused: false,
+            layer,
+            preempted: false,
+            waiting_for: None,
+            on_finish: None,
+            driver,
+        });
+
+        handle
+    }
+
+    /// Moves every tweener which isn't paused or [waiting](Self::add_when) forward by `delta`.
+    /// Tweeners which finish are removed, and their handles are kept for
+    /// [drain_finished](Self::drain_finished).
+    pub fn update(&mut self, delta: Time) {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("tween_manager_update", delta = ?delta, tweeners = self.entries.len()).entered();
+
+        self.release_waiting();
+
+        let finished = &mut self.finished;
+        let blackboard = &mut self.blackboard;
+        let already_finished = finished.len();
+
+        #[cfg(feature = "profile")]
+        let (profile, started) = {
+            self.profile = UpdateProfile::default();
+            (&mut self.profile, std::time::Instant::now())
+        };
+
+        self.entries.retain_mut(|entry| {
+            if entry.paused || entry.preempted || entry.waiting_for.is_some() {
+                #[cfg(feature = "profile")]
+                {
+                    profile.paused += 1;
+                }
+                return true;
+            }
+
+            #[cfg(feature = "profile")]
+            let advanced = std::time::Instant::now();
+
+            let done = entry.driver.advance(delta);
+
+            #[cfg(feature = "profile")]
+            profile.record(entry.handle, advanced.elapsed());
+
+            if !done {
+                return true;
+            }
+
+            #[cfg(feature = "profile")]
+            {
+                profile.finished += 1;
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(handle = ?entry.handle, "managed tween finished");
+
+            if let Some((key, value)) = entry.on_finish.take() {
+                blackboard.set(key, value);
+            }
+            finished.push(entry.handle);
+            false
+        });
+
+        #[cfg(feature = "profile")]
+        {
+            self.profile.total = started.elapsed();
+        }
+
+        // tweeners finishing can let the ones they preempted run again
+        if self.finished.len() != already_finished {
+            self.settle_layers();
+        }
+        self.settle_groups();
+    }
+
+    /// Returns what happened during the last [update](Self::update). See [UpdateProfile].
+    #[cfg(feature = "profile")]
+    pub fn last_profile(&self) -> &UpdateProfile {
+        &self.profile
+    }
+
+    /// Returns the handles of all tweeners which have finished since this was last called.
+    pub fn drain_finished(&mut self) -> impl Iterator<Item = TweenHandle> + '_ {
+        self.finished.drain(..)
+    }
+
+    /// Removes a tweener without finishing it. Returns `false` if the handle didn't match anything.
+    pub fn cancel(&mut self, handle: TweenHandle) -> bool {
+        self.changed();
+        let len = self.entries.len();
+        self.entries.retain(|entry| entry.handle != handle);
+
+        let cancelled = self.entries.len() != len;
+        if cancelled {
+            self.settle_layers();
+        }
+        #[cfg(feature = "tracing")]
+        if cancelled {
+            tracing::debug!(handle = ?handle, "managed tween cancelled");
+        }
+
+        self.settle_groups();
+        cancelled
+    }
+
+    /// Waits for every tweener in `handles` to stop running, by finishing or being cancelled, and
+    /// then calls `on_complete` and sets the returned [Completion].
+    ///
+    /// This is for multi-part sequences, where some logic should only run once a few animations
+    /// have all played out. Handles which don't match anything count as stopped already, so if
+    /// none of them are running, this completes straight away.
+    ///
+    /// ```
+    /// # use tween::{TweenManager, Tweener};
+    /// let mut manager = TweenManager::new();
+    /// let slide = manager.add(Tweener::linear(0, 100, 2), |_| {});
+    /// let fade = manager.add(Tweener::linear(0.0, 1.0, 4), |_| {});
+    ///
+    /// let shown = manager.when_all([slide, fade], || println!("the menu is open"));
+    ///
+    /// manager.update(2);
+    /// assert!(!shown.is_complete());
+    /// manager.update(2);
+    /// assert!(shown.is_complete());
+    /// ```
+    pub fn when_all(
+        &mut self,
+        handles: impl IntoIterator<Item = TweenHandle>,
+        on_complete: impl FnOnce() + 'static,
+    ) -> Completion {
+        self.wait(handles.into_iter().collect(), true, Box::new(on_complete))
+    }
+
+    /// Waits for any one tweener in `handles` to stop running, by finishing or being cancelled, and
+    /// then calls `on_complete` and sets the returned [Completion]. Like
+    /// [when_all](Self::when_all), this completes straight away if any of the handles don't match
+    /// anything, or if there are none.
+    pub fn when_any(
+        &mut self,
+        handles: impl IntoIterator<Item = TweenHandle>,
+        on_complete: impl FnOnce() + 'static,
+    ) -> Completion {
+        self.wait(handles.into_iter().collect(), false, Box::new(on_complete))
+    }
+
+    /// Retargets a tweener to `end`, as with [Tweener::retarget]. Returns `false` if the handle
+    /// didn't match anything, or if it matched a tweener of a different value type.
+    pub fn retarget<Value: 'static>(&mut self, handle: TweenHandle, end: Value) -> bool {
+        self.changed();
+        match self.entries.iter_mut().find(|entry| entry.handle == handle) {
+            Some(entry) => entry.driver.retarget(&mut Some(end)),
+            None => false,
+        }
+    }
+
+    /// Pauses a tweener, so [update](Self::update) skips it. Returns `false` if the handle didn't
+    /// match anything.
+    pub fn pause(&mut self, handle: TweenHandle) -> bool {
+        self.set_paused(handle, true)
+    }
+
+    /// Resumes a paused tweener. Returns `false` if the handle didn't match anything.
+    pub fn resume(&mut self, handle: TweenHandle) -> bool {
+        self.set_paused(handle, false)
+    }
+
+    /// Returns `true` if the handle matches a paused tweener.
+    pub fn is_paused(&self, handle: TweenHandle) -> bool {
+        self.entry(handle).is_some_and(|entry| entry.paused)
+    }
+
+    /// Returns `true` if the handle matches a layered tweener which is being held by one of higher
+    /// priority. See [add_layered](Self::add_layered).
+    pub fn is_preempted(&self, handle: TweenHandle) -> bool {
+        self.entry(handle).is_some_and(|entry| entry.preempted)
+    }
+
+    /// Returns `true` if the handle matches a tweener which hasn't finished or been cancelled.
+    pub fn contains(&self, handle: TweenHandle) -> bool {
+        self.entry(handle).is_some()
+    }
+
+    /// The number of tweeners in the manager, including paused ones.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the manager has no tweeners.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Cancels every tweener in the manager.
+    pub fn clear(&mut self) {
+        self.changed();
+        self.entries.clear();
+        self.settle_groups();
+    }
+
+    fn wait(&mut self, handles: Vec<TweenHandle>, all: bool, on_complete: Box<dyn FnOnce()>) -> Completion {
+        self.changed();
+        let group = Group::new(handles, all, on_complete);
+        let completion = group.completion();
+
+        self.groups.push(group);
+        self.settle_groups();
+
+        completion
+    }
+
+    /// Completes every group which is done waiting.
+    fn settle_groups(&mut self) {
+        let entries = &self.entries;
+        let running = |handle| entries.iter().any(|entry: &Entry<Time>| entry.handle == handle);
+
+        self.groups.retain_mut(|group| !group.settle(running));
+    }
+
+    fn entry(&self, handle: TweenHandle) -> Option<&Entry<Time>> {
+        self.entries.iter().find(|entry| entry.handle == handle)
+    }
+
+    #[inline]
+    fn changed(&mut self) {
+        self.changes = self.changes.wrapping_add(1);
+    }
+
+    fn set_paused(&mut self, handle: TweenHandle, paused: bool) -> bool {
+        self.changed();
+        match self.entries.iter_mut().find(|entry| entry.handle == handle) {
+            Some(entry) => {
+                entry.paused = paused;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<Time: TweenTime> Default for TweenManager<Time> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Time> fmt::Debug for TweenManager<Time> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TweenManager")
+            .field("len", &self.entries.len())
+            .field("finished", &self.finished)
+            .field("groups", &self.groups.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Looper};
+    use std::{cell::Cell, rc::Rc};
+
+    fn tracked<V: Copy + Default + 'static>() -> (Rc<Cell<V>>, impl FnMut(V) + 'static) {
+        let cell = Rc::new(Cell::new(V::default()));
+        let sink = cell.clone();
+
+        (cell, move |value| sink.set(value))
+    }
+
+    #[test]
+    fn pause_and_resume() {
+        let (value, sink) = tracked();
+        let mut manager = TweenManager::new();
+        let handle = manager.add(Tweener::new(0, 10, 10, Linear), sink);
+
+        manager.update(2);
+        assert!(manager.pause(handle));
+        assert!(manager.is_paused(handle));
+        manager.update(2);
+        assert_eq!(value.get(), 2);
+
+        assert!(manager.resume(handle));
+        manager.update(2);
+        assert_eq!(value.get(), 4);
+    }
+
+    #[test]
+    fn cancel() {
+        let (value, sink) = tracked();
+        let mut manager = TweenManager::new();
+        let handle = manager.add(Tweener::new(0.0f32, 1.0, 1.0, Looper::new(Linear)), sink);
+
+        manager.update(0.5);
+        assert!(manager.cancel(handle));
+        assert!(!manager.cancel(handle));
+        assert!(!manager.contains(handle));
+
+        manager.update(0.25);
+        assert_eq!(value.get(), 0.5);
+        assert_eq!(manager.drain_finished().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "profile")]
+    fn profile() {
+        let mut manager = TweenManager::new();
+        let slow = manager.add(Tweener::new(0, 10, 2, Linear), |_| {
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        });
+        let paused = manager.add(Tweener::new(0, 10, 2, Linear), |_| {});
+        manager.add(Tweener::new(0, 10, 1, Linear), |_| {});
+        manager.pause(paused);
+
+        manager.update(1);
+        let profile = *manager.last_profile();
+
+        assert_eq!((profile.updated, profile.paused, profile.finished), (2, 1, 1));
+        assert_eq!(profile.slowest_handle, Some(slow));
+        assert!(profile.slowest >= std::time::Duration::from_millis(2));
+        assert!(profile.total >= profile.slowest);
+    }
+
+    #[test]
+    fn retarget() {
+        let (value, sink) = tracked();
+        let mut manager = TweenManager::new();
+        let handle = manager.add(Tweener::new(0, 10, 10, Linear), sink);
+
+        manager.update(5);
+        assert!(!manager.retarget(handle, 1.0f32));
+        assert!(manager.retarget(handle, 0));
+
+        manager.update(5);
+        assert_eq!(value.get(), 3);
+    }
+
+    #[test]
+    fn when_all_and_any() {
+        let (fired, _) = tracked::<u32>();
+        let mut manager = TweenManager::new();
+        let short = manager.add(Tweener::new(0, 10, 2, Linear), |_| {});
+        let long = manager.add(Tweener::new(0, 10, 4, Linear), |_| {});
+
+        let counter = fired.clone();
+        let all = manager.when_all([short, long], move || counter.set(counter.get() + 1));
+        let any = manager.when_any([short, long], || {});
+
+        manager.update(2);
+        assert!(any.is_complete());
+        assert!(!all.is_complete());
+
+        manager.update(2);
+        assert!(all.is_complete());
+        manager.update(2);
+        assert_eq!(fired.get(), 1);
+    }
+
+    #[test]
+    fn groups_count_cancelled_and_unknown() {
+        let mut manager = TweenManager::new();
+        let looping = manager.add(Tweener::new(0, 10, 2, Looper::new(Linear)), |_| {});
+        let done = manager.when_all([looping], || {});
+
+        manager.update(10);
+        assert!(!done.is_complete());
+        manager.cancel(looping);
+        assert!(done.is_complete());
+
+        // `looping` is gone, so anything waiting on it is already complete
+        assert!(manager.when_any([looping], || {}).is_complete());
+        assert!(manager.when_all([], || {}).is_complete());
+    }
+
+    #[test]
+    fn layers_preempt_and_resume() {
+        let (idle, idle_sink) = tracked();
+        let mut manager = TweenManager::new();
+        let wobble = manager.add_layered(Tweener::new(0, 10, 10, Linear), idle_sink, Layer::new(1, 0));
+        let other = manager.add_layered(Tweener::new(0, 10, 10, Linear), |_| {}, Layer::new(2, 0));
+
+        manager.update(2);
+        let hit = manager.add_layered(Tweener::new(0, 1, 4, Linear), |_| {}, Layer::new(1, 5));
+        assert!(manager.is_preempted(wobble));
+        assert!(!manager.is_preempted(other));
+
+        manager.update(2);
+        assert_eq!(idle.get(), 2);
+        assert!(!manager.is_paused(wobble));
+
+        // a user's pause is kept separately from preemption
+        manager.pause(wobble);
+        manager.update(2);
+        assert!(!manager.contains(hit));
+        assert!(!manager.is_preempted(wobble));
+        assert!(manager.is_paused(wobble));
+
+        manager.resume(wobble);
+        manager.update(2);
+        assert_eq!(idle.get(), 4);
+    }
+
+    #[test]
+    fn layers_cancel() {
+        let mut manager = TweenManager::new();
+        let base = Layer::new(0, 1).with_preemption(Preemption::Cancel);
+        let low = manager.add_layered(Tweener::new(0, 10, 10, Linear), |_| {}, base);
+        let same = manager.add_layered(Tweener::new(0, 10, 10, Linear), |_| {}, base);
+        let gone = manager.when_any([low], || {});
+
+        let high = manager.add_layered(Tweener::new(0, 10, 10, Linear), |_| {}, Layer::new(0, 2));
+        assert!(!manager.contains(low) && !manager.contains(same));
+        assert!(gone.is_complete());
+
+        // cancelling tweeners are never started under a higher one
+        let late = manager.add_layered(Tweener::new(0, 10, 10, Linear), |_| {}, base);
+        assert!(!manager.contains(late));
+        assert!(manager.contains(high));
+    }
+
+    #[test]
+    fn mixed_types() {
+        let (x, x_sink) = tracked();
+        let (y, y_sink) = tracked();
+        let mut manager = TweenManager::new();
+        let first = manager.add(Tweener::new(0, 10, 2, Linear), x_sink);
+        let second = manager.add(Tweener::new(0.0f32, 1.0, 4, Linear), y_sink);
+        assert_ne!(first, second);
+
+        manager.update(2);
+        assert_eq!((x.get(), y.get()), (10, 0.5));
+        assert!(manager.drain_finished().eq([first]));
+
+        manager.update(2);
+        assert_eq!(y.get(), 1.0);
+        assert!(manager.drain_finished().eq([second]));
+        assert!(manager.is_empty());
+    }
+}
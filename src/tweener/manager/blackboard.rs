@@ -0,0 +1,203 @@
+use super::{TweenHandle, TweenManager};
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+use alloc::{collections::BTreeMap, string::String};
+
+/// A small store of named values, kept by a [TweenManager] so its tweeners can trigger each other
+/// without any glue code.
+///
+/// Tweeners can set a value when they finish, with [set_on_finish](TweenManager::set_on_finish),
+/// and others can wait for a value to be set before they start, with
+/// [add_when](TweenManager::add_when). Game code can read and write it too, through
+/// [blackboard_mut](TweenManager::blackboard_mut), to feed in events or check on progress.
+///
+/// Flags are just values which are set, so [set_flag](Self::set_flag) stores `1.0`.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blackboard {
+    values: BTreeMap<String, f32>,
+}
+
+impl Blackboard {
+    /// Creates a new, empty Blackboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `key` to `value`, returning what it was before, if it was set.
+    pub fn set(&mut self, key: impl Into<String>, value: f32) -> Option<f32> {
+        self.values.insert(key.into(), value)
+    }
+
+    /// Sets `key` to `1.0`.
+    pub fn set_flag(&mut self, key: impl Into<String>) {
+        self.set(key, 1.0);
+    }
+
+    /// Returns the value of `key`, if it's set.
+    pub fn get(&self, key: &str) -> Option<f32> {
+        self.values.get(key).copied()
+    }
+
+    /// Returns `true` if `key` is set, to any value.
+    pub fn is_set(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// Unsets `key`, returning its value, if it was set.
+    pub fn remove(&mut self, key: &str) -> Option<f32> {
+        self.values.remove(key)
+    }
+
+    /// Unsets every key.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Iterates over every key which is set, and its value, in order of their keys.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f32)> + '_ {
+        self.values.iter().map(|(key, value)| (key.as_str(), *value))
+    }
+}
+
+impl<Time: TweenTime> TweenManager<Time> {
+    /// Adds a [Tweener] to the manager which waits until `key` is set on the [Blackboard] before
+    /// it starts. Like [add](Self::add), `on_update` is called with its value every time it moves.
+    ///
+    /// The manager checks for the key at the start of each [update](Self::update), so a tweener
+    /// waiting on another one's [set_on_finish](Self::set_on_finish) starts on the update after
+    /// that one finishes. If the key is already set, it starts straight away.
+    ///
+    /// ```
+    /// # use tween::{TweenManager, Tweener};
+    /// let mut manager = TweenManager::new();
+    /// let door = manager.add(Tweener::linear(0.0f32, 90.0, 1.0), |_angle| {});
+    /// manager.set_on_finish(door, "door_open", 1.0);
+    ///
+    /// let walk = manager.add_when("door_open", Tweener::linear(0.0f32, 5.0, 2.0), |_x| {});
+    ///
+    /// manager.update(1.0);
+    /// assert!(manager.blackboard().is_set("door_open"));
+    /// assert!(manager.is_waiting(walk));
+    ///
+    /// manager.update(1.0);
+    /// assert!(!manager.is_waiting(walk));
+    /// ```
+    pub fn add_when<Value, T>(
+        &mut self,
+        key: impl Into<String>,
+        tweener: Tweener<Value, Time, T>,
+        on_update: impl FnMut(Value) + 'static,
+    ) -> TweenHandle
+    where
+        Value: TweenValue + 'static,
+        Time: 'static,
+        T: Tween<Value> + 'static,
+    {
+        let key = key.into();
+        let handle = self.add(tweener, on_update);
+        if !self.blackboard.is_set(&key) {
+            if let Some(entry) = self.entries.last_mut() {
+                entry.waiting_for = Some(key);
+            }
+        }
+
+        handle
+    }
+
+    /// Sets `key` to `value` on the [Blackboard] when the tweener finishes, replacing anything set
+    /// up by an earlier call. Cancelled tweeners don't set anything. Returns `false` if the handle
+    /// didn't match anything.
+    pub fn set_on_finish(&mut self, handle: TweenHandle, key: impl Into<String>, value: f32) -> bool {
+        self.changed();
+        match self.entries.iter_mut().find(|entry| entry.handle == handle) {
+            Some(entry) => {
+                entry.on_finish = Some((key.into(), value));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the handle matches a tweener which is still waiting for its key to be set.
+    /// See [add_when](Self::add_when).
+    pub fn is_waiting(&self, handle: TweenHandle) -> bool {
+        self.entry(handle).is_some_and(|entry| entry.waiting_for.is_some())
+    }
+
+    /// The manager's [Blackboard].
+    #[inline]
+    pub fn blackboard(&self) -> &Blackboard {
+        &self.blackboard
+    }
+
+    /// The manager's [Blackboard], to set or unset values on.
+    #[inline]
+    pub fn blackboard_mut(&mut self) -> &mut Blackboard {
+        self.changed();
+        &mut self.blackboard
+    }
+
+    /// Starts every waiting tweener whose key is set.
+    pub(super) fn release_waiting(&mut self) {
+        let blackboard = &self.blackboard;
+        for entry in &mut self.entries {
+            if entry.waiting_for.as_deref().is_some_and(|key| blackboard.is_set(key)) {
+                entry.waiting_for = None;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    use std::{cell::Cell, rc::Rc, vec::Vec};
+
+    #[test]
+    fn chains_through_flags() {
+        let walked = Rc::new(Cell::new(0));
+        let mut manager = TweenManager::new();
+
+        let door = manager.add(Tweener::new(0, 90, 2, Linear), |_| {});
+        manager.set_on_finish(door, "door_open", 1.0);
+
+        let sink = walked.clone();
+        let walk = manager.add_when("door_open", Tweener::new(0, 10, 10, Linear), move |x| sink.set(x));
+        let wave = manager.add_when("greeted", Tweener::new(0, 1, 1, Linear), |_| {});
+        assert!(manager.is_waiting(walk) && manager.is_waiting(wave));
+
+        manager.update(1);
+        manager.update(1);
+        assert_eq!(manager.blackboard().get("door_open"), Some(1.0));
+        assert_eq!(walked.get(), 0);
+
+        manager.update(1);
+        assert_eq!(walked.get(), 1);
+        assert!(manager.is_waiting(wave));
+
+        // game code can set flags too
+        manager.blackboard_mut().set_flag("greeted");
+        manager.update(1);
+        assert!(!manager.contains(wave));
+    }
+
+    #[test]
+    fn cancelled_tweeners_set_nothing() {
+        let mut manager = TweenManager::new();
+        let door = manager.add(Tweener::new(0, 90, 2, Linear), |_| {});
+        manager.set_on_finish(door, "door_open", 1.0);
+        manager.cancel(door);
+
+        manager.update(2);
+        assert!(!manager.blackboard().is_set("door_open"));
+        assert!(!manager.set_on_finish(door, "door_open", 1.0));
+
+        // already set keys don't wait at all
+        manager.blackboard_mut().set("door_open", 0.5);
+        let walk = manager.add_when("door_open", Tweener::new(0, 10, 10, Linear), |_| {});
+        assert!(!manager.is_waiting(walk));
+        assert_eq!(manager.blackboard().iter().collect::<Vec<_>>(), [("door_open", 0.5)]);
+    }
+}
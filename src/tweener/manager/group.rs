@@ -0,0 +1,63 @@
+use super::TweenHandle;
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use core::cell::Cell;
+
+/// A flag which is set once a group of tweeners, waited on with
+/// [when_all](super::TweenManager::when_all) or [when_any](super::TweenManager::when_any),
+/// completes.
+///
+/// Clones share the same flag, so it can be handed to whatever needs to poll it, like a state
+/// machine which checks each frame whether it can move on.
+#[derive(Debug, Clone, Default)]
+pub struct Completion(Rc<Cell<bool>>);
+
+impl Completion {
+    /// Returns `true` once the group has completed.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.0.get()
+    }
+}
+
+/// A group of tweeners being waited on.
+pub(super) struct Group {
+    handles: Vec<TweenHandle>,
+    all: bool,
+    on_complete: Option<Box<dyn FnOnce()>>,
+    completion: Completion,
+}
+
+impl Group {
+    pub(super) fn new(handles: Vec<TweenHandle>, all: bool, on_complete: Box<dyn FnOnce()>) -> Self {
+        Self {
+            handles,
+            all,
+            on_complete: Some(on_complete),
+            completion: Completion::default(),
+        }
+    }
+
+    pub(super) fn completion(&self) -> Completion {
+        self.completion.clone()
+    }
+
+    /// Completes the group if enough of its tweeners have stopped running, where `running` says
+    /// whether a tweener still is. Returns `true` if it completed.
+    pub(super) fn settle(&mut self, running: impl Fn(TweenHandle) -> bool) -> bool {
+        let mut stopped = self.handles.iter().map(|&handle| !running(handle));
+        let complete = if self.all {
+            stopped.all(|stopped| stopped)
+        } else {
+            self.handles.is_empty() || stopped.any(|stopped| stopped)
+        };
+
+        if complete {
+            self.completion.0.set(true);
+            if let Some(on_complete) = self.on_complete.take() {
+                on_complete();
+            }
+        }
+
+        complete
+    }
+}
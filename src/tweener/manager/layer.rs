@@ -0,0 +1,78 @@
+use super::{Entry, TweenManager};
+use crate::TweenTime;
+use alloc::vec::Vec;
+
+/// What happens to a layered tweener when one of higher priority starts on its target.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Preemption {
+    /// The tweener is held where it is, and resumes once nothing of higher priority is running on
+    /// its target anymore.
+    #[default]
+    Pause,
+
+    /// The tweener is cancelled.
+    Cancel,
+}
+
+/// Puts a tweener in a [TweenManager] on a priority layer of some target, so that tweeners of
+/// higher priority on the same target override it. See
+/// [add_layered](TweenManager::add_layered).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layer {
+    /// What the tweener animates, such as an entity's id. Only tweeners on the same target
+    /// preempt each other.
+    pub target: u64,
+
+    /// The priority of the tweener. Higher priorities preempt lower ones.
+    pub priority: i32,
+
+    /// What happens to the tweener when it's preempted.
+    pub preemption: Preemption,
+}
+
+impl Layer {
+    /// Creates a new Layer, which pauses its tweener while it's preempted.
+    pub const fn new(target: u64, priority: i32) -> Self {
+        Self {
+            target,
+            priority,
+            preemption: Preemption::Pause,
+        }
+    }
+
+    /// Sets what happens to the tweener when it's preempted.
+    pub const fn with_preemption(mut self, preemption: Preemption) -> Self {
+        self.preemption = preemption;
+        self
+    }
+
+    /// Returns `true` if this layer is overridden by `other`.
+    fn preempted_by(&self, other: &Layer) -> bool {
+        self.target == other.target && self.priority < other.priority
+    }
+}
+
+impl<Time: TweenTime> TweenManager<Time> {
+    /// Preempts every layered tweener which has a tweener of higher priority on its target, and
+    /// lets the rest run. Returns `true` if any were cancelled.
+    pub(super) fn settle_layers(&mut self) -> bool {
+        let layers: Vec<Layer> = self.entries.iter().filter_map(|entry| entry.layer).collect();
+        if layers.is_empty() {
+            return false;
+        }
+
+        let len = self.entries.len();
+        self.entries.retain_mut(|entry: &mut Entry<Time>| {
+            let Some(layer) = entry.layer else {
+                return true;
+            };
+
+            entry.preempted = layers.iter().any(|other| layer.preempted_by(other));
+            !(entry.preempted && layer.preemption == Preemption::Cancel)
+        });
+
+        self.entries.len() != len
+    }
+}
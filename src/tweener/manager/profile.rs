@@ -0,0 +1,40 @@
+use super::TweenHandle;
+use std::time::Duration;
+
+/// What happened during a [TweenManager](crate::TweenManager)'s last
+/// [update](crate::TweenManager::update), for spotting slow frames in big scenes without reaching
+/// for an external profiler.
+///
+/// Only available with the `profile` feature. Timing each tweener costs a couple of clock reads,
+/// so leave the feature off in release builds unless you need it there.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct UpdateProfile {
+    /// How many tweeners were moved.
+    pub updated: usize,
+
+    /// How many tweeners were skipped because they were paused.
+    pub paused: usize,
+
+    /// How many tweeners finished.
+    pub finished: usize,
+
+    /// How long the whole update took.
+    pub total: Duration,
+
+    /// How long the slowest tweener took to move, including its callback.
+    pub slowest: Duration,
+
+    /// The handle of the slowest tweener, if any were moved.
+    pub slowest_handle: Option<TweenHandle>,
+}
+
+impl UpdateProfile {
+    pub(super) fn record(&mut self, handle: TweenHandle, elapsed: Duration) {
+        self.updated += 1;
+
+        if self.slowest_handle.is_none() || elapsed > self.slowest {
+            self.slowest = elapsed;
+            self.slowest_handle = Some(handle);
+        }
+    }
+}
@@ -0,0 +1,497 @@
+use super::{TweenHandle, TweenManager};
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+use alloc::vec::Vec;
+
+/// A command given to a [TweenManager], as recorded by a [TweenRecorder].
+///
+/// Tweeners and their callbacks can't be written down, so starting and retargeting a tweener
+/// carry a `spec` instead: any value of your choosing which describes what was done well enough
+/// to do it again, like an enum of the animations in your game.
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TweenCommand<Time, S> {
+    /// The manager was updated by `delta`.
+    Update {
+        /// How far the manager was moved.
+        delta: Time,
+    },
+
+    /// A tweener described by `spec` was added, and given `handle`.
+    Start {
+        /// The handle the tweener was given.
+        handle: TweenHandle,
+
+        /// What was started.
+        spec: S,
+    },
+
+    /// A tweener was retargeted, as described by `spec`.
+    Retarget {
+        /// The tweener which was retargeted.
+        handle: TweenHandle,
+
+        /// What it was retargeted to.
+        spec: S,
+    },
+
+    /// A tweener was cancelled.
+    Cancel {
+        /// The tweener which was cancelled.
+        handle: TweenHandle,
+    },
+
+    /// A tweener was paused.
+    Pause {
+        /// The tweener which was paused.
+        handle: TweenHandle,
+    },
+
+    /// A tweener was resumed.
+    Resume {
+        /// The tweener which was resumed.
+        handle: TweenHandle,
+    },
+
+    /// Every tweener was cancelled.
+    Clear,
+}
+
+/// A [TweenCommand], stamped with the time it was given at, which is the sum of every `delta`
+/// the manager had been updated by before it.
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimedCommand<Time, S> {
+    /// When the command was given.
+    pub time: Time,
+
+    /// What the command was.
+    pub command: TweenCommand<Time, S>,
+}
+
+/// Every command given to a [TweenRecorder], in order. With `serde`, this can be saved alongside
+/// a bug report, or checked in as a test, and then [replayed](Self::replay) to drive a fresh
+/// [TweenManager] through exactly the same frames.
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TweenLog<Time, S> {
+    clock: Time,
+    commands: Vec<TimedCommand<Time, S>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    unrecorded: bool,
+}
+
+impl<Time: TweenTime, S> TweenLog<Time, S> {
+    /// Creates a new, empty log.
+    pub fn new() -> Self {
+        Self {
+            clock: Time::ZERO,
+            commands: Vec::new(),
+            unrecorded: false,
+        }
+    }
+
+    /// The commands in this log, in the order they were given.
+    pub fn commands(&self) -> &[TimedCommand<Time, S>] {
+        &self.commands
+    }
+
+    /// The total time the manager was updated by.
+    pub fn clock(&self) -> Time {
+        self.clock
+    }
+
+    /// Returns `true` if the manager was changed in a way this log couldn't capture, so that
+    /// [replaying](Self::replay) it would diverge. See [TweenRecorder].
+    pub fn has_unrecorded_changes(&self) -> bool {
+        self.unrecorded
+    }
+
+    /// Runs every command in this log on `manager`, in order.
+    ///
+    /// Starting and retargeting are handed back to you: `start` should add the tweener described
+    /// by a spec to the manager and return its handle, and `retarget` should retarget the given
+    /// handle as described by a spec, usually with [TweenManager::retarget]. Handles in the log
+    /// are mapped to the handles `start` returns, so the manager doesn't need to be fresh.
+    ///
+    /// Logs with [unrecorded changes](Self::has_unrecorded_changes) can't be replayed faithfully,
+    /// so they're rejected before anything is run.
+    pub fn replay(
+        &self,
+        manager: &mut TweenManager<Time>,
+        mut start: impl FnMut(&mut TweenManager<Time>, &S) -> TweenHandle,
+        mut retarget: impl FnMut(&mut TweenManager<Time>, TweenHandle, &S) -> bool,
+    ) -> Result<(), UnrecordedChangesError> {
+        if self.unrecorded {
+            return Err(UnrecordedChangesError);
+        }
+
+        let mut handles: Vec<(TweenHandle, TweenHandle)> = Vec::new();
+        let find = |handles: &[(TweenHandle, TweenHandle)], recorded: TweenHandle| {
+            handles.iter().find(|(from, _)| *from == recorded).map(|(_, to)| *to)
+        };
+
+        for timed in &self.commands {
+            match &timed.command {
+                TweenCommand::Update { delta } => manager.update(*delta),
+                TweenCommand::Start { handle, spec } => {
+                    let replayed = start(manager, spec);
+                    handles.push((*handle, replayed));
+                }
+                TweenCommand::Retarget { handle, spec } => {
+                    if let Some(handle) = find(&handles, *handle) {
+                        retarget(manager, handle, spec);
+                    }
+                }
+                TweenCommand::Cancel { handle } => {
+                    if let Some(handle) = find(&handles, *handle) {
+                        manager.cancel(handle);
+                    }
+                }
+                TweenCommand::Pause { handle } => {
+                    if let Some(handle) = find(&handles, *handle) {
+                        manager.pause(handle);
+                    }
+                }
+                TweenCommand::Resume { handle } => {
+                    if let Some(handle) = find(&handles, *handle) {
+                        manager.resume(handle);
+                    }
+                }
+                TweenCommand::Clear => manager.clear(),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, command: TweenCommand<Time, S>) {
+        self.commands.push(TimedCommand {
+            time: self.clock,
+            command,
+        });
+    }
+}
+
+impl<Time: TweenTime, S> Default for TweenLog<Time, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The error returned when [replaying](TweenLog::replay) a log whose manager was changed outside
+/// of its [TweenRecorder].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnrecordedChangesError;
+
+impl core::fmt::Display for UnrecordedChangesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("the manager was changed in ways the log didn't record")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnrecordedChangesError {}
+
+/// A TweenRecorder is a [TweenManager] which writes down every command it's given into a
+/// [TweenLog], so that a session can be replayed deterministically later, for reproducing bugs or
+/// for regression testing animations.
+///
+/// It derefs to its manager for queries, like [contains](TweenManager::contains). What it records
+/// is exactly what it has methods for: starting a tweener, [update](Self::update),
+/// [retarget](Self::retarget), [cancel](Self::cancel), [pause](Self::pause),
+/// [resume](Self::resume) and [clear](Self::clear). Anything else, like
+/// [when_all](TweenManager::when_all), [set_on_finish](TweenManager::set_on_finish) or the
+/// [Blackboard], can only be reached through the manager given to [add](Self::add)'s closure,
+/// and doing any of it there, or starting anything other than exactly one tweener, marks the log
+/// as having [unrecorded changes](TweenLog::has_unrecorded_changes), so it won't replay.
+///
+/// ```
+/// # use tween::{TweenManager, TweenRecorder, Tweener};
+/// use std::{cell::Cell, rc::Rc};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Anim {
+///     FadeIn,
+///     FadeTo(f32),
+/// }
+///
+/// fn start(manager: &mut TweenManager<f32>, alpha: &Rc<Cell<f32>>) -> tween::TweenHandle {
+///     let alpha = alpha.clone();
+///     manager.add(Tweener::linear(0.0, 1.0, 4.0), move |value| alpha.set(value))
+/// }
+///
+/// let alpha = Rc::new(Cell::new(0.0));
+/// let mut recorder = TweenRecorder::new();
+///
+/// let fade = recorder.add(Anim::FadeIn, |manager| start(manager, &alpha));
+/// recorder.update(2.0);
+/// recorder.retarget(fade, 0.0f32, Anim::FadeTo(0.0));
+/// recorder.update(1.0);
+/// let recorded = alpha.get();
+///
+/// // later, maybe after loading the log from a bug report...
+/// let log = recorder.into_log();
+/// let replayed = Rc::new(Cell::new(0.0));
+/// let mut manager = TweenManager::new();
+///
+/// log.replay(
+///     &mut manager,
+///     |manager, _| start(manager, &replayed),
+///     |manager, handle, spec| match spec {
+///         Anim::FadeTo(alpha) => manager.retarget(handle, *alpha),
+///         Anim::FadeIn => false,
+///     },
+/// )
+/// .unwrap();
+/// assert_eq!(replayed.get(), recorded);
+/// ```
+pub struct TweenRecorder<Time, S> {
+    manager: TweenManager<Time>,
+    log: TweenLog<Time, S>,
+}
+
+impl<Time: TweenTime, S> TweenRecorder<Time, S> {
+    /// Creates a new TweenRecorder, with an empty manager and log.
+    pub fn new() -> Self {
+        Self {
+            manager: TweenManager::new(),
+            log: TweenLog::new(),
+        }
+    }
+
+    /// Adds a tweener, described by `spec`, to the manager. `add` should add it, just as `start`
+    /// will when the log is [replayed](TweenLog::replay), and return its handle. It can use any of
+    /// the manager's ways of adding a tweener, like [add_layered](TweenManager::add_layered), but
+    /// shouldn't change the manager in any other way.
+    pub fn add(&mut self, spec: S, add: impl FnOnce(&mut TweenManager<Time>) -> TweenHandle) -> TweenHandle {
+        let changes = self.manager.changes;
+        let handle = add(&mut self.manager);
+        if self.manager.changes != changes.wrapping_add(1) {
+            self.log.unrecorded = true;
+        }
+        self.log.push(TweenCommand::Start { handle, spec });
+
+        handle
+    }
+
+    /// Adds `tweener` to the manager, as with [TweenManager::add], describing it with `spec`.
+    pub fn add_tweener<Value, T>(
+        &mut self,
+        spec: S,
+        tweener: Tweener<Value, Time, T>,
+        on_update: impl FnMut(Value) + 'static,
+    ) -> TweenHandle
+    where
+        Value: TweenValue + 'static,
+        Time: 'static,
+        T: Tween<Value> + 'static,
+    {
+        self.add(spec, |manager| manager.add(tweener, on_update))
+    }
+
+    /// Updates the manager, as with [TweenManager::update].
+    pub fn update(&mut self, delta: Time) {
+        self.log.push(TweenCommand::Update { delta });
+        self.log.clock = self.log.clock.saturating_add(delta);
+
+        self.manager.update(delta);
+    }
+
+    /// Retargets a tweener to `end`, described by `spec`, as with [TweenManager::retarget].
+    pub fn retarget<Value: 'static>(&mut self, handle: TweenHandle, end: Value, spec: S) -> bool {
+        self.log.push(TweenCommand::Retarget { handle, spec });
+        self.manager.retarget(handle, end)
+    }
+
+    /// Cancels a tweener, as with [TweenManager::cancel].
+    pub fn cancel(&mut self, handle: TweenHandle) -> bool {
+        self.log.push(TweenCommand::Cancel { handle });
+        self.manager.cancel(handle)
+    }
+
+    /// Pauses a tweener, as with [TweenManager::pause].
+    pub fn pause(&mut self, handle: TweenHandle) -> bool {
+        self.log.push(TweenCommand::Pause { handle });
+        self.manager.pause(handle)
+    }
+
+    /// Resumes a tweener, as with [TweenManager::resume].
+    pub fn resume(&mut self, handle: TweenHandle) -> bool {
+        self.log.push(TweenCommand::Resume { handle });
+        self.manager.resume(handle)
+    }
+
+    /// Cancels every tweener, as with [TweenManager::clear].
+    pub fn clear(&mut self) {
+        self.log.push(TweenCommand::Clear);
+        self.manager.clear();
+    }
+
+    /// Returns the handles of all tweeners which have finished since this was last called, as with
+    /// [TweenManager::drain_finished].
+    pub fn drain_finished(&mut self) -> impl Iterator<Item = TweenHandle> + '_ {
+        self.manager.drain_finished()
+    }
+
+    /// The commands recorded so far.
+    pub fn log(&self) -> &TweenLog<Time, S> {
+        &self.log
+    }
+
+    /// Consumes this, returning everything it recorded.
+    pub fn into_log(self) -> TweenLog<Time, S> {
+        self.log
+    }
+}
+
+impl<Time: TweenTime, S> Default for TweenRecorder<Time, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Time, S> core::ops::Deref for TweenRecorder<Time, S> {
+    type Target = TweenManager<Time>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.manager
+    }
+}
+
+impl<Time, S: core::fmt::Debug> core::fmt::Debug for TweenRecorder<Time, S>
+where
+    Time: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TweenRecorder")
+            .field("manager", &self.manager)
+            .field("log", &self.log)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    use std::{cell::RefCell, rc::Rc};
+
+    type Trace = Rc<RefCell<Vec<(u32, i32)>>>;
+
+    fn start(manager: &mut TweenManager<i32>, trace: &Trace, id: u32) -> TweenHandle {
+        let trace = trace.clone();
+        manager.add(Tweener::new(0, 100, 10, Linear), move |value| {
+            trace.borrow_mut().push((id, value))
+        })
+    }
+
+    #[test]
+    fn replays_identically() {
+        let recorded = Trace::default();
+        let mut recorder = TweenRecorder::new();
+
+        let first = recorder.add(1, |manager| start(manager, &recorded, 1));
+        recorder.update(3);
+        let second = recorder.add(2, |manager| start(manager, &recorded, 2));
+        recorder.pause(first);
+        recorder.update(5);
+        recorder.resume(first);
+        recorder.retarget(second, 50, 0);
+        recorder.update(1);
+        recorder.cancel(first);
+        recorder.update(20);
+        assert_eq!(recorder.log().clock(), 29);
+        assert_eq!(recorder.log().commands()[3].time, 3);
+
+        let replayed = Trace::default();
+        let mut manager = TweenManager::new();
+
+        // something already in the manager shifts every handle
+        manager.add(Tweener::new(0, 1, 1000, Linear), |_| {});
+
+        recorder
+            .into_log()
+            .replay(
+                &mut manager,
+                |manager, id| start(manager, &replayed, *id),
+                |manager, handle, _| manager.retarget(handle, 50),
+            )
+            .unwrap();
+
+        assert_eq!(*replayed.borrow(), *recorded.borrow());
+    }
+
+    #[test]
+    fn replays_clears() {
+        let recorded = Trace::default();
+        let mut recorder = TweenRecorder::new();
+
+        recorder.add(1, |manager| start(manager, &recorded, 1));
+        recorder.update(3);
+        recorder.clear();
+        recorder.update(3);
+
+        let replayed = Trace::default();
+        let mut manager = TweenManager::new();
+        recorder
+            .log()
+            .replay(
+                &mut manager,
+                |manager, id| start(manager, &replayed, *id),
+                |_, _, _| false,
+            )
+            .unwrap();
+
+        assert!(manager.is_empty());
+        assert_eq!(*replayed.borrow(), *recorded.borrow());
+    }
+
+    #[test]
+    fn rejects_unrecorded_changes() {
+        let trace = Trace::default();
+        let mut recorder = TweenRecorder::new();
+
+        recorder.add(1, |manager| start(manager, &trace, 1));
+        assert!(!recorder.log().has_unrecorded_changes());
+
+        recorder.add(2, |manager| {
+            let handle = start(manager, &trace, 2);
+            manager.blackboard_mut().set_flag("sneaky");
+            handle
+        });
+        assert!(recorder.log().has_unrecorded_changes());
+
+        let replay = recorder.log().replay(
+            &mut TweenManager::new(),
+            |manager, id| start(manager, &trace, *id),
+            |_, _, _| false,
+        );
+        assert_eq!(replay, Err(UnrecordedChangesError));
+    }
+
+    #[test]
+    fn clock_saturates() {
+        let mut recorder = TweenRecorder::<u8, ()>::new();
+
+        recorder.update(200);
+        recorder.update(200);
+        assert_eq!(recorder.log().clock(), u8::MAX);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut recorder = TweenRecorder::new();
+        let handle = recorder.add_tweener("slide", Tweener::new(0, 1, 2, Linear), |_| {});
+        recorder.update(1);
+        recorder.cancel(handle);
+
+        let json = serde_json::to_string(recorder.log()).unwrap();
+        let log: TweenLog<i32, &str> = serde_json::from_str(&json).unwrap();
+        assert_eq!(&log, recorder.log());
+        assert!(json.contains(r#"{"start":{"handle":0,"spec":"slide"}}"#));
+    }
+}
@@ -0,0 +1,146 @@
+use super::{Managed, TweenHandle, TweenManager};
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+use alloc::boxed::Box;
+use core::{fmt, hash, marker::PhantomData};
+
+/// A [TweenHandle] which remembers the type of the [Tweener] it points to, so the tweener can be
+/// borrowed back out of its [TweenManager] with [get](TweenManager::get) and
+/// [get_mut](TweenManager::get_mut), with its full type, to inspect or change.
+///
+/// It's the same size as a TweenHandle, and turns into one with [handle](Self::handle) or `into`
+/// wherever a plain handle is wanted, like [when_all](TweenManager::when_all).
+pub struct TypedHandle<Value, Time, T> {
+    handle: TweenHandle,
+    _marker: PhantomData<Points<Value, Time, T>>,
+}
+
+/// What a [TypedHandle] points to, without owning one, so that handles stay `Send`, `Sync` and
+/// `Copy` whatever the tweener is.
+type Points<Value, Time, T> = fn() -> Tweener<Value, Time, T>;
+
+impl<Value, Time, T> TypedHandle<Value, Time, T> {
+    /// The plain [TweenHandle] underneath.
+    #[inline]
+    pub fn handle(self) -> TweenHandle {
+        self.handle
+    }
+}
+
+impl<Value, Time, T> From<TypedHandle<Value, Time, T>> for TweenHandle {
+    fn from(typed: TypedHandle<Value, Time, T>) -> Self {
+        typed.handle
+    }
+}
+
+// these are written out by hand, since deriving them would require them of `Value`, `Time` and `T`
+impl<Value, Time, T> Clone for TypedHandle<Value, Time, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Value, Time, T> Copy for TypedHandle<Value, Time, T> {}
+
+impl<Value, Time, T> PartialEq for TypedHandle<Value, Time, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl<Value, Time, T> Eq for TypedHandle<Value, Time, T> {}
+
+impl<Value, Time, T> hash::Hash for TypedHandle<Value, Time, T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.handle.hash(state);
+    }
+}
+
+impl<Value, Time, T> fmt::Debug for TypedHandle<Value, Time, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedHandle").field(&self.handle).finish()
+    }
+}
+
+impl<Time: TweenTime + 'static> TweenManager<Time> {
+    /// Adds a [Tweener] to the manager, like [add](Self::add), but returns a [TypedHandle], which
+    /// can borrow the tweener back later.
+    ///
+    /// ```
+    /// # use tween::{TweenManager, Tweener};
+    /// let mut manager = TweenManager::new();
+    /// let slide = manager.add_typed(Tweener::linear(0, 100, 10), |_x| {});
+    ///
+    /// manager.update(5);
+    /// let tweener = manager.get_mut(slide).unwrap();
+    /// assert_eq!(tweener.current_time, 5);
+    /// tweener.duration = 20;
+    ///
+    /// manager.update(5);
+    /// assert!(manager.get(slide).is_some_and(|tweener| !tweener.is_finished()));
+    /// ```
+    pub fn add_typed<Value, T>(
+        &mut self,
+        tweener: Tweener<Value, Time, T>,
+        on_update: impl FnMut(Value) + 'static,
+    ) -> TypedHandle<Value, Time, T>
+    where
+        Value: TweenValue + 'static,
+        T: Tween<Value> + 'static,
+    {
+        TypedHandle {
+            handle: self.insert(Box::new(Managed { tweener, on_update }), None),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Borrows the tweener a [TypedHandle] points to. Returns `None` if it has finished or been
+    /// cancelled.
+    pub fn get<Value, T>(&self, handle: TypedHandle<Value, Time, T>) -> Option<&Tweener<Value, Time, T>>
+    where
+        Value: 'static,
+        T: 'static,
+    {
+        self.entry(handle.handle)?.driver.tweener().downcast_ref()
+    }
+
+    /// Mutably borrows the tweener a [TypedHandle] points to, to retarget it or change how it
+    /// plays. Returns `None` if it has finished or been cancelled.
+    pub fn get_mut<Value, T>(&mut self, handle: TypedHandle<Value, Time, T>) -> Option<&mut Tweener<Value, Time, T>>
+    where
+        Value: 'static,
+        T: 'static,
+    {
+        self.changed();
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.handle == handle.handle)?
+            .driver
+            .tweener_mut()
+            .downcast_mut()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear};
+
+    #[test]
+    fn borrows_back_the_tweener() {
+        let mut manager = TweenManager::new();
+        let plain = manager.add(Tweener::new(0.0f32, 1.0, 1.0, Linear), |_| {});
+        let typed = manager.add_typed(Tweener::new(0.0f32, 1.0, 2.0, CubicBezier::EASE_IN), |_| {});
+
+        assert_eq!(core::mem::size_of_val(&typed), core::mem::size_of_val(&plain));
+        assert_ne!(TweenHandle::from(typed), plain);
+
+        manager.update(1.0);
+        manager.get_mut(typed).unwrap().retarget(2.0);
+        assert_eq!(manager.get(typed).unwrap().final_value(), 2.0);
+
+        manager.update(2.0);
+        assert!(manager.get(typed).is_none());
+        assert!(manager.drain_finished().any(|handle| handle == typed.handle()));
+    }
+}
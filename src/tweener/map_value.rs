@@ -0,0 +1,139 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// A MapValue is a [Tweener] whose output is run through a conversion, so that a tween computed
+/// in one value type can drive a value of another type entirely.
+///
+/// The most common use is to tween a plain `f32` from `0.0` to `1.0`, and then build whatever the
+/// frame needs out of it: a style, a transform, or several unrelated values at once. That way,
+/// everything follows the same clock and easing, without a Tweener for each of them, and the
+/// output doesn't even need to be a [TweenValue].
+///
+/// You will normally make this with [Tweener::map_value]. It derefs to the inner Tweener, so its
+/// time, duration and state can be checked and changed as usual.
+///
+/// ```
+/// # use tween::Tweener;
+/// #[derive(Debug, PartialEq)]
+/// struct Style {
+///     opacity: f32,
+///     offset: i32,
+///     label: &'static str,
+/// }
+///
+/// let mut reveal = Tweener::linear(0.0f32, 1.0, 4.0).map_value(|t| Style {
+///     opacity: t,
+///     offset: ((1.0 - t) * 20.0) as i32,
+///     label: if t < 1.0 { "loading" } else { "done" },
+/// });
+///
+/// assert_eq!(reveal.move_to(2.0), Style { opacity: 0.5, offset: 10, label: "loading" });
+/// assert_eq!(reveal.move_by(2.0), Style { opacity: 1.0, offset: 0, label: "done" });
+/// assert!(reveal.is_finished());
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapValue<Value, Time, T, F> {
+    /// The Tweener whose output is converted.
+    pub tweener: Tweener<Value, Time, T>,
+
+    /// The conversion.
+    pub map: F,
+}
+
+impl<Value, Time, T, F, Output> MapValue<Value, Time, T, F>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+    F: FnMut(Value) -> Output,
+{
+    /// Creates a new MapValue, converting the output of `tweener` with `map`.
+    pub fn new(tweener: Tweener<Value, Time, T>, map: F) -> Self {
+        Self { tweener, map }
+    }
+
+    /// Moves the inner Tweener to `position` with [Tweener::move_to], and returns its converted
+    /// output.
+    #[inline]
+    pub fn move_to(&mut self, position: Time) -> Output {
+        (self.map)(self.tweener.move_to(position))
+    }
+
+    /// Moves the inner Tweener by `delta` with [Tweener::move_by], and returns its converted
+    /// output.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Output {
+        (self.map)(self.tweener.move_by(delta))
+    }
+
+    /// Consumes this, returning the inner Tweener.
+    pub fn into_inner(self) -> Tweener<Value, Time, T> {
+        self.tweener
+    }
+}
+
+impl<Value, Time, T, F> core::ops::Deref for MapValue<Value, Time, T, F> {
+    type Target = Tweener<Value, Time, T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.tweener
+    }
+}
+
+impl<Value, Time, T, F> core::ops::DerefMut for MapValue<Value, Time, T, F> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.tweener
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Converts the output of this Tweener with `map`, which can return any type at all. See
+    /// [MapValue].
+    ///
+    /// ```
+    /// # use tween::Tweener;
+    /// let mut bar = Tweener::linear(0, 10, 10).map_value(|filled| "#".repeat(filled as usize));
+    ///
+    /// assert_eq!(bar.move_to(3), "###");
+    /// ```
+    pub fn map_value<F, Output>(self, map: F) -> MapValue<Value, Time, T, F>
+    where
+        F: FnMut(Value) -> Output,
+    {
+        MapValue::new(self, map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, Tweener};
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn follows_the_easing() {
+        use crate::QuadIn;
+
+        let mut eased = Tweener::new(0.0f32, 1.0, 10.0, QuadIn).map_value(|t| (t * 100.0) as u8);
+
+        assert_eq!(eased.move_to(5.0), 25);
+        assert_eq!(eased.move_by(5.0), 100);
+        assert!(eased.is_finished());
+    }
+
+    #[test]
+    fn changes_through_deref() {
+        let mut mapped = Tweener::new(0, 4, 4, Linear).map_value(|v| v * 2);
+        mapped.move_to(4);
+
+        mapped.current_time = 1;
+        assert_eq!(mapped.move_by(1), 4);
+        assert_eq!(mapped.into_inner().current_time, 2);
+    }
+}
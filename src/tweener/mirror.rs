@@ -0,0 +1,82 @@
+use crate::{Tween, TweenValue};
+
+/// A [Mirror] is a wrapper around a [Tween] which plays it forwards and then backwards again, all
+/// within one duration. It ends back where it started.
+///
+/// This is like an [Oscillator](crate::Oscillator) which only goes there and back once, but
+/// without taking twice as long. It's handy for "pulse" effects, like a button which grows and
+/// shrinks back when clicked.
+///
+/// ```
+/// # #[cfg(feature = "sine")] {
+/// # use tween::{Mirror, SineInOut, Tweener};
+/// let mut pulse = Tweener::new(1.0, 1.5, 1.0, Mirror::new(SineInOut));
+///
+/// assert_eq!(pulse.move_to(0.5), 1.5);
+/// assert_eq!(pulse.move_to(1.0), 1.0);
+/// assert!(pulse.is_finished());
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Mirror<T: ?Sized>(pub T);
+
+impl<T> Mirror<T> {
+    /// Creates a new Mirror around a [Tween].
+    pub fn new(tween: T) -> Self {
+        Self(tween)
+    }
+}
+
+impl<Value, T> Tween<Value> for Mirror<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let span = self.0.span();
+        let percent = if percent <= span / 2.0 {
+            percent * 2.0
+        } else {
+            (span - percent) * 2.0
+        };
+
+        self.0.tween(value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.0.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Linear, Tweener};
+
+    #[test]
+    fn there_and_back() {
+        let fixed = FixedTweener::new(0, 4, 4, Mirror::new(Linear), 1);
+
+        assert!(fixed.eq([2, 4, 2, 0]));
+    }
+
+    #[test]
+    fn clamps_to_start() {
+        let mut tweener = Tweener::new(10, 20, 4, Mirror::new(Linear));
+
+        assert_eq!(tweener.move_to(-1), 10);
+        assert_eq!(tweener.move_to(2), 20);
+        assert_eq!(tweener.move_to(5), 10);
+    }
+}
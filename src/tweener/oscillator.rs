@@ -1,4 +1,4 @@
-use crate::{Tween, TweenValue};
+use crate::{PhaseShift, PingPong, Tween, TweenValue, math::float::trunc};
 
 /// An [Oscillator] is a wrapper around a [Tween] which places the Tween into an infinite
 /// ping pong.
@@ -7,6 +7,7 @@ use crate::{Tween, TweenValue};
 /// beginning, it restarts it at the end and travels backwards. For many Tweens in this library,
 /// this is the same
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Oscillator<T: ?Sized>(pub T);
 
@@ -15,6 +16,38 @@ impl<T> Oscillator<T> {
     pub fn new(tween: T) -> Self {
         Self(tween)
     }
+
+    /// Wraps this Oscillator in a [PhaseShift] so that it starts `fraction` of the way through
+    /// its cycle. The cycle goes there and back again, so a `fraction` of `0.5` starts at the far
+    /// end, heading back.
+    ///
+    /// ```
+    /// # use tween::{Linear, Oscillator, Tweener};
+    /// let mut oscillator = Tweener::new(0.0, 4.0, 4.0, Oscillator::new(Linear).with_phase(0.5));
+    ///
+    /// assert_eq!(oscillator.move_to(0.0), 4.0);
+    /// assert_eq!(oscillator.move_to(1.0), 3.0);
+    /// ```
+    pub fn with_phase(self, fraction: f32) -> PhaseShift<Self> {
+        PhaseShift::new(self, fraction * 2.0)
+    }
+
+    /// Wraps this Oscillator in a [PhaseShift] with a phase picked from the given seed, so that it
+    /// starts somewhere in the middle of its cycle. The same seed always gives the same phase.
+    ///
+    /// Since an Oscillator's full cycle goes there and back again, the phase is in `0.0..2.0`.
+    pub fn with_random_phase(self, seed: u64) -> PhaseShift<Self> {
+        let mut shift = PhaseShift::random(self, seed);
+        shift.phase *= 2.0;
+
+        shift
+    }
+
+    /// Makes this Oscillator finite, so that it goes there and back again `count` times and then
+    /// finishes. See [PingPong].
+    pub fn with_cycles(self, count: u32) -> PingPong<T> {
+        PingPong::new(self.0, count)
+    }
 }
 
 impl<Value, T> Tween<Value> for Oscillator<T>
@@ -26,11 +59,7 @@ where
     fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
         let temp = percent % 2.0;
 
-        #[cfg(feature = "std")]
-        let (which_tween, percent) = { (temp.trunc(), percent.fract()) };
-
-        #[cfg(feature = "libm")]
-        let (which_tween, percent) = { (libm::truncf(temp), percent - libm::truncf(percent)) };
+        let (which_tween, percent) = (trunc(temp), percent - trunc(percent));
 
         // note: we don't have to worry about 0/1 difference here, since the tween
         // will get us to the same place
@@ -0,0 +1,119 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// An [OutputClamp] is a wrapper around a [Tween] which keeps its output between a `min` and a
+/// `max`. This stops overshooting tweens, like [BackIn](crate::BackIn) or
+/// [ElasticOut](crate::ElasticOut), from pushing a value somewhere it can't go, such as a health
+/// bar below zero or an alpha above one.
+///
+/// Since tweens work on `value_delta`, `min` and `max` are relative to the start of the tween. You
+/// will normally make this with [Tweener::clamp_output], which takes absolute bounds instead.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputClamp<Value, T: ?Sized> {
+    /// The lowest value the tween can return, relative to its start.
+    pub min: Value,
+
+    /// The highest value the tween can return, relative to its start.
+    pub max: Value,
+
+    /// The underlying Tween.
+    pub tween: T,
+}
+
+impl<Value, T> OutputClamp<Value, T> {
+    /// Creates a new OutputClamp around a [Tween], with bounds relative to the tween's start.
+    pub fn new(tween: T, min: Value, max: Value) -> Self {
+        Self { min, max, tween }
+    }
+}
+
+impl<Value, T> Tween<Value> for OutputClamp<Value, T>
+where
+    Value: TweenValue + PartialOrd,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let value = self.tween.tween(value_delta, percent);
+
+        if value < self.min {
+            self.min.clone()
+        } else if value > self.max {
+            self.max.clone()
+        } else {
+            value
+        }
+    }
+
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.tween.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.tween.returns_to_start()
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Value: TweenValue + PartialOrd,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Keeps the output of this Tweener between `min` and `max`, after easing. See [OutputClamp].
+    ///
+    /// The start and end values of the Tweener should already be within these bounds.
+    ///
+    /// ```
+    /// # #[cfg(feature = "back")] {
+    /// # use tween::Tweener;
+    /// let mut alpha = Tweener::back_out(0.0, 1.0, 10.0).clamp_output(0.0, 1.0);
+    ///
+    /// // `BackOut` would overshoot past `1.0` here
+    /// assert_eq!(alpha.move_to(7.0), 1.0);
+    /// # }
+    /// ```
+    pub fn clamp_output(self, min: Value, max: Value) -> Tweener<Value, Time, OutputClamp<Value, T>> {
+        let start = self.initial_value();
+        let (min, max) = (min - start.clone(), max - start);
+
+        self.map(|tween| OutputClamp::new(tween, min.clone(), max.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CubicBezier, Tweener};
+
+    #[cfg(all(feature = "back", feature = "elastic"))]
+    #[test]
+    fn never_leaves_bounds() {
+        use crate::{BackIn, ElasticOut};
+
+        let mut health = Tweener::new(100, 0, 100, BackIn).clamp_output(0, 100);
+        let mut alpha = Tweener::new(0.0, 1.0, 100.0, ElasticOut).clamp_output(0.0, 1.0);
+
+        for time in 0..=100 {
+            let health = health.move_to(time);
+            assert!((0..=100).contains(&health));
+
+            let alpha = alpha.move_to(time as f32);
+            assert!((0.0..=1.0).contains(&alpha));
+        }
+    }
+
+    #[test]
+    fn inside_bounds_untouched() {
+        let mut plain = Tweener::new(10.0, 20.0, 1.0, CubicBezier::EASE_IN);
+        let mut clamped = plain.clamp_output(-100.0, 100.0);
+
+        for i in 0..=10 {
+            let time = i as f32 / 10.0;
+            assert_eq!(clamped.move_to(time), plain.move_to(time));
+        }
+    }
+}
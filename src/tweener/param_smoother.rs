@@ -0,0 +1,302 @@
+use crate::{TweenValue, math::float::exp2};
+
+/// The shape of the ramps a [ParamSmoother] makes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RampShape {
+    /// Moves at a steady rate, which suits most parameters.
+    #[default]
+    Linear,
+
+    /// Moves quickly at first, and slows as it nears the target, like an analog RC filter. This
+    /// suits gains and frequencies, whose changes are heard relative to where they are.
+    Exponential,
+
+    /// Starts and finishes gently, with no sudden change of rate at either end.
+    SCurve,
+}
+
+impl RampShape {
+    /// How far along a ramp of this shape is, where `percent` runs from `0.0` to `1.0`.
+    pub fn apply(self, percent: f32) -> f32 {
+        match self {
+            RampShape::Linear => percent,
+            RampShape::Exponential => {
+                // ten halvings over the ramp, normalized so it lands exactly on the target
+                const HALVINGS: f32 = 10.0;
+                (1.0 - exp2(-HALVINGS * percent)) / (1.0 - exp2(-HALVINGS))
+            }
+            RampShape::SCurve => percent * percent * (3.0 - 2.0 * percent),
+        }
+    }
+}
+
+/// A ParamSmoother ramps an audio plugin's parameter to new values over a fixed number of samples,
+/// so that changes, like a gain knob being turned, don't click.
+///
+/// Targets are set at control rate, once per block, with [set_target](Self::set_target), and the
+/// smoother is advanced once per sample with [next_sample](Self::next_sample) or
+/// [fill](Self::fill). Setting the same target again leaves the ramp alone, so it's fine to set it
+/// every block. Setting a new target mid-ramp starts a new ramp from wherever the value is, so
+/// the output never jumps.
+///
+/// Unlike a [Smoother](crate::Smoother), which chases its target forever, every ramp lands exactly
+/// on its target after `ramp_length` samples, and stays there.
+///
+/// ```
+/// # use tween::{ParamSmoother, RampShape};
+/// let mut gain = ParamSmoother::new(0.0f32, 4, RampShape::Linear);
+/// gain.set_target(1.0);
+///
+/// let mut block = [0.0; 6];
+/// gain.fill(&mut block);
+/// assert_eq!(block, [0.25, 0.5, 0.75, 1.0, 1.0, 1.0]);
+/// assert!(!gain.is_smoothing());
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamSmoother<Value> {
+    /// How many samples each ramp takes. Changing this takes effect on the next ramp.
+    pub ramp_length: u32,
+
+    /// The shape of each ramp. Changing this mid-ramp changes the rest of the ramp.
+    pub shape: RampShape,
+
+    value: Value,
+    start: Value,
+    target: Value,
+    position: u32,
+    length: u32,
+}
+
+impl<Value: TweenValue> ParamSmoother<Value> {
+    /// Creates a new ParamSmoother resting at `value`, which ramps over `ramp_length` samples.
+    pub fn new(value: Value, ramp_length: u32, shape: RampShape) -> Self {
+        Self {
+            ramp_length,
+            shape,
+            value: value.clone(),
+            start: value.clone(),
+            target: value,
+            position: 0,
+            length: 0,
+        }
+    }
+
+    /// Creates a new ParamSmoother resting at `value`, which ramps over `seconds` at the given
+    /// `sample_rate`.
+    ///
+    /// ```
+    /// # use tween::{ParamSmoother, RampShape};
+    /// let cutoff = ParamSmoother::with_ramp_time(1000.0f32, 0.02, 48000.0, RampShape::Exponential);
+    /// assert_eq!(cutoff.ramp_length, 960);
+    /// ```
+    pub fn with_ramp_time(value: Value, seconds: f32, sample_rate: f32, shape: RampShape) -> Self {
+        Self::new(value, Self::samples(seconds, sample_rate), shape)
+    }
+
+    /// Changes how long each ramp takes to `seconds` at the given `sample_rate`, such as when the
+    /// host changes its sample rate. This takes effect on the next ramp.
+    pub fn set_ramp_time(&mut self, seconds: f32, sample_rate: f32) {
+        self.ramp_length = Self::samples(seconds, sample_rate);
+    }
+
+    /// Starts ramping to `target`, from wherever the value is now. Setting the target it's
+    /// already ramping to, or resting at, does nothing.
+    pub fn set_target(&mut self, target: Value)
+    where
+        Value: PartialEq,
+    {
+        if target == self.target {
+            return;
+        }
+
+        self.start = self.value.clone();
+        self.target = target;
+        self.position = 0;
+        self.length = self.ramp_length;
+    }
+
+    /// Moves the value straight to `value`, and rests there. This is for when clicks don't matter,
+    /// like when playback starts or a preset is loaded.
+    pub fn snap_to(&mut self, value: Value) {
+        *self = Self::new(value, self.ramp_length, self.shape);
+    }
+
+    /// Advances the smoother by one sample, and returns its value there.
+    #[inline]
+    pub fn next_sample(&mut self) -> Value {
+        if self.position < self.length {
+            self.position += 1;
+
+            self.value = if self.position == self.length {
+                self.target.clone()
+            } else {
+                let percent = self.shape.apply(self.position as f32 / self.length as f32);
+                self.start.clone() + (self.target.clone() - self.start.clone()).scale(percent)
+            };
+        } else {
+            self.value = self.target.clone();
+        }
+
+        self.value.clone()
+    }
+
+    /// Fills `block` with the value at each of its samples, advancing the smoother by its length.
+    pub fn fill(&mut self, block: &mut [Value]) {
+        for sample in block {
+            *sample = self.next_sample();
+        }
+    }
+
+    /// Advances the smoother by `samples` without reporting the values in between, and returns the
+    /// value it lands on.
+    pub fn skip(&mut self, samples: u32) -> Value {
+        if samples == 0 {
+            return self.value.clone();
+        }
+
+        if samples >= self.remaining_samples() {
+            self.position = self.length;
+            self.value = self.target.clone();
+            return self.value.clone();
+        }
+
+        self.position += samples - 1;
+        self.next_sample()
+    }
+
+    /// The value at the last sample.
+    #[inline]
+    pub fn value(&self) -> Value {
+        self.value.clone()
+    }
+
+    /// The value being ramped to.
+    #[inline]
+    pub fn target(&self) -> Value {
+        self.target.clone()
+    }
+
+    /// Returns `true` while a ramp is underway. Plugins can skip smoothing work for a whole block
+    /// when this is `false`.
+    #[inline]
+    pub fn is_smoothing(&self) -> bool {
+        self.position < self.length
+    }
+
+    /// How many samples are left in the current ramp.
+    #[inline]
+    pub fn remaining_samples(&self) -> u32 {
+        self.length - self.position
+    }
+
+    fn samples(seconds: f32, sample_rate: f32) -> u32 {
+        (seconds * sample_rate + 0.5).max(0.0) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    const SHAPES: [RampShape; 3] = [RampShape::Linear, RampShape::Exponential, RampShape::SCurve];
+
+    #[test]
+    fn shapes_land_on_their_ends() {
+        for shape in SHAPES {
+            assert_eq!(shape.apply(0.0), 0.0);
+            assert_relative_eq!(shape.apply(1.0), 1.0);
+
+            let mut last = 0.0;
+            for i in 1..=100 {
+                let now = shape.apply(i as f32 / 100.0);
+                assert!(now > last, "{shape:?} isn't rising at {i}");
+                last = now;
+            }
+        }
+
+        assert!(RampShape::Exponential.apply(0.1) > 0.4);
+        assert_eq!(RampShape::SCurve.apply(0.5), 0.5);
+    }
+
+    #[test]
+    fn ramps_take_their_length() {
+        for shape in SHAPES {
+            let mut smoother = ParamSmoother::new(-1.0f32, 64, shape);
+            smoother.set_target(3.0);
+
+            for _ in 0..63 {
+                smoother.next_sample();
+                assert!(smoother.is_smoothing());
+            }
+            assert_eq!(smoother.next_sample(), 3.0);
+            assert!(!smoother.is_smoothing());
+            assert_eq!(smoother.next_sample(), 3.0);
+        }
+    }
+
+    #[test]
+    fn restarts_without_clicks() {
+        for shape in SHAPES {
+            let mut smoother = ParamSmoother::new(0.0f32, 100, shape);
+            smoother.set_target(1.0);
+
+            let mut last = smoother.value();
+            for i in 0..400 {
+                // automation from the host, every 32 samples
+                if i % 32 == 0 {
+                    smoother.set_target(if (i / 32) % 2 == 0 { 1.0 } else { -1.0 });
+                }
+
+                let now = smoother.next_sample();
+                // the biggest step any shape takes over a ramp of 100 samples across 2.0
+                assert!((now - last).abs() < 0.15, "{shape:?} jumped at {i}");
+                last = now;
+            }
+        }
+    }
+
+    #[test]
+    fn same_target_keeps_ramping() {
+        let mut smoother = ParamSmoother::new(0.0f32, 8, RampShape::Linear);
+        smoother.set_target(8.0);
+        smoother.skip(4);
+
+        smoother.set_target(8.0);
+        assert_eq!(smoother.remaining_samples(), 4);
+        assert_eq!(smoother.next_sample(), 5.0);
+        assert_eq!(smoother.skip(0), 5.0);
+        assert_eq!(smoother.skip(2), 7.0);
+        assert_eq!(smoother.skip(100), 8.0);
+
+        smoother.snap_to(2.0);
+        assert_eq!((smoother.value(), smoother.target()), (2.0, 2.0));
+        assert!(!smoother.is_smoothing());
+    }
+
+    #[test]
+    fn ramp_length_changes_wait_for_the_next_ramp() {
+        let mut smoother = ParamSmoother::new(0.0f32, 4, RampShape::Linear);
+        smoother.set_target(4.0);
+        smoother.skip(2);
+
+        smoother.set_ramp_time(1.0, 2.0);
+        assert_eq!(smoother.ramp_length, 2);
+        assert_eq!(smoother.next_sample(), 3.0);
+        assert_eq!(smoother.next_sample(), 4.0);
+
+        smoother.set_target(0.0);
+        assert_eq!(smoother.remaining_samples(), 2);
+    }
+
+    #[test]
+    fn zero_length_ramps_snap() {
+        let mut smoother = ParamSmoother::new(0, 0, RampShape::SCurve);
+        smoother.set_target(10);
+
+        assert!(!smoother.is_smoothing());
+        assert_eq!(smoother.next_sample(), 10);
+    }
+}
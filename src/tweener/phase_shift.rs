@@ -0,0 +1,113 @@
+use crate::{Tween, TweenRng, TweenValue, XorShift64};
+
+/// A [PhaseShift] is a wrapper around a [Tween] which offsets every percent it is given by a fixed
+/// `phase`, in parametric space.
+///
+/// This is mostly useful around infinite tweens, like [Looper] and [Oscillator], to start them
+/// somewhere in the middle of their cycle. Since the offset lives in parametric space, rather than
+/// in frames, it is frame-rate independent. Around a finite tween, the inner tween will simply
+/// receive percents beyond `1.0`.
+///
+/// Many identical loopers created at once will pulse in lockstep. To avoid that, give each of them
+/// a different seed with [Looper::with_random_phase] or [Oscillator::with_random_phase]:
+///
+/// ```
+/// # #[cfg(feature = "sine")] {
+/// # use tween::{Looper, SineInOut, Tweener};
+/// let mut lights: Vec<_> = (0..50)
+///     .map(|seed| Tweener::new(0.0, 1.0, 2.0, Looper::new(SineInOut).with_random_phase(seed)))
+///     .collect();
+///
+/// let first = lights[0].move_to(0.0);
+/// assert!(lights.iter_mut().any(|light| light.move_to(0.0) != first));
+/// # }
+/// ```
+///
+/// [Looper]: crate::Looper
+/// [Oscillator]: crate::Oscillator
+/// [Looper::with_random_phase]: crate::Looper::with_random_phase
+/// [Oscillator::with_random_phase]: crate::Oscillator::with_random_phase
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseShift<T: ?Sized> {
+    /// The offset, in parametric space, added to every percent.
+    pub phase: f32,
+
+    /// The underlying Tween.
+    pub tween: T,
+}
+
+impl<T> PhaseShift<T> {
+    /// Creates a new PhaseShift around a [Tween] with the given phase.
+    pub fn new(tween: T, phase: f32) -> Self {
+        Self { phase, tween }
+    }
+
+    /// Creates a new PhaseShift around a [Tween] with a phase in `0.0..1.0`, picked from the given
+    /// seed. The same seed always gives the same phase.
+    pub fn random(tween: T, seed: u64) -> Self {
+        Self::random_with(tween, &mut XorShift64::new(seed))
+    }
+
+    /// Creates a new PhaseShift around a [Tween] with a phase in `0.0..1.0`, picked by the given
+    /// [TweenRng].
+    pub fn random_with<R: TweenRng + ?Sized>(tween: T, rng: &mut R) -> Self {
+        Self::new(tween, rng.next_f32())
+    }
+}
+
+impl<Value, T> Tween<Value> for PhaseShift<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween.tween(value_delta, percent + self.phase)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.tween.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.tween.returns_to_start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Looper, Oscillator, Tweener};
+
+    #[test]
+    fn shifted_looper() {
+        let mut looper = Tweener::new(0.0, 4.0, 4.0, PhaseShift::new(Looper::new(Linear), 0.5));
+
+        assert_eq!(looper.move_to(0.0), 2.0);
+        assert_eq!(looper.move_to(1.0), 3.0);
+        assert_eq!(looper.move_to(2.0), 4.0);
+        assert_eq!(looper.move_to(3.0), 1.0);
+    }
+
+    #[test]
+    fn random_phase_is_seeded() {
+        let a = Looper::new(Linear).with_random_phase(7);
+        let b = Looper::new(Linear).with_random_phase(7);
+        let c = Looper::new(Linear).with_random_phase(8);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!((0.0..1.0).contains(&a.phase));
+    }
+
+    #[test]
+    fn random_phase_oscillator() {
+        let shifted = Oscillator::new(Linear).with_random_phase(3);
+        assert!((0.0..2.0).contains(&shifted.phase));
+    }
+}
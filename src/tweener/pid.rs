@@ -0,0 +1,169 @@
+use crate::{TweenTime, TweenValue};
+
+/// The gains of a [PidFollower].
+#[derive(Debug, PartialEq, Clone, PartialOrd, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PidGains {
+    /// How strongly the follower moves towards the target, in proportion to how far away it is.
+    pub proportional: f32,
+
+    /// How strongly the follower makes up for error which builds up over time, such as lagging
+    /// behind a target which keeps moving.
+    pub integral: f32,
+
+    /// How strongly the follower reacts to the error changing, which damps overshoot.
+    pub derivative: f32,
+}
+
+impl PidGains {
+    /// Creates new PidGains.
+    pub const fn new(proportional: f32, integral: f32, derivative: f32) -> Self {
+        Self {
+            proportional,
+            integral,
+            derivative,
+        }
+    }
+}
+
+/// A PidFollower is a driver which chases a target value with a PID controller, for when a
+/// [Smoother](crate::Smoother) or a [Spring](crate::Spring) isn't the right model.
+///
+/// It has the same API as a [Smoother](crate::Smoother): it starts at a `value`, chases a `target`
+/// which can change at any time, and moves with [update](Self::update). Each update, the
+/// controller's output is used as the follower's velocity. With only a proportional gain, that's a
+/// simple exponential chase; the integral gain lets it catch up to targets which keep moving
+/// instead of trailing behind them, and the derivative gain reacts to sudden changes.
+///
+/// Unlike a [Smoother](crate::Smoother), a PID controller is stepped rather than solved, so it
+/// isn't frame-rate independent, and large steps with large gains can overshoot or even diverge.
+/// Update it with a steady `delta` where that matters.
+///
+/// ```
+/// # use tween::{PidFollower, PidGains};
+/// let mut follower = PidFollower::new(0.0, 100.0, PidGains::new(0.5, 0.0, 0.0));
+///
+/// assert_eq!(follower.update(1.0), 50.0);
+/// assert_eq!(follower.update(1.0), 75.0);
+///
+/// follower.set_target(0.0);
+/// assert_eq!(follower.update(1.0), 37.5);
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PidFollower<Value> {
+    /// The current value.
+    pub value: Value,
+
+    /// The value being chased.
+    pub target: Value,
+
+    /// The gains of the controller.
+    pub gains: PidGains,
+
+    integral: Value,
+    last_error: Option<Value>,
+}
+
+impl<Value> PidFollower<Value>
+where
+    Value: TweenValue,
+{
+    /// Creates a new PidFollower at `value`, chasing `target`.
+    pub fn new(value: Value, target: Value, gains: PidGains) -> Self {
+        Self {
+            integral: value.clone().scale(0.0),
+            value,
+            target,
+            gains,
+            last_error: None,
+        }
+    }
+
+    /// Creates a new PidFollower which is resting at `value`.
+    pub fn at_rest(value: Value, gains: PidGains) -> Self {
+        Self::new(value.clone(), value, gains)
+    }
+
+    /// Changes the target, without moving the current value.
+    #[inline]
+    pub fn set_target(&mut self, target: Value) {
+        self.target = target;
+    }
+
+    /// Moves both the value and the target to `value`, so the PidFollower rests there. This also
+    /// forgets the error the controller has built up.
+    pub fn snap_to(&mut self, value: Value) {
+        self.integral = value.clone().scale(0.0);
+        self.last_error = None;
+        self.value = value.clone();
+        self.target = value;
+    }
+
+    /// Steps the controller by `delta` of time, and returns the new value. A `delta` of zero or
+    /// less leaves the value where it is.
+    pub fn update<Time: TweenTime>(&mut self, delta: Time) -> Value {
+        let delta = delta.to_f32();
+        if delta <= 0.0 {
+            return self.value.clone();
+        }
+
+        let error = self.target.clone() - self.value.clone();
+        self.integral = self.integral.clone() + error.clone().scale(delta);
+
+        let mut output =
+            error.clone().scale(self.gains.proportional) + self.integral.clone().scale(self.gains.integral);
+        if let Some(last_error) = self.last_error.take() {
+            output = output + (error.clone() - last_error).scale(self.gains.derivative / delta);
+        }
+        self.last_error = Some(error);
+
+        self.value = self.value.clone() + output.scale(delta);
+        self.value.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn proportional_settles() {
+        let mut follower = PidFollower::new(0.0f32, 10.0, PidGains::new(2.0, 0.0, 0.0));
+
+        for _ in 0..120 {
+            follower.update(1.0 / 60.0);
+        }
+        assert_relative_eq!(follower.value, 10.0, epsilon = 0.2);
+    }
+
+    #[test]
+    fn integral_catches_moving_targets() {
+        let run = |gains| {
+            let mut follower = PidFollower::at_rest(0.0f32, gains);
+            for frame in 1..=600 {
+                follower.set_target(frame as f32 / 60.0);
+                follower.update(1.0 / 60.0);
+            }
+
+            follower.target - follower.value
+        };
+
+        // a proportional controller always trails a ramp by about `speed / gain`...
+        assert_relative_eq!(run(PidGains::new(2.0, 0.0, 0.0)), 0.5, epsilon = 0.02);
+        // ...but the integral closes the gap
+        assert!(run(PidGains::new(2.0, 1.0, 0.0)).abs() < 0.05);
+    }
+
+    #[test]
+    fn snap_forgets_error() {
+        let mut follower = PidFollower::new(0.0, 10.0, PidGains::new(1.0, 1.0, 0.5));
+        follower.update(0.5);
+        follower.update(0.5);
+
+        follower.snap_to(3.0);
+        assert_eq!(follower.update(1.0), 3.0);
+        assert_eq!(follower.update(0.0), 3.0);
+    }
+}
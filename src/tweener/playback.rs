@@ -0,0 +1,181 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// Which way a [Tweener] runs when it's moved with [move_by](Tweener::move_by).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Time moves towards the end of the tween, as normal.
+    #[default]
+    Forward,
+
+    /// Time moves back towards the start of the tween.
+    Backward,
+}
+
+impl Direction {
+    /// Returns the other Direction.
+    pub fn flipped(self) -> Self {
+        match self {
+            Direction::Forward => Direction::Backward,
+            Direction::Backward => Direction::Forward,
+        }
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Pauses this Tweener, so that [move_by](Self::move_by) leaves it where it is until it's
+    /// [resumed](Self::resume). Seeking still works while paused.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes this Tweener after a [pause](Self::pause).
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if this Tweener is paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Sets which way [move_by](Self::move_by) runs this Tweener. Changing direction mid-flight
+    /// rewinds from wherever the tween is, so a single Tweener can open and close a menu without
+    /// its output jumping.
+    ///
+    /// ```
+    /// # use tween::{Direction, Tweener};
+    /// let mut menu = Tweener::linear(0, 100, 10);
+    /// assert_eq!(menu.move_by(4), 40);
+    ///
+    /// // the player closed the menu before it finished opening
+    /// menu.set_direction(Direction::Backward);
+    /// assert_eq!(menu.move_by(1), 30);
+    /// assert_eq!(menu.move_by(10), 0);
+    /// assert!(menu.has_arrived());
+    /// ```
+    #[inline]
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    /// Flips the direction this Tweener runs in. See [set_direction](Self::set_direction).
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.direction = self.direction.flipped();
+    }
+
+    /// The direction this Tweener runs in.
+    #[inline]
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns `true` once this Tweener has reached the end it's heading towards: its end when
+    /// running [Direction::Forward], like [is_finished](Self::is_finished), or its start when
+    /// running [Direction::Backward].
+    pub fn has_arrived(&self) -> bool {
+        match self.direction {
+            Direction::Forward => self.is_finished(),
+            Direction::Backward => self.current_time <= Time::ZERO,
+        }
+    }
+
+    /// Scrubs this Tweener to `time`, returning the value there. This works whether or not the
+    /// Tweener is paused, and in either direction; it's the same as [move_to](Self::move_to).
+    #[inline]
+    pub fn seek_to(&mut self, time: Time) -> Value {
+        self.move_to(time)
+    }
+
+    /// Scrubs this Tweener to `percent` of its `duration`, returning the value there.
+    ///
+    /// For integer [TweenTime]s, the time is rounded towards zero.
+    #[inline]
+    pub fn seek_percent(&mut self, percent: f32) -> Value {
+        self.move_to(Time::from_f32(self.duration.to_f32() * percent))
+    }
+
+    /// Where `delta` of time back from now is, for running backward. Finished tweens rewind from
+    /// their end, rather than from however far past it they were moved, and we stop at zero
+    /// so unsigned times can't underflow.
+    pub(super) fn rewound_by(&self, delta: Time) -> Time {
+        let mut from = self.current_time;
+        if self.tween.is_finite() {
+            let end = Time::from_f32(self.duration.to_f32() * self.tween.span());
+            if from > end {
+                from = end;
+            }
+        }
+
+        if from <= Time::ZERO {
+            from
+        } else if from > delta {
+            from - delta
+        } else {
+            Time::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, FixedTweener, Linear, Looper, Tweener};
+
+    #[test]
+    fn pause_and_resume() {
+        let mut tweener = Tweener::linear(0, 10, 10);
+        tweener.move_by(2);
+
+        tweener.pause();
+        assert_eq!(tweener.move_by(5), 2);
+        assert_eq!(tweener.current_time, 2);
+        assert_eq!(tweener.seek_to(6), 6);
+
+        tweener.resume();
+        assert_eq!(tweener.move_by(1), 7);
+    }
+
+    #[test]
+    fn rewinds_from_the_end() {
+        let mut tweener = Tweener::linear(0u32, 10, 10u32);
+        assert_eq!(tweener.move_by(15), 10);
+
+        tweener.reverse();
+        assert_eq!(tweener.move_by(2), 8);
+        assert_eq!(tweener.move_by(20), 0);
+        assert_eq!(tweener.current_time, 0);
+        assert!(tweener.has_arrived());
+
+        tweener.reverse();
+        assert!(!tweener.has_arrived());
+    }
+
+    #[test]
+    fn fixed_backward() {
+        let mut fixed = FixedTweener::new(0, 4, 4, Linear, 1);
+        fixed.seek_percent(1.0);
+        fixed.set_direction(Direction::Backward);
+
+        assert_eq!(fixed.remaining_steps(), Some(4));
+        assert!(fixed.eq([3, 2, 1, 0]));
+    }
+
+    #[test]
+    fn infinite_backward() {
+        let mut tweener = Tweener::new(0, 10, 10, Looper::new(Linear));
+        tweener.move_to(25);
+
+        tweener.set_direction(Direction::Backward);
+        assert_eq!(tweener.move_by(1), 4);
+        assert_eq!(tweener.current_time, 24);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// A [RelativeTo] is a driver which tweens an *offset*, and adds it to a base value which is
+/// looked up fresh every time it is moved.
+///
+/// This keeps an animation correct when its reference point moves while it plays: a tooltip which
+/// slides out from a button that is itself being laid out, or a HUD marker which tracks an object
+/// on a moving camera. The base comes from a closure, so it can query whatever it needs.
+///
+/// ```
+/// # use tween::{RelativeTo, Tweener};
+/// use std::cell::Cell;
+///
+/// let parent = Cell::new(100.0);
+///
+/// // slide in from 20.0 left of the parent to right on top of it
+/// let mut child = RelativeTo::new(Tweener::linear(-20.0, 0.0, 2.0), || parent.get());
+/// assert_eq!(child.move_by(1.0), 90.0);
+///
+/// // ...and if the parent moves, so does the child.
+/// parent.set(200.0);
+/// assert_eq!(child.move_by(0.0), 190.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct RelativeTo<Value, Time, T, F> {
+    /// The Tweener which drives the offset.
+    pub tweener: Tweener<Value, Time, T>,
+
+    base: F,
+}
+
+impl<Value, Time, T, F> RelativeTo<Value, Time, T, F>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+    F: FnMut() -> Value,
+{
+    /// Creates a new RelativeTo out of a [Tweener] over offsets, and a closure which returns the
+    /// base they are relative to.
+    pub fn new(tweener: Tweener<Value, Time, T>, base: F) -> Self {
+        Self { tweener, base }
+    }
+
+    /// Moves the offset to a given Time, returning it added to the current base. See
+    /// [Tweener::move_to].
+    #[inline]
+    pub fn move_to(&mut self, position: Time) -> Value {
+        (self.base)() + self.tweener.move_to(position)
+    }
+
+    /// Drives the offset forward X steps in time, returning it added to the current base. See
+    /// [Tweener::move_by].
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        (self.base)() + self.tweener.move_by(delta)
+    }
+
+    /// Returns `true` once the underlying Tweener is finished. See [Tweener::is_finished].
+    pub fn is_finished(&self) -> bool {
+        self.tweener.is_finished()
+    }
+
+    /// Consumes this RelativeTo, returning its Tweener and its base closure.
+    pub fn into_inner(self) -> (Tweener<Value, Time, T>, F) {
+        (self.tweener, self.base)
+    }
+}
+
+impl<Value, Time, T, F> core::fmt::Debug for RelativeTo<Value, Time, T, F>
+where
+    Value: core::fmt::Debug,
+    Time: core::fmt::Debug,
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RelativeTo")
+            .field("tweener", &self.tweener)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn follows_moving_base() {
+        let camera = Cell::new(0);
+        let mut marker = RelativeTo::new(Tweener::linear(0, 10, 10), || camera.get());
+
+        assert_eq!(marker.move_by(5), 5);
+        camera.set(100);
+        assert_eq!(marker.move_by(0), 105);
+        camera.set(-100);
+        assert_eq!(marker.move_by(5), -90);
+        assert!(marker.is_finished());
+
+        // clamped values are still relative
+        assert_eq!(marker.move_by(5), -90);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{Looper, Oscillator, Tween, TweenValue};
+
+/// A [Repeat] is a [Looper] which only loops `count` times, and then finishes.
+///
+/// Unlike a Looper, a Repeat is [finite](Tween::is_finite), so a [Tweener](crate::Tweener) will
+/// report it as finished and clamp it to its end. The Tweener's `duration` is the length of *one*
+/// loop, just like with a Looper, so the whole Repeat takes `duration * count`.
+///
+/// ```
+/// # use tween::{Linear, Looper, Tweener};
+/// let mut tweener = Tweener::new(0, 2, 2, Looper::new(Linear).with_repeats(2));
+///
+/// assert_eq!(tweener.move_to(3), 1);
+/// assert_eq!(tweener.move_to(4), 2);
+/// assert!(tweener.is_finished());
+/// assert_eq!(tweener.move_to(5), 2);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Repeat<T: ?Sized> {
+    /// How many times the tween plays.
+    pub count: u32,
+
+    /// The underlying Looper.
+    pub looper: Looper<T>,
+}
+
+impl<T> Repeat<T> {
+    /// Creates a new Repeat around a [Tween], which plays it `count` times.
+    pub fn new(tween: T, count: u32) -> Self {
+        Self {
+            count,
+            looper: Looper::new(tween),
+        }
+    }
+}
+
+impl<Value, T> Tween<Value> for Repeat<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.looper.tween(value_delta, percent)
+    }
+
+    fn span(&self) -> f32 {
+        self.count as f32
+    }
+}
+
+/// A [PingPong] is an [Oscillator] which only goes there and back again `count` times, and then
+/// finishes back at the start.
+///
+/// Unlike an Oscillator, a PingPong is [finite](Tween::is_finite), so a
+/// [Tweener](crate::Tweener) will report it as finished and clamp it. The Tweener's `duration` is
+/// the length of *one way* of a cycle, just like with an Oscillator, so the whole PingPong takes
+/// `duration * count * 2`.
+///
+/// ```
+/// # use tween::{Linear, Oscillator, Tweener};
+/// let mut tweener = Tweener::new(0, 2, 2, Oscillator::new(Linear).with_cycles(1));
+///
+/// assert_eq!(tweener.move_to(2), 2);
+/// assert_eq!(tweener.move_to(3), 1);
+/// assert!(!tweener.is_finished());
+/// assert_eq!(tweener.move_to(4), 0);
+/// assert!(tweener.is_finished());
+/// assert_eq!(tweener.move_to(5), 0);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PingPong<T: ?Sized> {
+    /// How many times the tween goes there and back again.
+    pub count: u32,
+
+    /// The underlying Oscillator.
+    pub oscillator: Oscillator<T>,
+}
+
+impl<T> PingPong<T> {
+    /// Creates a new PingPong around a [Tween], which goes there and back again `count` times.
+    pub fn new(tween: T, count: u32) -> Self {
+        Self {
+            count,
+            oscillator: Oscillator::new(tween),
+        }
+    }
+}
+
+impl<Value, T> Tween<Value> for PingPong<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.oscillator.tween(value_delta, percent)
+    }
+
+    fn span(&self) -> f32 {
+        self.count as f32 * 2.0
+    }
+
+    fn returns_to_start(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Linear, Tweener};
+
+    #[test]
+    fn repeat_matches_looper() {
+        let mut looper = Tweener::new(0, 4, 4, Looper::new(Linear));
+        let mut repeat = Tweener::new(0, 4, 4, Repeat::new(Linear, 3));
+
+        for time in 0..12 {
+            assert_eq!(repeat.move_to(time), looper.move_to(time));
+            assert!(!repeat.is_finished());
+        }
+
+        assert_eq!(repeat.move_to(12), 4);
+        assert!(repeat.is_finished());
+        assert_eq!(repeat.move_to(13), 4);
+    }
+
+    #[test]
+    fn ping_pong_fixed() {
+        let fixed = FixedTweener::new(0, 2, 2, PingPong::new(Linear, 2), 1);
+        assert_eq!(fixed.remaining_steps(), Some(8));
+
+        assert!(fixed.eq([1, 2, 1, 0, 1, 2, 1, 0]));
+    }
+
+    #[test]
+    fn single_repeat_is_plain() {
+        let mut plain = Tweener::new(0.0, 10.0, 1.0, Linear);
+        let mut repeat = Tweener::new(0.0, 10.0, 1.0, Looper::new(Linear).with_repeats(1));
+
+        for time in [-0.5, 0.0, 0.25, 1.0, 1.5] {
+            assert_eq!(repeat.move_to(time), plain.move_to(time));
+            assert_eq!(repeat.current_time_state(), plain.current_time_state());
+        }
+    }
+}
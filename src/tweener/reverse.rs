@@ -0,0 +1,93 @@
+use crate::{Tween, TweenValue};
+
+/// A [Reverse] is a wrapper around a [Tween] which plays it backwards, from its end to its start.
+///
+/// The value still travels from the start of the tween to its end, but along the inner curve run
+/// in reverse. In other words, an ease-in becomes an ease-out and vice versa: `Reverse<QuadIn>`
+/// is the same as [QuadOut].
+///
+/// ```
+/// # #[cfg(feature = "quad")] {
+/// # use tween::{QuadIn, Reverse, Tweener};
+/// let mut reversed = Tweener::new(0.0f32, 100.0, 10.0, Reverse::new(QuadIn));
+/// let mut quad_out = Tweener::quad_out(0.0, 100.0, 10.0);
+///
+/// assert_eq!(reversed.move_to(3.0), quad_out.move_to(3.0));
+/// # }
+/// ```
+///
+/// [QuadOut]: crate::QuadOut
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Reverse<T: ?Sized>(pub T);
+
+impl<T> Reverse<T> {
+    /// Creates a new Reverse around a [Tween].
+    pub fn new(tween: T) -> Self {
+        Self(tween)
+    }
+}
+
+impl<Value, T> Tween<Value> for Reverse<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let end = if self.0.returns_to_start() {
+            value_delta.clone().scale(0.0)
+        } else {
+            value_delta.clone()
+        };
+
+        end - self.0.tween(value_delta, self.0.span() - percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.0.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.0.returns_to_start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear, Tweener};
+    use approx::assert_relative_eq;
+
+    #[cfg(feature = "cubic")]
+    #[test]
+    fn in_becomes_out() {
+        use crate::{CubicIn, CubicOut};
+
+        let mut reversed = Reverse::new(CubicIn);
+
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+            assert_relative_eq!(reversed.tween(1.0, pct), CubicOut.tween(1.0, pct), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn symmetric_is_unchanged() {
+        let mut plain = Tweener::new(0.0, 100.0, 10.0, Linear);
+        let mut reversed = Tweener::new(0.0, 100.0, 10.0, Reverse::new(Linear));
+        for time in -1..=11 {
+            let time = time as f32;
+            assert_relative_eq!(reversed.move_to(time), plain.move_to(time), epsilon = 1e-4);
+        }
+
+        let mut ease = CubicBezier::EASE_IN_OUT;
+        let mut reversed = Reverse::new(ease);
+        assert_relative_eq!(reversed.tween(1.0, 0.25), ease.tween(1.0, 0.25), epsilon = 1e-6);
+    }
+}
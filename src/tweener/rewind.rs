@@ -0,0 +1,215 @@
+use crate::{PidFollower, Smoother, TweenTime, TweenValue};
+use alloc::vec::Vec;
+
+/// A driver whose state builds up as it's stepped forward, like a [Smoother] or a
+/// [PidFollower], so it can't simply be moved to an earlier time the way a
+/// [Tweener](crate::Tweener) can. [Rewind] makes drivers like this seekable.
+///
+/// Clones are used as checkpoints, so a clone must capture all of the driver's state.
+pub trait Step<Time>: Clone {
+    /// What the driver returns when it's stepped.
+    type Output;
+
+    /// Steps the driver forward by `delta` of time.
+    fn step(&mut self, delta: Time) -> Self::Output;
+}
+
+impl<Value, Time> Step<Time> for Smoother<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    type Output = Value;
+
+    #[inline]
+    fn step(&mut self, delta: Time) -> Value {
+        self.update(delta)
+    }
+}
+
+impl<Value, Time> Step<Time> for PidFollower<Value>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    type Output = Value;
+
+    #[inline]
+    fn step(&mut self, delta: Time) -> Value {
+        self.update(delta)
+    }
+}
+
+/// A Rewind wraps a stateful driver, like a [Smoother] or a [PidFollower], and checkpoints it as
+/// it runs, so it can be seeked back to an earlier time along with the rest of a timeline.
+///
+/// Seeking back restores the last checkpoint at or before that time and steps forward from there,
+/// so the driver's hidden state, such as a [PidFollower]'s built-up error, is what it was back
+/// then rather than whatever it's built up to since. Checkpoints after the seek are dropped, since
+/// the driver may be steered differently from then on. Anything changed on the driver itself,
+/// like its target, is part of its state, so seeking back also rewinds those changes.
+///
+/// Checkpoints are taken every `interval` of time, at most once per [update](Self::update).
+/// Smaller intervals cost more memory but re-simulate less on each seek. The re-simulation is done
+/// in a single step, so seeking lands exactly where running did for frame-rate independent
+/// drivers like a [Smoother], and close to it for the others.
+///
+/// Tweens which are solved in closed form, like a [Spring](crate::Spring), don't need any of this:
+/// their [Tweener](crate::Tweener) can be moved to any time directly.
+///
+/// ```
+/// # use tween::{Rewind, Smoother};
+/// let mut camera = Rewind::new(Smoother::new(0.0f32, 100.0, 1.0), 1.0);
+///
+/// camera.update(1.0);
+/// camera.set_target(0.0);
+/// assert_eq!(camera.update(1.0), 25.0);
+///
+/// // back to before the target changed
+/// assert_eq!(camera.seek(1.0), 50.0);
+/// assert_eq!(camera.target, 100.0);
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rewind<D, Time> {
+    driver: D,
+    time: Time,
+    interval: Time,
+    checkpoints: Vec<(Time, D)>,
+}
+
+impl<D, Time> Rewind<D, Time>
+where
+    D: Step<Time>,
+    Time: TweenTime,
+{
+    /// Creates a new Rewind over `driver`, starting at time zero and taking a checkpoint every
+    /// `interval`.
+    pub fn new(driver: D, interval: Time) -> Self {
+        Self {
+            checkpoints: alloc::vec![(Time::ZERO, driver.clone())],
+            driver,
+            time: Time::ZERO,
+            interval,
+        }
+    }
+
+    /// Steps the driver forward by `delta` of time, and returns its output. A checkpoint is taken
+    /// afterwards if `interval` has passed since the last one.
+    pub fn update(&mut self, delta: Time) -> D::Output {
+        let output = self.driver.step(delta);
+        self.time = self.time.saturating_add(delta);
+
+        let last = self.checkpoints.last().map_or(Time::ZERO, |(time, _)| *time);
+        if self.time - last >= self.interval {
+            self.checkpoints.push((self.time, self.driver.clone()));
+        }
+
+        output
+    }
+
+    /// Moves the driver to `time`, and returns its output there.
+    ///
+    /// Seeking forward steps the driver like [update](Self::update). Seeking back restores the
+    /// driver from the last checkpoint at or before `time`; seeking to before the first
+    /// checkpoint restores that checkpoint as it was.
+    pub fn seek(&mut self, time: Time) -> D::Output {
+        if time >= self.time {
+            return self.update(time - self.time);
+        }
+
+        let kept = self
+            .checkpoints
+            .partition_point(|(checkpoint, _)| *checkpoint <= time)
+            .max(1);
+        self.checkpoints.truncate(kept);
+
+        let (checkpoint, driver) = self.checkpoints[kept - 1].clone();
+        self.driver = driver;
+        self.time = checkpoint;
+        self.update(if time > checkpoint {
+            time - checkpoint
+        } else {
+            Time::ZERO
+        })
+    }
+
+    /// The time the driver has been run to.
+    #[inline]
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// How many checkpoints are held, including the one at the start.
+    #[inline]
+    pub fn checkpoints(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Consumes this, returning the driver.
+    pub fn into_inner(self) -> D {
+        self.driver
+    }
+}
+
+impl<D, Time> core::ops::Deref for Rewind<D, Time> {
+    type Target = D;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.driver
+    }
+}
+
+impl<D, Time> core::ops::DerefMut for Rewind<D, Time> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.driver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PidGains;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn seeking_back_restores_hidden_state() {
+        let gains = PidGains::new(2.0, 1.0, 0.1);
+        let mut follower = Rewind::new(PidFollower::new(0.0f32, 10.0, gains), 0.25);
+
+        let mut recorded = Vec::new();
+        for _ in 0..8 {
+            recorded.push(follower.update(0.25));
+        }
+        let ran = *follower;
+
+        follower.seek(1.0);
+        assert_eq!(follower.time(), 1.0);
+        assert_relative_eq!(follower.value, recorded[3]);
+
+        // running again from there follows the same path, integral and all
+        for _ in 0..4 {
+            follower.update(0.25);
+        }
+        assert_eq!(*follower, ran);
+    }
+
+    #[test]
+    fn seeks_between_checkpoints() {
+        let mut smoother = Rewind::new(Smoother::new(0.0f32, 8.0, 1.0), 2.0);
+        for _ in 0..6 {
+            smoother.update(1.0);
+        }
+        assert_eq!(smoother.checkpoints(), 4);
+
+        assert_relative_eq!(smoother.seek(3.0), 7.0, epsilon = 1e-5);
+        assert_eq!(smoother.checkpoints(), 2);
+
+        // and forward again
+        assert_relative_eq!(smoother.seek(4.0), 7.5, epsilon = 1e-5);
+        assert_eq!(smoother.seek(-1.0), 0.0);
+        assert_eq!(smoother.time(), 0.0);
+    }
+}
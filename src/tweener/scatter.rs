@@ -0,0 +1,187 @@
+use crate::{Tween, TweenRng, TweenTime, TweenValue, Tweener, XorShift64};
+
+/// The amount of randomness [Tweener::scatter] applies to each tweener it creates.
+///
+/// Every field is a *maximum*: each new tweener rolls its own amount between zero and that value.
+/// [Jitter::default] applies no jitter at all.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Jitter<Time> {
+    /// The maximum extra delay before a tweener starts. This is applied by moving its
+    /// `current_time` backwards, so it needs a signed [TweenTime], like `f32` or `i32`.
+    pub delay: Time,
+
+    /// The maximum relative change in duration. `0.1` gives durations between 90% and 110% of the
+    /// template's duration.
+    pub duration: f32,
+
+    /// The maximum phase, as a fraction of the (jittered) duration, that a tweener starts at. This
+    /// is mostly useful for [Looper](crate::Looper) and [Oscillator](crate::Oscillator).
+    pub phase: f32,
+}
+
+impl<Time: TweenTime> Default for Jitter<Time> {
+    fn default() -> Self {
+        Self {
+            delay: Time::ZERO,
+            duration: 0.0,
+            phase: 0.0,
+        }
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Time: TweenTime,
+    Value: TweenValue,
+    T: Tween<Value> + Clone,
+{
+    /// Uses this Tweener as a template, and pairs each of the given `targets` with a copy of it
+    /// which has had some random [Jitter] applied. This is handy for crowds and particles, which
+    /// look mechanical when they all move in lockstep.
+    ///
+    /// The randomness is seeded, so the same seed always produces the same tweeners. This does not
+    /// allocate -- it returns an iterator.
+    ///
+    /// ```
+    /// # #[cfg(feature = "sine")] {
+    /// # use tween::{Jitter, Tweener};
+    /// let template = Tweener::sine_in_out(0.0, 100.0, 1.0);
+    /// let jitter = Jitter {
+    ///     delay: 0.5,
+    ///     duration: 0.1,
+    ///     ..Jitter::default()
+    /// };
+    ///
+    /// for (_particle, tweener) in template.scatter(0..50, jitter, 1234) {
+    ///     assert!(tweener.current_time <= 0.0 && tweener.current_time >= -0.5);
+    ///     assert!(tweener.duration >= 0.9 && tweener.duration <= 1.1);
+    /// }
+    /// # }
+    /// ```
+    pub fn scatter<I>(&self, targets: I, jitter: Jitter<Time>, seed: u64) -> Scatter<'_, Value, Time, T, I::IntoIter>
+    where
+        I: IntoIterator,
+    {
+        self.scatter_with(targets, jitter, XorShift64::new(seed))
+    }
+
+    /// Like [scatter](Self::scatter), but rolls the jitter with the given [TweenRng] instead of
+    /// seeding a new [XorShift64].
+    ///
+    /// ```
+    /// # use tween::{Jitter, Tweener, XorShift64};
+    /// let template = Tweener::linear(0.0, 1.0, 1.0);
+    /// let jitter = Jitter {
+    ///     duration: 0.5,
+    ///     ..Jitter::default()
+    /// };
+    ///
+    /// let a: Vec<_> = template.scatter_with(0..4, jitter, XorShift64::new(3)).collect();
+    /// let b: Vec<_> = template.scatter(0..4, jitter, 3).collect();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn scatter_with<I, R>(
+        &self,
+        targets: I,
+        jitter: Jitter<Time>,
+        rng: R,
+    ) -> Scatter<'_, Value, Time, T, I::IntoIter, R>
+    where
+        I: IntoIterator,
+        R: TweenRng,
+    {
+        Scatter {
+            template: self,
+            targets: targets.into_iter(),
+            jitter,
+            rng,
+        }
+    }
+}
+
+/// An iterator which creates jittered copies of a template [Tweener]. See [Tweener::scatter].
+#[derive(Debug, Clone)]
+pub struct Scatter<'a, Value, Time, T, I, R = XorShift64> {
+    template: &'a Tweener<Value, Time, T>,
+    targets: I,
+    jitter: Jitter<Time>,
+    rng: R,
+}
+
+impl<Value, Time, T, I, R> Iterator for Scatter<'_, Value, Time, T, I, R>
+where
+    R: TweenRng,
+    Time: TweenTime,
+    Value: TweenValue,
+    T: Tween<Value> + Clone,
+    I: Iterator,
+{
+    type Item = (I::Item, Tweener<Value, Time, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let target = self.targets.next()?;
+
+        let mut tweener = self.template.clone();
+
+        let duration_scale = 1.0 + self.jitter.duration * (self.rng.next_f32() * 2.0 - 1.0);
+        tweener.duration = Time::from_f32(tweener.duration.to_f32() * duration_scale);
+
+        let delay = self.jitter.delay.to_f32() * self.rng.next_f32();
+        let phase = tweener.duration.to_f32() * self.jitter.phase * self.rng.next_f32();
+        tweener.current_time = Time::from_f32(tweener.current_time.to_f32() - delay + phase);
+
+        Some((target, tweener))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.targets.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Looper};
+
+    #[test]
+    fn no_jitter_is_identity() {
+        let template = Tweener::new(0.0, 10.0, 2.0, Linear);
+
+        for (_, tweener) in template.scatter(0..10, Jitter::default(), 0) {
+            assert_eq!(tweener, template);
+        }
+    }
+
+    #[test]
+    fn seeded() {
+        let template = Tweener::new(0.0, 10.0, 2.0, Looper::new(Linear));
+        let jitter = Jitter {
+            delay: 1.0,
+            duration: 0.5,
+            phase: 1.0,
+        };
+
+        let a = template.scatter(0..10, jitter, 99);
+        let b = template.scatter(0..10, jitter, 99);
+        assert!(a.zip(b).all(|(a, b)| a == b));
+
+        let mut c = template.scatter(0..2, jitter, 99).map(|(_, t)| t);
+        assert_ne!(c.next(), c.next());
+    }
+
+    #[test]
+    fn bounds() {
+        let template = Tweener::new(0, 100, 100, Linear);
+        let jitter = Jitter {
+            delay: 20,
+            duration: 0.25,
+            phase: 0.0,
+        };
+
+        for (_, tweener) in template.scatter(0..1000, jitter, 5) {
+            assert!((75..=125).contains(&tweener.duration));
+            assert!((-20..=0).contains(&tweener.current_time));
+        }
+    }
+}
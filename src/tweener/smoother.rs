@@ -0,0 +1,117 @@
+use crate::{TweenTime, TweenValue, math::float::exp2};
+
+/// A Smoother is a driver which chases a target value, closing half of the remaining distance
+/// every `half_life`, rather than running a [Tween](crate::Tween) over a fixed duration.
+///
+/// This is the other half of what game code usually needs: where a [Tweener](crate::Tweener) gets
+/// from `start` to `end` in a set time, a Smoother follows a target that can keep moving, like a
+/// camera trailing the player or a health bar catching up to the real health. The target can be
+/// changed at any time with [set_target](Self::set_target), and the motion stays smooth.
+///
+/// The decay is exponential, so it's frame-rate independent: one update of `2.0` lands in the
+/// same place as two updates of `1.0`.
+///
+/// ```
+/// # use tween::Smoother;
+/// let mut camera = Smoother::new(0.0, 100.0, 1.0);
+///
+/// assert_eq!(camera.update(1.0), 50.0);
+/// assert_eq!(camera.update(1.0), 75.0);
+///
+/// camera.set_target(0.0);
+/// assert_eq!(camera.update(1.0), 37.5);
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Smoother<Value, Time> {
+    /// The current value.
+    pub value: Value,
+
+    /// The value being chased.
+    pub target: Value,
+
+    /// How long it takes to close half of the distance to the target. A half-life of zero or less
+    /// snaps straight to the target.
+    pub half_life: Time,
+}
+
+impl<Value, Time> Smoother<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    /// Creates a new Smoother at `value`, chasing `target`.
+    pub fn new(value: Value, target: Value, half_life: Time) -> Self {
+        Self {
+            value,
+            target,
+            half_life,
+        }
+    }
+
+    /// Creates a new Smoother which is resting at `value`.
+    pub fn at_rest(value: Value, half_life: Time) -> Self {
+        Self::new(value.clone(), value, half_life)
+    }
+
+    /// Changes the target, without moving the current value.
+    #[inline]
+    pub fn set_target(&mut self, target: Value) {
+        self.target = target;
+    }
+
+    /// Moves both the value and the target to `value`, so the Smoother rests there.
+    #[inline]
+    pub fn snap_to(&mut self, value: Value) {
+        self.value = value.clone();
+        self.target = value;
+    }
+
+    /// Moves the value towards the target by `delta` of time, and returns the new value.
+    pub fn update(&mut self, delta: Time) -> Value {
+        let half_life = self.half_life.to_f32();
+        let closed = if half_life <= 0.0 {
+            1.0
+        } else {
+            1.0 - exp2(-delta.to_f32() / half_life)
+        };
+
+        self.value = self.value.clone() + (self.target.clone() - self.value.clone()).scale(closed);
+        self.value.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn frame_rate_independent() {
+        let mut coarse = Smoother::new(0.0f32, 10.0, 0.5);
+        let mut fine = coarse;
+
+        coarse.update(1.0);
+        for _ in 0..60 {
+            fine.update(1.0 / 60.0);
+        }
+
+        assert_relative_eq!(coarse.value, 7.5, epsilon = 0.0001);
+        assert_relative_eq!(fine.value, coarse.value, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn zero_half_life_snaps() {
+        let mut smoother = Smoother::new(0.0, 10.0, 0.0);
+        assert_eq!(smoother.update(0.1), 10.0);
+
+        smoother.snap_to(3.0);
+        assert_eq!(smoother.update(1.0), 3.0);
+    }
+
+    #[test]
+    fn at_rest_stays() {
+        let mut smoother = Smoother::at_rest(5, 2);
+        assert_eq!(smoother.update(10), 5);
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// The part of a [SyncGroup]'s clock that a tweener runs over, from `start` to `end`, as percents
+/// of the group's `duration`.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyncWindow {
+    /// The percent of the group's clock at which the tweener starts.
+    pub start: f32,
+
+    /// The percent of the group's clock at which the tweener finishes.
+    pub end: f32,
+}
+
+impl SyncWindow {
+    /// A window over the whole of the group's clock.
+    pub const FULL: Self = Self::new(0.0, 1.0);
+
+    /// Creates a new SyncWindow. `start` should be below `end`.
+    pub const fn new(start: f32, end: f32) -> Self {
+        Self { start, end }
+    }
+
+    /// Maps a percent of the group's clock to a percent of this window, clamped to `0.0..=1.0`.
+    pub fn local_percent(&self, percent: f32) -> f32 {
+        let width = self.end - self.start;
+        if width <= 0.0 {
+            return if percent < self.start { 0.0 } else { 1.0 };
+        }
+
+        ((percent - self.start) / width).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for SyncWindow {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+/// A SyncGroup is a single clock which drives many [Tweener]s, keeping them phase-locked.
+///
+/// Every tweener driven by the group is moved to wherever the group's clock says it should be,
+/// rather than adding up its own deltas. Tweeners of different durations (and even of different
+/// value and time types) can share one group, and each runs over its own [SyncWindow] of the
+/// group's clock, scaled to fit. Since nothing is accumulated per tweener, uneven frame times
+/// can't make them drift apart.
+///
+/// ```
+/// # use tween::{SyncGroup, SyncWindow, Tweener};
+/// let mut group = SyncGroup::new(2.0);
+/// let mut slide = Tweener::linear(0, 100, 100);
+/// let mut fade = Tweener::linear(0.0, 1.0, 0.25);
+///
+/// group.move_by(1.0);
+/// assert_eq!(group.drive(&mut slide, SyncWindow::FULL), 50);
+///
+/// // the fade runs over the second half of the group
+/// assert_eq!(group.drive(&mut fade, SyncWindow::new(0.5, 1.0)), 0.0);
+///
+/// group.move_by(0.5);
+/// assert_eq!(group.drive(&mut slide, SyncWindow::FULL), 75);
+/// assert_eq!(group.drive(&mut fade, SyncWindow::new(0.5, 1.0)), 0.5);
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyncGroup<Time> {
+    /// The current time of the group's clock.
+    pub current_time: Time,
+
+    /// How long the group's clock runs.
+    pub duration: Time,
+}
+
+impl<Time: TweenTime> SyncGroup<Time> {
+    /// Creates a new SyncGroup, with a clock which runs over `duration`.
+    pub fn new(duration: Time) -> Self {
+        Self {
+            current_time: Time::ZERO,
+            duration,
+        }
+    }
+
+    /// Moves the group's clock to `position`, returning its new [percent](Self::percent).
+    #[inline]
+    pub fn move_to(&mut self, position: Time) -> f32 {
+        self.current_time = position;
+        self.percent()
+    }
+
+    /// Moves the group's clock forward by `delta`, returning its new [percent](Self::percent).
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> f32 {
+        self.move_to(self.current_time.saturating_add(delta))
+    }
+
+    /// How far through its `duration` the group's clock is, clamped to `0.0..=1.0`.
+    pub fn percent(&self) -> f32 {
+        let percent = self.current_time.to_f32() / self.duration.to_f32();
+        if percent.is_nan() { 1.0 } else { percent.clamp(0.0, 1.0) }
+    }
+
+    /// Returns `true` once the group's clock has run its `duration`.
+    pub fn is_finished(&self) -> bool {
+        self.current_time >= self.duration
+    }
+
+    /// Moves `tweener` to the place the group's clock puts it, given the `window` of the clock it
+    /// runs over, and returns its value there.
+    ///
+    /// The whole window is mapped onto the whole run of the tweener, which for a tween that
+    /// plays more than once (like a [Repeat](crate::Repeat)) is all of its repeats. An
+    /// infinite tween is mapped onto one `duration`.
+    pub fn drive<Value, T, Tw>(&self, tweener: &mut Tweener<Value, T, Tw>, window: SyncWindow) -> Value
+    where
+        Value: TweenValue,
+        T: TweenTime,
+        Tw: Tween<Value>,
+    {
+        let span = if tweener.tween.is_finite() {
+            tweener.tween.span()
+        } else {
+            1.0
+        };
+        let local = window.local_percent(self.percent());
+
+        tweener.move_to(T::from_f32(local * span * tweener.duration.to_f32()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Repeat};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn stays_locked_under_uneven_frames() {
+        let mut group = SyncGroup::new(1.0f32);
+        let mut short = Tweener::linear(0.0, 1.0, 0.3);
+        let mut long = Tweener::linear(0.0, 1.0, 7.0);
+
+        for dt in [0.013, 0.2, 0.0001, 0.31, 0.07, 0.5] {
+            group.move_by(dt);
+            assert_relative_eq!(
+                group.drive(&mut short, SyncWindow::FULL),
+                group.drive(&mut long, SyncWindow::FULL),
+                epsilon = 1e-6
+            );
+        }
+
+        assert!(group.is_finished());
+        assert!(short.is_finished() && long.is_finished());
+    }
+
+    #[test]
+    fn windows() {
+        let mut group = SyncGroup::new(8);
+        let mut tweener = Tweener::linear(0, 100, 1.0);
+        let window = SyncWindow::new(0.25, 0.75);
+
+        assert_eq!(group.drive(&mut tweener, window), 0);
+        group.move_to(4);
+        assert_eq!(group.drive(&mut tweener, window), 50);
+        group.move_to(7);
+        assert_eq!(group.drive(&mut tweener, window), 100);
+
+        assert_eq!(SyncWindow::new(0.5, 0.5).local_percent(0.5), 1.0);
+    }
+
+    #[test]
+    fn repeats_fit_the_window() {
+        let mut group = SyncGroup::new(4);
+        let mut tweener = Tweener::new(0, 10, 10, Repeat::new(Linear, 2));
+
+        group.move_to(1);
+        assert_eq!(group.drive(&mut tweener, SyncWindow::FULL), 5);
+        group.move_to(3);
+        assert_eq!(group.drive(&mut tweener, SyncWindow::FULL), 5);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// A [Threshold] is a wrapper around a [Tween] which ignores changes smaller than `epsilon`,
+/// returning the last value it gave out instead.
+///
+/// This is a dead-zone for tweened values which drive expensive downstream systems, like a layout
+/// pass or a network sync: if you only act when the value actually changes, tiny steps at the
+/// slow ends of an easing no longer cost you anything.
+///
+/// The end of the tween is always let through, so a Threshold never gets stuck short of its final
+/// value.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Threshold<Value, T: ?Sized> {
+    /// The smallest change which will be let through.
+    pub epsilon: Value,
+
+    last: Option<Value>,
+
+    /// The underlying Tween.
+    pub tween: T,
+}
+
+impl<Value, T> Threshold<Value, T> {
+    /// Creates a new Threshold around a [Tween].
+    pub fn new(tween: T, epsilon: Value) -> Self {
+        Self {
+            epsilon,
+            last: None,
+            tween,
+        }
+    }
+
+    /// Forgets the last value, so the next one is let through no matter what.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+impl<Value, T> Tween<Value> for Threshold<Value, T>
+where
+    Value: TweenValue + PartialOrd,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let value = self.tween.tween(value_delta, percent);
+
+        let at_end = self.tween.is_finite() && percent >= self.tween.span();
+
+        if let Some(last) = self.last.as_ref().filter(|_| !at_end) {
+            // we do this instead of subtracting once so unsigned values can't underflow
+            let change = if value > *last {
+                value.clone() - last.clone()
+            } else {
+                last.clone() - value.clone()
+            };
+            if change < self.epsilon {
+                return last.clone();
+            }
+        }
+
+        self.last = Some(value.clone());
+        value
+    }
+
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.tween.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.tween.returns_to_start()
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Value: TweenValue + PartialOrd,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Ignores changes in this Tweener's output smaller than `epsilon`. See [Threshold].
+    ///
+    /// ```
+    /// # use tween::Tweener;
+    /// let mut tweener = Tweener::linear(0.0f32, 10.0, 100.0).threshold(1.0);
+    ///
+    /// assert_eq!(tweener.move_to(0.0), 0.0);
+    /// assert_eq!(tweener.move_to(5.0), 0.0); // only moved by 0.5
+    /// assert_eq!(tweener.move_to(10.0), 1.0);
+    /// ```
+    pub fn threshold(self, epsilon: Value) -> Tweener<Value, Time, Threshold<Value, T>> {
+        self.map(|tween| Threshold::new(tween, epsilon.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear};
+
+    #[test]
+    fn suppresses_small_changes() {
+        let mut tweener = Tweener::new(0.0f32, 100.0, 100.0, CubicBezier::EASE_IN_OUT).threshold(5.0);
+
+        let mut last = tweener.move_to(0.0);
+        for time in 1..100 {
+            let value = tweener.move_to(time as f32);
+            if value != last {
+                assert!(value - last >= 5.0);
+                last = value;
+            }
+        }
+
+        assert_eq!(tweener.move_to(100.0), 100.0);
+    }
+
+    #[test]
+    fn unsigned_backwards() {
+        let mut threshold = Threshold::new(Linear, 3u32);
+
+        assert_eq!(threshold.tween(10, 1.0), 10);
+        assert_eq!(threshold.tween(10, 0.8), 10);
+        assert_eq!(threshold.tween(10, 0.5), 5);
+
+        threshold.reset();
+        assert_eq!(threshold.tween(10, 0.6), 6);
+    }
+}
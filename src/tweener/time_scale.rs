@@ -0,0 +1,97 @@
+use crate::{Tween, TweenValue};
+
+/// A [TimeScale] is a wrapper around a [Tween] which speeds it up or slows it down by a factor.
+///
+/// A `factor` of `2.0` plays the inner tween twice as fast, so a [Tweener](crate::Tweener) will
+/// finish it in half of its `duration`; a `factor` of `0.5` takes twice the `duration`. The factor
+/// should be positive.
+///
+/// ```
+/// # use tween::{Linear, TimeScale, Tweener};
+/// let mut tweener = Tweener::new(0, 10, 10, TimeScale::new(Linear, 2.0));
+///
+/// assert_eq!(tweener.move_to(2), 4);
+/// assert_eq!(tweener.move_to(5), 10);
+/// assert!(tweener.is_finished());
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeScale<T: ?Sized> {
+    /// How much faster than normal the inner tween plays.
+    pub factor: f32,
+
+    /// The underlying Tween.
+    pub tween: T,
+}
+
+impl<T> TimeScale<T> {
+    /// Creates a new TimeScale around a [Tween].
+    pub fn new(tween: T, factor: f32) -> Self {
+        Self { factor, tween }
+    }
+}
+
+impl<Value, T> Tween<Value> for TimeScale<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween.tween(value_delta, percent * self.factor)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.tween.span() / self.factor
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.tween.returns_to_start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Linear, Looper, Mirror, PingPong, Reverse, StepPosition, Steps, Tweener};
+
+    #[test]
+    fn slow_down() {
+        let fixed = FixedTweener::new(0, 4, 4, TimeScale::new(Linear, 0.5), 1);
+
+        assert_eq!(fixed.remaining_steps(), Some(8));
+        assert!(fixed.eq([0, 1, 1, 2, 2, 3, 3, 4]));
+    }
+
+    #[test]
+    fn infinite_inner() {
+        let mut tweener = Tweener::new(0, 2, 2, TimeScale::new(Looper::new(Linear), 2.0));
+
+        assert_eq!(tweener.move_to(1), 2);
+        assert_eq!(tweener.move_to(3), 2);
+        assert!(!tweener.is_finished());
+    }
+
+    #[test]
+    fn composes() {
+        // all of these wrappers nest, and a PingPong still ends where it started
+        let mut tweener = Tweener::new(
+            0,
+            100,
+            10,
+            TimeScale::new(
+                PingPong::new(Reverse::new(Mirror::new(Steps::new(4, StepPosition::JumpEnd))), 1),
+                4.0,
+            ),
+        );
+
+        assert_eq!(tweener.move_to(0), 0);
+        assert!(!tweener.is_finished());
+        assert_eq!(tweener.move_to(5), 0);
+        assert!(tweener.is_finished());
+    }
+}
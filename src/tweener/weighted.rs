@@ -0,0 +1,224 @@
+use crate::{Blend, Easing, Morph, Tween, TweenValue};
+
+/// A [Weighted] tween scales the output of an inner tween by a fixed `weight`.
+///
+/// You will normally make this by multiplying a tween by a number, like `0.7 * QuadOut`. On its
+/// own, a Weighted tween doesn't reach the end of its range; it's meant to be summed with others
+/// into a [WeightedSum] whose weights add up to `1.0`:
+///
+/// ```
+/// # #[cfg(all(feature = "sine", feature = "quad"))] {
+/// # use tween::{QuadOut, SineInOut, Tweener};
+/// let curve = 0.75 * QuadOut + 0.25 * SineInOut;
+/// let mut tweener = Tweener::new(0.0, 100.0, 10.0, curve);
+///
+/// assert_eq!(tweener.move_to(0.0), 0.0);
+/// assert_eq!(tweener.move_to(10.0), 100.0);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Weighted<T> {
+    /// How much the output of `tween` is scaled by.
+    pub weight: f32,
+
+    /// The underlying Tween.
+    pub tween: T,
+}
+
+impl<T> Weighted<T> {
+    /// Creates a new Weighted tween around a [Tween].
+    pub fn new(tween: T, weight: f32) -> Self {
+        Self { weight, tween }
+    }
+}
+
+impl<Value, T> Tween<Value> for Weighted<T>
+where
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween.tween(value_delta, percent).scale(self.weight)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.tween.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.tween.returns_to_start()
+    }
+}
+
+/// A [WeightedSum] adds the outputs of two tweens together.
+///
+/// You will normally make this by adding tweens, like `0.7 * QuadOut + 0.3 * SineInOut`. Unlike a
+/// [Blend], the weights aren't tied together, so it's up to you to keep them adding up to `1.0` if
+/// the sum should still land on the end of its range. Summing more than two tweens nests
+/// WeightedSums.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedSum<A, B> {
+    /// The first Tween.
+    pub a: A,
+
+    /// The second Tween.
+    pub b: B,
+}
+
+impl<A, B> WeightedSum<A, B> {
+    /// Creates a new WeightedSum of two [Tween]s.
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<Value, A, B> Tween<Value> for WeightedSum<A, B>
+where
+    Value: TweenValue,
+    A: Tween<Value>,
+    B: Tween<Value>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.a.tween(value_delta.clone(), percent) + self.b.tween(value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.a.is_finite() && self.b.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        Tween::<Value>::span(&self.a).max(Tween::<Value>::span(&self.b))
+    }
+
+    fn returns_to_start(&self) -> bool {
+        Tween::<Value>::returns_to_start(&self.a) && Tween::<Value>::returns_to_start(&self.b)
+    }
+}
+
+/// A [Compose] tween runs the percent through an `inner` tween before handing it to the `outer`
+/// one, so the result is `outer(inner(percent))`.
+///
+/// You will normally make this by multiplying two tweens, like `QuadIn * SineOut`, which reads
+/// like function composition: `SineOut` reshapes time, and `QuadIn` eases over the reshaped time.
+///
+/// ```
+/// # #[cfg(feature = "quad")] {
+/// # use tween::{Linear, QuadIn, Tween};
+/// let mut squared = QuadIn * QuadIn;
+///
+/// // (0.5²)² is 0.0625
+/// assert_eq!(squared.tween(1.0f32, 0.5), 0.0625);
+/// assert_eq!((Linear * QuadIn).tween(1.0f32, 0.5), 0.25);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Compose<Outer, Inner> {
+    /// The Tween which produces the output.
+    pub outer: Outer,
+
+    /// The Tween which reshapes the percent.
+    pub inner: Inner,
+}
+
+impl<Outer, Inner> Compose<Outer, Inner> {
+    /// Creates a new Compose tween, running `outer` over the output of `inner`.
+    pub fn new(outer: Outer, inner: Inner) -> Self {
+        Self { outer, inner }
+    }
+}
+
+impl<Value, Outer, Inner> Tween<Value> for Compose<Outer, Inner>
+where
+    Value: TweenValue,
+    Outer: Tween<Value>,
+    Inner: Tween<f32>,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = self.inner.tween(1.0, percent);
+        self.outer.tween(value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.inner.is_finite()
+    }
+
+    fn span(&self) -> f32 {
+        self.inner.span()
+    }
+
+    fn returns_to_start(&self) -> bool {
+        self.inner.returns_to_start()
+    }
+}
+
+impl_tween_ops!(Weighted<T>);
+impl_tween_ops!(WeightedSum<A, B>);
+impl_tween_ops!(Compose<Outer, Inner>);
+impl_tween_ops!(Blend<A, B>);
+impl_tween_ops!(Morph<A, B, W>);
+impl_tween_ops!(Easing);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn sum_matches_blend() {
+        let mut sum = 0.7 * CubicBezier::EASE_OUT + 0.3 * CubicBezier::EASE_IN_OUT;
+        let mut blend = Blend::new(CubicBezier::EASE_OUT, CubicBezier::EASE_IN_OUT, 0.3);
+
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+            assert_relative_eq!(sum.tween(10.0f32, pct), blend.tween(10.0f32, pct), epsilon = 1e-5);
+        }
+    }
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn sums_nest() {
+        use crate::{QuadIn, Tweener};
+
+        let mut three = 0.5 * Linear + 0.25 * QuadIn + 0.25 * Easing::QuadOut;
+        let mut tweener = Tweener::new(0.0f32, 1.0, 1.0, three);
+
+        assert_relative_eq!(tweener.move_to(0.5), 0.5 * 0.5 + 0.25 * 0.25 + 0.25 * 0.75);
+        assert_eq!(three.tween(1.0f32, 1.0), 1.0);
+    }
+
+    #[test]
+    fn only_tweens_add() {
+        static_assertions::assert_impl_all!(Linear: core::ops::Add<CubicBezier>);
+        static_assertions::assert_not_impl_any!(Linear: core::ops::Add<i32>, core::ops::Add<&'static str>);
+    }
+
+    #[test]
+    fn compose_reshapes_time() {
+        let mut ease = CubicBezier::EASE;
+        let mut composed = Linear * ease;
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+            assert_eq!(composed.tween(1.0f32, pct), ease.tween(1.0f32, pct));
+        }
+    }
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn compose_with_a_blend() {
+        use crate::QuadIn;
+
+        let mut blended = Blend::new(Linear, QuadIn, 1.0) * QuadIn;
+        assert_relative_eq!(blended.tween(1.0f32, 0.5), 0.0625);
+    }
+}
@@ -1,32 +1,86 @@
 mod linear;
 pub use linear::Linear;
 
+#[cfg(feature = "cubic")]
 mod cubic;
+#[cfg(feature = "cubic")]
 pub use cubic::{CubicIn, CubicInOut, CubicOut};
 
+mod cubic_bezier;
+pub use cubic_bezier::CubicBezier;
+
+#[cfg(feature = "sine")]
 mod sine;
+#[cfg(feature = "sine")]
 pub use sine::{SineIn, SineInOut, SineOut};
 
+#[cfg(feature = "quint")]
 mod quint;
+#[cfg(feature = "quint")]
 pub use quint::{QuintIn, QuintInOut, QuintOut};
 
+#[cfg(feature = "quad")]
 mod quad;
+#[cfg(feature = "quad")]
 pub use quad::{QuadIn, QuadInOut, QuadOut};
 
+#[cfg(feature = "quart")]
 mod quart;
+#[cfg(feature = "quart")]
 pub use quart::{QuartIn, QuartInOut, QuartOut};
 
+#[cfg(feature = "expo")]
 mod expo;
+#[cfg(feature = "expo")]
 pub use expo::{ExpoIn, ExpoInOut, ExpoOut};
 
+#[cfg(feature = "circ")]
 mod circ;
+#[cfg(feature = "circ")]
 pub use circ::{CircIn, CircInOut, CircOut};
 
+mod ease_mode;
+pub use ease_mode::EaseMode;
+
+#[cfg(feature = "back")]
 mod back;
-pub use back::{BackIn, BackInOut, BackOut};
+#[cfg(feature = "back")]
+pub use back::{Back, BackIn, BackInOut, BackOut};
 
+#[cfg(feature = "elastic")]
 mod elastic;
-pub use elastic::{ElasticIn, ElasticInOut, ElasticOut};
+#[cfg(feature = "elastic")]
+pub use elastic::{Elastic, ElasticIn, ElasticInOut, ElasticOut};
 
+#[cfg(feature = "bounce")]
 mod bounce;
-pub use bounce::{BounceIn, BounceInOut, BounceOut};
+#[cfg(feature = "bounce")]
+pub use bounce::{Bounce, BounceIn, BounceInOut, BounceOut};
+
+mod steps;
+pub use steps::{StepPosition, Steps};
+
+mod spring;
+pub use spring::Spring;
+
+mod fade;
+pub use fade::{FadeCurve, FadeIn, FadeOut};
+
+mod baked;
+#[cfg(feature = "alloc")]
+pub use baked::AdaptiveBaked;
+pub use baked::Baked;
+
+mod spline;
+pub use spline::Spline;
+
+mod trapezoid;
+pub use trapezoid::TrapezoidProfile;
+
+mod s_curve;
+pub use s_curve::SCurveProfile;
+
+mod piecewise;
+#[cfg(feature = "alloc")]
+pub use piecewise::PiecewiseVec;
+pub use piecewise::{Piecewise, PiecewiseArray};
@@ -1,3 +1,5 @@
+use super::EaseMode;
+
 /// This appears to be a magic constant for Back eases. I have no idea
 /// where it's from, but we'll use it
 const BACK_CONST: f32 = 1.70158;
@@ -67,3 +69,134 @@ declare_tween! {
 }
 
 test_tween!(Back);
+
+/// A [Back] tween with its overshoot tuned, instead of using the easings.net defaults of
+/// [BackIn], [BackOut], and [BackInOut].
+///
+/// The `overshoot` is Robert Penner's `s` constant, which defaults to `1.70158` for a 10%
+/// overshoot. Larger values pull further back; `0.0` removes the overshoot entirely. Like
+/// [BackInOut], [EaseMode::InOut] scales the overshoot by `1.525` so each half pulls back about as
+/// far as the single-ended modes do.
+///
+/// ```
+/// # use tween::{BackOut, Tweener};
+/// let mut gentle = Tweener::new(0.0, 100.0, 10.0, BackOut::with_overshoot(0.5));
+/// let mut classic = Tweener::back_out(0.0, 100.0, 10.0);
+///
+/// assert!(gentle.move_to(7.0) < classic.move_to(7.0));
+/// assert_eq!(gentle.move_to(10.0), 100.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Back {
+    /// Which end(s) of the tween overshoot.
+    pub mode: EaseMode,
+
+    /// How far the tween pulls back, as Penner's `s` constant.
+    pub overshoot: f32,
+}
+
+impl Back {
+    /// The overshoot used by [BackIn], [BackOut], and [BackInOut].
+    pub const DEFAULT_OVERSHOOT: f32 = BACK_CONST;
+
+    /// Creates a new Back tween with the given overshoot.
+    pub fn new(mode: EaseMode, overshoot: f32) -> Self {
+        Self { mode, overshoot }
+    }
+}
+
+impl BackIn {
+    /// Creates a [Back] tween like this one, but with a custom `overshoot`.
+    pub fn with_overshoot(overshoot: f32) -> Back {
+        Back::new(EaseMode::In, overshoot)
+    }
+}
+
+impl BackOut {
+    /// Creates a [Back] tween like this one, but with a custom `overshoot`.
+    pub fn with_overshoot(overshoot: f32) -> Back {
+        Back::new(EaseMode::Out, overshoot)
+    }
+}
+
+impl BackInOut {
+    /// Creates a [Back] tween like this one, but with a custom `overshoot`. This is scaled by
+    /// `1.525` just like the default is.
+    pub fn with_overshoot(overshoot: f32) -> Back {
+        Back::new(EaseMode::InOut, overshoot)
+    }
+}
+
+impl_tween_ops!(Back);
+
+impl<Value: crate::TweenValue> crate::Tween<Value> for Back {
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let s = match self.mode {
+            EaseMode::InOut => self.overshoot * 1.525,
+            _ => self.overshoot,
+        };
+
+        let scalar = self.mode.apply(percent, |t| t * t * ((s + 1.0) * t - s));
+
+        value_delta.scale(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tuned_tests {
+    use super::*;
+    use crate::Tween;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn defaults_match_fixed() {
+        for i in 0..=20 {
+            let percent = i as f32 / 20.0;
+            let s = Back::DEFAULT_OVERSHOOT;
+
+            assert_relative_eq!(
+                BackIn::with_overshoot(s).tween(10.0, percent),
+                BackIn.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(
+                BackOut::with_overshoot(s).tween(10.0, percent),
+                BackOut.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(
+                BackInOut::with_overshoot(s).tween(10.0, percent),
+                BackInOut.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+        }
+    }
+
+    #[test]
+    fn same_curve_in_any_time_unit() {
+        use crate::Tweener;
+
+        // a tenth of a second, at 50 frames per second
+        let mut frames = Tweener::new(0.0f32, 1.0, 5i32, BackOut);
+        let mut seconds = Tweener::new(0.0f32, 1.0, 0.1f64, BackOut);
+
+        for frame in 0..=5 {
+            let seconds = seconds.move_to(frame as f64 / 50.0);
+
+            assert_relative_eq!(frames.move_to(frame), seconds, epsilon = 1e-5);
+            assert_relative_eq!(seconds, BackOut.tween(1.0f32, frame as f32 / 5.0), epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn no_overshoot() {
+        let mut back = BackIn::with_overshoot(0.0);
+
+        for i in 0..=20 {
+            let value: f32 = back.tween(1.0, i as f32 / 20.0);
+            assert!(value >= 0.0);
+        }
+    }
+}
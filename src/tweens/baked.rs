@@ -0,0 +1,332 @@
+use crate::Tween;
+
+/// A tween which samples another tween into an `N` entry lookup table once, and afterwards answers
+/// with a lerp between the two nearest entries.
+///
+/// This trades a little accuracy for speed: evaluating [ElasticOut](crate::ElasticOut) or
+/// [BounceOut](crate::BounceOut) for tens of thousands of particles every frame adds up, but a
+/// table lookup costs the same no matter what the curve is. More entries follow the curve more
+/// closely; `64` or so is plenty for most easings.
+///
+/// The table holds the *shape* of the inner tween, sampled with `f32`s across `0.0..=1.0`, so a
+/// Baked tween can then be used with any [TweenValue](crate::TweenValue). This assumes the inner
+/// tween is an easing, which scales `value_delta` by some amount, as every easing in this library
+/// does. Percents outside of `0.0..=1.0` are clamped to the ends of the table.
+///
+/// ```
+/// # #[cfg(feature = "bounce")] {
+/// # use tween::{Baked, BounceOut, Tweener};
+/// let mut baked = Tweener::new(0.0f32, 100.0, 10.0, Baked::<64>::new(BounceOut));
+/// let mut exact = Tweener::bounce_out(0.0f32, 100.0, 10.0);
+///
+/// assert_eq!(baked.move_to(0.0), 0.0);
+/// assert!((baked.move_to(5.0) - exact.move_to(5.0)).abs() < 1.0);
+/// assert_eq!(baked.move_to(10.0), 100.0);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct Baked<const N: usize> {
+    table: [f32; N],
+}
+
+impl<const N: usize> Baked<N> {
+    /// Bakes a tween into a new lookup table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than `2`, since a table needs both of its ends.
+    pub fn new<T: Tween<f32>>(mut tween: T) -> Self {
+        assert!(N >= 2, "a baked tween needs at least 2 entries");
+
+        let last = (N - 1) as f32;
+        let table = core::array::from_fn(|i| tween.tween(1.0, i as f32 / last));
+
+        Self { table }
+    }
+
+    /// Builds a lookup table out of observed data, which warps progress so that it moves at a
+    /// steady pace. This is histogram equalization, applied to progress bars.
+    ///
+    /// Real progress rarely moves evenly: a loader might fly through small files and then sit on
+    /// one big one. To smooth that out, record the real progress at steady intervals over some
+    /// typical runs, and pass all of those readings in as `samples`. The resulting easing maps real
+    /// progress to the fraction of the time which usually passes before it, so a bar driven by it
+    /// fills at an even rate, while still starting at `0.0`, ending at `1.0`, and never moving
+    /// backwards. Samples outside of `0.0..=1.0` are clamped.
+    ///
+    /// ```
+    /// # use tween::{Baked, Tweener};
+    /// // a loader which usually gets halfway in the first fifth of the time
+    /// let samples = [0.0, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.0];
+    /// let mut bar = Tweener::new(0.0f32, 100.0, 1.0, Baked::<33>::equalized(&samples));
+    ///
+    /// // so halfway through the load only shows a small bit of progress
+    /// assert!(bar.move_to(0.5) < 25.0);
+    /// assert_eq!(bar.move_to(1.0), 100.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is empty, or if `N` is less than `2`.
+    pub fn equalized(samples: &[f32]) -> Self {
+        assert!(N >= 2, "a baked tween needs at least 2 entries");
+        assert!(!samples.is_empty(), "equalizing needs at least one sample");
+
+        let last = (N - 1) as f32;
+        let total = samples.len() as f32;
+        let mut table: [f32; N] = core::array::from_fn(|i| {
+            let percent = i as f32 / last;
+            let below = samples
+                .iter()
+                .filter(|&&sample| sample.clamp(0.0, 1.0) < percent)
+                .count();
+
+            below as f32 / total
+        });
+        table[N - 1] = 1.0;
+
+        Self { table }
+    }
+
+    /// The sampled table, where entry `i` is the inner tween at `i / (N - 1)`.
+    pub fn table(&self) -> &[f32; N] {
+        &self.table
+    }
+
+    /// Looks `percent` up in the table.
+    #[inline(always)]
+    fn lookup(&self, percent: f32) -> f32 {
+        let position = percent.clamp(0.0, 1.0) * (N - 1) as f32;
+
+        // `position` is never negative, so truncating is a floor
+        let index = (position as usize).min(N - 2);
+        let fraction = position - index as f32;
+
+        let (a, b) = (self.table[index], self.table[index + 1]);
+        a + (b - a) * fraction
+    }
+}
+
+impl<Value: crate::TweenValue, const N: usize> Tween<Value> for Baked<N> {
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.lookup(percent))
+    }
+}
+
+/// A lookup table like [Baked], which places its entries where the inner tween needs them, rather
+/// than evenly.
+///
+/// The table starts with just its ends, and keeps splitting any segment whose straight line strays
+/// more than `max_error` from the inner tween, so wiggly parts of a curve, like the early bounces
+/// of an [ElasticOut](crate::ElasticOut), get many entries, and flat or straight parts get
+/// hardly any. That meets the same accuracy as an even table with far fewer entries. Each segment
+/// is checked at a few points, so a feature much narrower than its neighbours can slip through.
+///
+/// Like [Baked], it holds the shape of the inner tween, and clamps percents outside of
+/// `0.0..=1.0`. Lookups are a binary search, rather than an index, so they cost a little more.
+///
+/// ```
+/// # #[cfg(feature = "elastic")] {
+/// # use tween::{AdaptiveBaked, ElasticOut, Tween};
+/// let mut baked = AdaptiveBaked::new(ElasticOut, 0.001);
+///
+/// for i in 0..=100 {
+///     let percent = i as f32 / 100.0;
+///     let exact: f32 = ElasticOut.tween(1.0, percent);
+///     assert!((baked.tween(1.0f32, percent) - exact).abs() < 0.002);
+/// }
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdaptiveBaked {
+    percents: alloc::vec::Vec<f32>,
+    values: alloc::vec::Vec<f32>,
+}
+
+#[cfg(feature = "alloc")]
+impl AdaptiveBaked {
+    /// Segments narrower than this aren't split any further, which keeps jumps, like those of
+    /// [Steps](crate::Steps), from being refined forever.
+    const MIN_WIDTH: f32 = 1.0 / 65536.0;
+
+    /// Bakes a tween into a new lookup table, which strays no more than about `max_error` from it.
+    pub fn new<T: Tween<f32>>(mut tween: T, max_error: f32) -> Self {
+        let mut baked = Self {
+            percents: alloc::vec![0.0],
+            values: alloc::vec![tween.tween(1.0, 0.0)],
+        };
+
+        let end = (1.0, tween.tween(1.0, 1.0));
+        baked.refine(&mut tween, (0.0, baked.values[0]), end, max_error);
+        baked.percents.push(end.0);
+        baked.values.push(end.1);
+
+        baked
+    }
+
+    /// How many entries the table ended up with.
+    pub fn len(&self) -> usize {
+        self.percents.len()
+    }
+
+    /// Always returns `false`, since a table has at least its two ends.
+    pub fn is_empty(&self) -> bool {
+        self.percents.is_empty()
+    }
+
+    /// The entries of the table, as each one's percent and the inner tween's value there.
+    pub fn entries(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.percents.iter().copied().zip(self.values.iter().copied())
+    }
+
+    /// Adds the entries strictly between `from` and `to`, in order.
+    fn refine<T: Tween<f32>>(&mut self, tween: &mut T, from: (f32, f32), to: (f32, f32), max_error: f32) {
+        let width = to.0 - from.0;
+        if width <= Self::MIN_WIDTH {
+            return;
+        }
+
+        let strays = [0.25, 0.5, 0.75].into_iter().any(|fraction| {
+            let percent = from.0 + width * fraction;
+            let line = from.1 + (to.1 - from.1) * fraction;
+
+            (tween.tween(1.0, percent) - line).abs() > max_error
+        });
+        if !strays {
+            return;
+        }
+
+        let middle = from.0 + width * 0.5;
+        let middle = (middle, tween.tween(1.0, middle));
+
+        self.refine(tween, from, middle, max_error);
+        self.percents.push(middle.0);
+        self.values.push(middle.1);
+        self.refine(tween, middle, to, max_error);
+    }
+
+    /// Looks `percent` up in the table.
+    #[inline(always)]
+    fn lookup(&self, percent: f32) -> f32 {
+        let percent = percent.clamp(0.0, 1.0);
+        let next = self
+            .percents
+            .partition_point(|&entry| entry <= percent)
+            .clamp(1, self.percents.len() - 1);
+
+        let (p0, p1) = (self.percents[next - 1], self.percents[next]);
+        let (a, b) = (self.values[next - 1], self.values[next]);
+
+        a + (b - a) * ((percent - p0) / (p1 - p0))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Value: crate::TweenValue> Tween<Value> for AdaptiveBaked {
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.lookup(percent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn exact_on_entries() {
+        let mut ease = CubicBezier::EASE_OUT;
+        let mut baked = Baked::<11>::new(ease);
+
+        for i in 0..=10 {
+            let percent = i as f32 / 10.0;
+            assert_eq!(baked.tween(1.0f32, percent), ease.tween(1.0f32, percent));
+        }
+    }
+
+    #[test]
+    fn close_between_entries() {
+        let mut ease = CubicBezier::EASE_IN_OUT;
+        let mut baked = Baked::<128>::new(ease);
+
+        for i in 0..=1000 {
+            let percent = i as f32 / 1000.0;
+            assert_relative_eq!(
+                baked.tween(1.0f32, percent),
+                ease.tween(1.0f32, percent),
+                epsilon = 0.0001
+            );
+        }
+    }
+
+    #[test]
+    fn equalizes_a_distribution() {
+        // this loader runs along `time²`, so it crawls at first, and equalizing it should give
+        // `sqrt(progress)`
+        let samples: [f32; 1001] = core::array::from_fn(|i| {
+            let time = i as f32 / 1000.0;
+            time * time
+        });
+        let mut baked = Baked::<65>::equalized(&samples);
+
+        for i in 0..=16 {
+            let percent = i as f32 / 16.0;
+            let shown: f32 = baked.tween(1.0, percent);
+            assert_relative_eq!(shown * shown, percent, epsilon = 0.01);
+        }
+    }
+
+    #[test]
+    fn clamps_and_scales() {
+        let mut baked = Baked::<2>::new(Linear);
+
+        assert_eq!(baked.tween(10, 0.5), 5);
+        assert_eq!(baked.tween(10.0, -1.0), 0.0);
+        assert_eq!(baked.tween(10.0, 2.0), 10.0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "elastic"))]
+    fn adaptive_meets_its_error() {
+        use crate::ElasticOut;
+
+        for max_error in [0.01, 0.001, 0.0001] {
+            let mut baked = AdaptiveBaked::new(ElasticOut, max_error);
+
+            for i in 0..=10000 {
+                let percent = i as f32 / 10000.0;
+                let exact: f32 = ElasticOut.tween(1.0, percent);
+                assert!((baked.tween(1.0f32, percent) - exact).abs() <= max_error * 1.5);
+            }
+        }
+
+        assert_eq!(baked_ends(AdaptiveBaked::new(ElasticOut, 0.01)), (0.0, 1.0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "elastic"))]
+    fn adaptive_spends_entries_on_wiggles() {
+        use crate::ElasticOut;
+
+        let baked = AdaptiveBaked::new(ElasticOut, 0.001);
+        let early = baked.entries().filter(|&(percent, _)| percent < 0.25).count();
+        let late = baked.entries().filter(|&(percent, _)| percent > 0.75).count();
+        assert!(early > 2 * late, "{early} early and {late} late");
+
+        // an even table needs a few hundred entries for the same error
+        assert!(baked.len() < 200, "{} entries", baked.len());
+
+        // straight lines need nothing but their ends
+        assert_eq!(AdaptiveBaked::new(Linear, 0.0001).len(), 2);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "elastic"))]
+    fn baked_ends(mut baked: AdaptiveBaked) -> (f32, f32) {
+        (baked.tween(1.0, -1.0), baked.tween(1.0, 2.0))
+    }
+}
@@ -1,3 +1,5 @@
+use super::EaseMode;
+
 const MAGIC: f32 = 7.5625;
 const STAGE_ZERO: f32 = 1.0 / 2.75;
 const STAGE_ONE: f32 = 2.0 / 2.75;
@@ -31,7 +33,7 @@ declare_tween!(
                 MAGIC * t * t + 0.984375
             };
 
-            value_delta.scale(multip)
+            value_delta.clone().scale(multip)
         };
 
         value_delta - v
@@ -97,7 +99,7 @@ declare_tween!(
                 MAGIC * t * t + 0.984375
             };
 
-            (value_delta - value_delta.scale(multip)).scale(0.5)
+            (value_delta.clone() - value_delta.scale(multip)).scale(0.5)
         } else {
             percent = (percent - 0.5) * 2.0;
 
@@ -115,10 +117,156 @@ declare_tween!(
                 MAGIC * t * t + 0.984375
             };
 
-            value_delta.scale(multip).scale(0.5) + value_delta.scale(0.5)
+            value_delta.clone().scale(multip).scale(0.5) + value_delta.scale(0.5)
         }
     }
 
 );
 
 test_tween!(Bounce);
+
+/// A [Bounce] tween with its bounces tuned, instead of using the easings.net defaults of
+/// [BounceIn], [BounceOut], and [BounceInOut].
+///
+/// The tween falls once and then bounces `bounces` times. Each bounce lasts `decay` times as long
+/// as the one before it, and so reaches `decay * decay` times as high, like a ball with a
+/// coefficient of restitution of `decay`. The defaults are `3` bounces with a `decay` of `0.5`.
+///
+/// ```
+/// # use tween::{BounceOut, Tweener};
+/// let mut ball = Tweener::new(0.0, 100.0, 10.0, BounceOut::with_bounces(5, 0.6));
+///
+/// assert_eq!(ball.move_to(0.0), 0.0);
+/// assert_eq!(ball.move_to(10.0), 100.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounce {
+    /// Which end(s) of the tween bounce.
+    pub mode: EaseMode,
+
+    /// How many times the tween bounces after its first fall.
+    pub bounces: u32,
+
+    /// How long each bounce lasts compared to the one before it, within `0.0..1.0`.
+    pub decay: f32,
+}
+
+impl Bounce {
+    /// The number of bounces in [BounceIn], [BounceOut], and [BounceInOut].
+    pub const DEFAULT_BOUNCES: u32 = 3;
+
+    /// The decay used by [BounceIn], [BounceOut], and [BounceInOut].
+    pub const DEFAULT_DECAY: f32 = 0.5;
+
+    /// Creates a new Bounce tween with the given number of bounces and decay.
+    pub fn new(mode: EaseMode, bounces: u32, decay: f32) -> Self {
+        Self { mode, bounces, decay }
+    }
+
+    /// The `Out` form of the curve, which the other modes mirror.
+    fn bounce_out(&self, percent: f32) -> f32 {
+        // lay the fall and bounces out along a timeline where the fall takes `1.0`
+        let mut total = 1.0;
+        let mut width = 1.0;
+        for _ in 0..self.bounces {
+            width *= self.decay;
+            total += width * 2.0;
+        }
+
+        let mut t = percent * total;
+        if t < 1.0 {
+            return t * t;
+        }
+        t -= 1.0;
+
+        let mut width = 1.0;
+        for _ in 0..self.bounces {
+            width *= self.decay;
+            if t < width * 2.0 {
+                let from_peak = t - width;
+                return 1.0 - width * width + from_peak * from_peak;
+            }
+            t -= width * 2.0;
+        }
+
+        1.0
+    }
+}
+
+impl BounceIn {
+    /// Creates a [Bounce] tween like this one, but with a custom number of `bounces` and `decay`.
+    pub fn with_bounces(bounces: u32, decay: f32) -> Bounce {
+        Bounce::new(EaseMode::In, bounces, decay)
+    }
+}
+
+impl BounceOut {
+    /// Creates a [Bounce] tween like this one, but with a custom number of `bounces` and `decay`.
+    pub fn with_bounces(bounces: u32, decay: f32) -> Bounce {
+        Bounce::new(EaseMode::Out, bounces, decay)
+    }
+}
+
+impl BounceInOut {
+    /// Creates a [Bounce] tween like this one, but with a custom number of `bounces` and `decay`.
+    pub fn with_bounces(bounces: u32, decay: f32) -> Bounce {
+        Bounce::new(EaseMode::InOut, bounces, decay)
+    }
+}
+
+impl_tween_ops!(Bounce);
+
+impl<Value: crate::TweenValue> crate::Tween<Value> for Bounce {
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let scalar = self.mode.apply(percent, |t| 1.0 - self.bounce_out(1.0 - t));
+
+        value_delta.scale(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tuned_tests {
+    use super::*;
+    use crate::Tween;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn defaults_match_fixed() {
+        let (n, d) = (Bounce::DEFAULT_BOUNCES, Bounce::DEFAULT_DECAY);
+
+        for i in 0..=20 {
+            let percent = i as f32 / 20.0;
+
+            assert_relative_eq!(
+                BounceIn::with_bounces(n, d).tween(10.0, percent),
+                BounceIn.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(
+                BounceOut::with_bounces(n, d).tween(10.0, percent),
+                BounceOut.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(
+                BounceInOut::with_bounces(n, d).tween(10.0, percent),
+                BounceInOut.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+        }
+    }
+
+    #[test]
+    fn stays_in_range() {
+        for bounces in 0..6 {
+            let mut bounce = BounceOut::with_bounces(bounces, 0.7);
+
+            for i in 0..=50 {
+                let value: f32 = bounce.tween(1.0, i as f32 / 50.0);
+                assert!((0.0..=1.0001).contains(&value));
+            }
+            assert_relative_eq!(bounce.tween(1.0f32, 1.0), 1.0, epsilon = 0.0001);
+        }
+    }
+}
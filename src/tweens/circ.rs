@@ -1,3 +1,5 @@
+use crate::math::float::sqrt;
+
 declare_tween!(
     /// A circular tween in. Go [here](https://easings.net/#easeInCirc) for a visual demonstration.
     pub struct CircIn;
@@ -9,11 +11,7 @@ declare_tween!(
     pub fn circ_in_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
-        #[cfg(feature = "libm")]
-        let scalar = 1.0 - libm::sqrtf(1.0 - percent * percent);
-
-        #[cfg(feature = "std")]
-        let scalar = 1.0 - (1.0 - percent * percent).sqrt();
+        let scalar = 1.0 - sqrt(1.0 - percent * percent);
 
         value_delta.scale(scalar)
     }
@@ -32,11 +30,7 @@ declare_tween!(
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         let t = percent - 1.0;
 
-        #[cfg(feature = "libm")]
-        let scalar = libm::sqrtf(1.0 - t * t);
-
-        #[cfg(feature = "std")]
-        let scalar = (1.0 - t * t).sqrt();
+        let scalar = sqrt(1.0 - t * t);
 
         value_delta.scale(scalar)
     }
@@ -56,23 +50,11 @@ declare_tween!(
         percent *= 2.0;
 
         let scalar = if percent < 1.0 {
-            #[cfg(feature = "libm")]
-            let o = 1.0 - libm::sqrtf(1.0 - percent * percent);
-
-            #[cfg(feature = "std")]
-            let o = 1.0 - (1.0 - percent * percent).sqrt();
-
-            o
+            1.0 - sqrt(1.0 - percent * percent)
         } else {
             let percent = percent - 2.0;
 
-            #[cfg(feature = "libm")]
-            let o = libm::sqrtf(1.0 - percent * percent) + 1.0;
-
-            #[cfg(feature = "std")]
-            let o = (1.0 - percent * percent).sqrt() + 1.0;
-
-            o
+            sqrt(1.0 - percent * percent) + 1.0
         };
 
         value_delta.scale(scalar / 2.0)
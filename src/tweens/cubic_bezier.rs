@@ -0,0 +1,246 @@
+/// How close to the target `x` the solver needs to get before it stops.
+const EPSILON: f32 = 1e-6;
+
+/// How many Newton-Raphson iterations we try before falling back to bisection.
+const NEWTON_ITERATIONS: usize = 8;
+
+/// Why a CubicBezier with an `x` outside of `0.0..=1.0` is rejected.
+const X_OUT_OF_RANGE: &str = "cubic bezier x values must be within 0.0..=1.0";
+
+/// A tween following a cubic bezier curve, with the exact semantics of CSS's `cubic-bezier()`.
+///
+/// The curve runs from `(0, 0)` to `(1, 1)`, shaped by the two control points `(x1, y1)` and `(x2,
+/// y2)`. The `x` axis is time and the `y` axis is progress, so you can copy curves straight out of
+/// a browser or a design tool. `y1` and `y2` can leave `0.0..=1.0` to overshoot, but `x1` and `x2`
+/// cannot.
+///
+/// ```
+/// # use tween::{CubicBezier, Tweener};
+/// // this is CSS's `ease-in-out`
+/// let mut tweener = Tweener::new(0.0f32, 100.0, 10.0, CubicBezier::new(0.42, 0.0, 0.58, 1.0));
+///
+/// assert_eq!(tweener.move_to(0.0), 0.0);
+/// assert!((tweener.move_to(5.0) - 50.0).abs() < 0.001);
+/// assert_eq!(tweener.move_to(10.0), 100.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCubicBezier"))]
+pub struct CubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+/// A [CubicBezier] as it is deserialized, before its control points are checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCubicBezier> for CubicBezier {
+    type Error = &'static str;
+
+    fn try_from(raw: RawCubicBezier) -> Result<Self, Self::Error> {
+        if !CubicBezier::x_in_range(raw.x1, raw.x2) {
+            return Err(X_OUT_OF_RANGE);
+        }
+
+        Ok(Self::from_css(raw.x1, raw.y1, raw.x2, raw.y2))
+    }
+}
+
+impl CubicBezier {
+    /// CSS's `ease` keyword.
+    pub const EASE: Self = Self::from_css(0.25, 0.1, 0.25, 1.0);
+    /// CSS's `ease-in` keyword.
+    pub const EASE_IN: Self = Self::from_css(0.42, 0.0, 1.0, 1.0);
+    /// CSS's `ease-out` keyword.
+    pub const EASE_OUT: Self = Self::from_css(0.0, 0.0, 0.58, 1.0);
+    /// CSS's `ease-in-out` keyword.
+    pub const EASE_IN_OUT: Self = Self::from_css(0.42, 0.0, 0.58, 1.0);
+
+    /// Creates a new CubicBezier out of its two control points, in the same order as CSS's
+    /// `cubic-bezier(x1, y1, x2, y2)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x1` or `x2` are outside of `0.0..=1.0`, since the curve would no longer be a
+    /// function of time. Deserializing checks this too, but returns an error instead.
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        assert!(Self::x_in_range(x1, x2), "{X_OUT_OF_RANGE}");
+
+        Self::from_css(x1, y1, x2, y2)
+    }
+
+    fn x_in_range(x1: f32, x2: f32) -> bool {
+        (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2)
+    }
+
+    const fn from_css(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Returns the control points as `(x1, y1, x2, y2)`.
+    pub fn control_points(&self) -> (f32, f32, f32, f32) {
+        (self.x1, self.y1, self.x2, self.y2)
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.progress(percent))
+    }
+
+    /// Returns the `y` of the curve at the given `x`, which is the progress of the tween at the
+    /// given percent of time.
+    pub fn progress(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        Self::sample(self.y1, self.y2, self.solve_t(x))
+    }
+
+    /// Finds the parametric `t` at which the curve reaches `x`.
+    fn solve_t(&self, x: f32) -> f32 {
+        // Newton-Raphson is fast and almost always converges...
+        let mut t = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = Self::sample(self.x1, self.x2, t) - x;
+            if -EPSILON < error && error < EPSILON {
+                return t;
+            }
+
+            let slope = Self::slope(self.x1, self.x2, t);
+            if -EPSILON < slope && slope < EPSILON {
+                break;
+            }
+
+            t -= error / slope;
+        }
+
+        // ...but when it doesn't, bisection always will, since x is monotonic in t.
+        let (mut low, mut high) = (0.0, 1.0);
+        t = x;
+        while high - low > EPSILON {
+            let sample = Self::sample(self.x1, self.x2, t);
+            if -EPSILON < sample - x && sample - x < EPSILON {
+                break;
+            }
+
+            if sample < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) / 2.0;
+        }
+
+        t
+    }
+
+    /// One axis of the curve at `t`, with its endpoints at `0` and `1`.
+    #[inline(always)]
+    fn sample(p1: f32, p2: f32, t: f32) -> f32 {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+
+        ((a * t + b) * t + c) * t
+    }
+
+    /// The derivative of [Self::sample].
+    #[inline(always)]
+    fn slope(p1: f32, p2: f32, t: f32) -> f32 {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+
+        (3.0 * a * t + 2.0 * b) * t + c
+    }
+}
+
+impl_tween_ops!(CubicBezier);
+
+impl<Value> crate::Tween<Value> for CubicBezier
+where
+    Value: crate::TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn linear() {
+        let bezier = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+
+        for i in 0..=100 {
+            let x = i as f32 / 100.0;
+            assert_relative_eq!(bezier.progress(x), x, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn css_keywords() {
+        // reference values, found by bisecting these curves in f64
+        assert_relative_eq!(CubicBezier::EASE.progress(0.5), 0.8024033, epsilon = 1e-4);
+        assert_relative_eq!(CubicBezier::EASE_IN.progress(0.5), 0.3153568, epsilon = 1e-4);
+        assert_relative_eq!(CubicBezier::EASE_OUT.progress(0.5), 0.6846432, epsilon = 1e-4);
+        assert_relative_eq!(CubicBezier::EASE_IN_OUT.progress(0.5), 0.5, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn overshoot() {
+        let bezier = CubicBezier::new(0.5, -0.5, 0.5, 1.5);
+
+        assert!(bezier.progress(0.1) < 0.0);
+        assert!(bezier.progress(0.9) > 1.0);
+        assert_eq!(bezier.progress(1.0), 1.0);
+    }
+
+    #[test]
+    fn steep() {
+        // this is nearly vertical at the start, which is where Newton struggles
+        let bezier = CubicBezier::new(0.0, 1.0, 0.0, 1.0);
+        let mut last = 0.0;
+
+        for i in 0..=100 {
+            let y = bezier.progress(i as f32 / 100.0);
+            assert!(y >= last);
+            last = y;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_x() {
+        CubicBezier::new(1.5, 0.0, 0.5, 1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_checks_x() {
+        let json = serde_json::to_string(&CubicBezier::EASE).unwrap();
+        assert_eq!(serde_json::from_str::<CubicBezier>(&json).unwrap(), CubicBezier::EASE);
+
+        let invalid = r#"{"x1":1.5,"y1":0.0,"x2":0.5,"y2":1.0}"#;
+        assert!(serde_json::from_str::<CubicBezier>(invalid).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+/// Which end(s) of a tunable tween, like [Back](crate::Back), get the effect.
+///
+/// These line up with the `In`, `Out`, and `InOut` suffixes of the fixed tweens, so
+/// `Back::new(EaseMode::In, ..)` is the tunable version of [BackIn](crate::BackIn).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EaseMode {
+    /// The effect happens at the start of the tween.
+    #[default]
+    In,
+
+    /// The effect happens at the end of the tween.
+    Out,
+
+    /// The effect happens at both ends of the tween.
+    InOut,
+}
+
+impl EaseMode {
+    /// Runs a curve given as its `In` form in this mode, mirroring it as needed.
+    #[cfg(any(feature = "back", feature = "bounce"))]
+    #[inline(always)]
+    pub(crate) fn apply(self, percent: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+        match self {
+            EaseMode::In => ease_in(percent),
+            EaseMode::Out => 1.0 - ease_in(1.0 - percent),
+            EaseMode::InOut => {
+                if percent < 0.5 {
+                    ease_in(percent * 2.0) / 2.0
+                } else {
+                    1.0 - ease_in(2.0 - percent * 2.0) / 2.0
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use super::EaseMode;
+use crate::math::float::{asin, exp2, powf, sin};
 use core::f32::consts::TAU;
 
 const SIGMA: f32 = 0.075;
@@ -16,30 +18,22 @@ declare_tween!(
     pub fn elastic_in_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, mut percent: f32) -> Value {
-        if percent == 0.0 {
+        if crate::percent_at_start(percent) {
             return value_delta.scale(0.0);
         }
 
-        if percent == 1.0 {
+        if crate::percent_at_end(percent) {
             return value_delta;
         }
 
         percent -= 1.0;
 
-        #[cfg(feature = "libm")]
-        let scalar = libm::powf(2.0, percent * 10.0);
-
-        #[cfg(feature = "std")]
-        let scalar = 2f32.powf(percent * 10.0);
+        let scalar = powf(2.0, percent * 10.0);
 
         let post_fix = value_delta.scale(scalar);
         let temp = (percent - SIGMA) * TAU * THREE_DOT_THREE_REPEATING;
 
-        #[cfg(feature = "libm")]
-        let scalar = -libm::sinf(temp);
-
-        #[cfg(feature = "std")]
-        let scalar = -temp.sin();
+        let scalar = -sin(temp);
 
         post_fix.scale(scalar)
     }
@@ -56,23 +50,19 @@ declare_tween!(
     pub fn elastic_out_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value,  percent: f32) -> Value {
-        if percent == 0.0 {
+        if crate::percent_at_start(percent) {
             return value_delta.scale(0.0);
         }
 
-        if percent == 1.0 {
+        if crate::percent_at_end(percent) {
             return value_delta;
         }
 
         let temp = (percent - SIGMA) * TAU * THREE_DOT_THREE_REPEATING;
 
-        #[cfg(feature = "libm")]
-        let scalar = libm::powf(2.0, -10.0 * percent) * libm::sinf(temp);
-
-        #[cfg(feature = "std")]
-        let scalar = 2f32.powf(-10.0 * percent) * temp.sin();
+        let scalar = powf(2.0, -10.0 * percent) * sin(temp);
 
-        value_delta.scale(scalar) + value_delta
+        value_delta.clone().scale(scalar) + value_delta
     }
 );
 
@@ -87,48 +77,32 @@ declare_tween!(
     pub fn elastic_in_out_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, mut percent: f32) -> Value {
-        if percent == 0.0 {
+        if crate::percent_at_start(percent) {
             return value_delta.scale(0.0);
         }
 
-        if percent == 1.0 {
+        if crate::percent_at_end(percent) {
             return value_delta;
         }
 
         percent = (percent * 2.0) - 1.0;
 
         if percent < 0.0 {
-            #[cfg(feature = "libm")]
-            let scalar = libm::powf(2.0, percent * 10.0);
-
-            #[cfg(feature = "std")]
-            let scalar = 2f32.powf(percent * 10.0);
+            let scalar = powf(2.0, percent * 10.0);
 
             let post_fix = value_delta.scale(scalar);
             let temp = (percent - SIGMA_IN_OUT) * TAU * FORTY_FIVE;
 
-            #[cfg(feature = "libm")]
-            let temp_sin = libm::sinf(temp);
-
-            #[cfg(feature = "std")]
-            let temp_sin = temp.sin();
+            let temp_sin = sin(temp);
 
             post_fix.scale(-0.5 * temp_sin)
         } else {
-            #[cfg(feature = "libm")]
-            let scalar = libm::powf(2.0, percent * -10.0);
+            let scalar = powf(2.0, percent * -10.0);
 
-            #[cfg(feature = "std")]
-            let scalar = 2f32.powf(-10.0 * percent);
-
-            let post_fix = value_delta.scale(scalar);
+            let post_fix = value_delta.clone().scale(scalar);
             let temp = (percent - SIGMA_IN_OUT) * TAU * FORTY_FIVE;
 
-            #[cfg(feature = "libm")]
-            let temp_sin = libm::sinf(temp);
-
-            #[cfg(feature = "std")]
-            let temp_sin = temp.sin();
+            let temp_sin = sin(temp);
 
             post_fix.scale(temp_sin * 0.5) + value_delta
         }
@@ -137,3 +111,194 @@ declare_tween!(
 );
 
 test_tween!(Elastic);
+
+/// An [Elastic] tween with its amplitude and period tuned, instead of using the easings.net
+/// defaults of [ElasticIn], [ElasticOut], and [ElasticInOut].
+///
+/// The `period` is how long one wobble takes, as a fraction of the tween, so smaller periods
+/// wobble more often. The defaults are `0.3` for the single-ended modes and `0.45` for
+/// [EaseMode::InOut]. The `amplitude` is how far the first wobble swings, where `1.0` is the whole
+/// `value_delta`; like in Penner's original equations, amplitudes below `1.0` are treated as `1.0`.
+///
+/// ```
+/// # use tween::{ElasticOut, Tweener};
+/// let mut springy = Tweener::new(0.0, 100.0, 10.0, ElasticOut::with_params(1.0, 0.6));
+///
+/// assert_eq!(springy.move_to(0.0), 0.0);
+/// assert_eq!(springy.move_to(10.0), 100.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Elastic {
+    /// Which end(s) of the tween wobble.
+    pub mode: EaseMode,
+
+    /// How far the wobbles swing, relative to `value_delta`.
+    pub amplitude: f32,
+
+    /// How long one wobble takes, as a fraction of the tween.
+    pub period: f32,
+}
+
+impl Elastic {
+    /// The amplitude used by [ElasticIn], [ElasticOut], and [ElasticInOut].
+    pub const DEFAULT_AMPLITUDE: f32 = 1.0;
+
+    /// The period used by [ElasticIn] and [ElasticOut].
+    pub const DEFAULT_PERIOD: f32 = 0.3;
+
+    /// The period used by [ElasticInOut].
+    pub const DEFAULT_IN_OUT_PERIOD: f32 = 0.45;
+
+    /// Creates a new Elastic tween with the given amplitude and period.
+    pub fn new(mode: EaseMode, amplitude: f32, period: f32) -> Self {
+        Self {
+            mode,
+            amplitude,
+            period,
+        }
+    }
+}
+
+impl ElasticIn {
+    /// Creates an [Elastic] tween like this one, but with a custom `amplitude` and `period`.
+    pub fn with_params(amplitude: f32, period: f32) -> Elastic {
+        Elastic::new(EaseMode::In, amplitude, period)
+    }
+}
+
+impl ElasticOut {
+    /// Creates an [Elastic] tween like this one, but with a custom `amplitude` and `period`.
+    pub fn with_params(amplitude: f32, period: f32) -> Elastic {
+        Elastic::new(EaseMode::Out, amplitude, period)
+    }
+}
+
+impl ElasticInOut {
+    /// Creates an [Elastic] tween like this one, but with a custom `amplitude` and `period`.
+    pub fn with_params(amplitude: f32, period: f32) -> Elastic {
+        Elastic::new(EaseMode::InOut, amplitude, period)
+    }
+}
+
+impl_tween_ops!(Elastic);
+
+impl<Value: crate::TweenValue> crate::Tween<Value> for Elastic {
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        if crate::percent_at_start(percent) {
+            return value_delta.scale(0.0);
+        }
+
+        if crate::percent_at_end(percent) {
+            return value_delta;
+        }
+
+        let (amplitude, sigma) = if self.amplitude <= 1.0 {
+            (1.0, self.period / 4.0)
+        } else {
+            (self.amplitude, self.period / TAU * asin(1.0 / self.amplitude))
+        };
+        let wobble = |t: f32| amplitude * sin((t - sigma) * TAU / self.period);
+
+        let scalar = match self.mode {
+            EaseMode::In => {
+                let t = percent - 1.0;
+                -exp2(t * 10.0) * wobble(t)
+            }
+            EaseMode::Out => exp2(percent * -10.0) * wobble(percent) + 1.0,
+            EaseMode::InOut => {
+                let t = percent * 2.0 - 1.0;
+                if t < 0.0 {
+                    -0.5 * exp2(t * 10.0) * wobble(t)
+                } else {
+                    0.5 * exp2(t * -10.0) * wobble(t) + 1.0
+                }
+            }
+        };
+
+        value_delta.scale(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tuned_tests {
+    use super::*;
+    use crate::Tween;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn defaults_match_fixed() {
+        let (a, p) = (Elastic::DEFAULT_AMPLITUDE, Elastic::DEFAULT_PERIOD);
+
+        for i in 0..=20 {
+            let percent = i as f32 / 20.0;
+
+            assert_relative_eq!(
+                ElasticIn::with_params(a, p).tween(10.0, percent),
+                ElasticIn.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(
+                ElasticOut::with_params(a, p).tween(10.0, percent),
+                ElasticOut.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(
+                ElasticInOut::with_params(a, Elastic::DEFAULT_IN_OUT_PERIOD).tween(10.0, percent),
+                ElasticInOut.tween(10.0, percent),
+                epsilon = 0.0001
+            );
+        }
+    }
+
+    #[test]
+    fn larger_amplitude_swings_further() {
+        let mut classic = ElasticOut::with_params(1.0, 0.3);
+        let mut wild = ElasticOut::with_params(2.0, 0.3);
+
+        let peak = |tween: &mut Elastic| {
+            (1..20)
+                .map(|i| tween.tween(1.0f32, i as f32 / 20.0))
+                .fold(0.0, f32::max)
+        };
+
+        assert!(peak(&mut wild) > peak(&mut classic));
+        assert_eq!(wild.tween(1.0f32, 1.0), 1.0);
+    }
+
+    #[test]
+    fn same_curve_in_any_time_unit() {
+        use crate::Tweener;
+        use core::time::Duration;
+
+        let mut frames = Tweener::new(0.0f32, 10.0, 60u32, ElasticInOut);
+        let mut seconds = Tweener::new(0.0f32, 10.0, 1.0f32, ElasticInOut);
+        let mut millis = Tweener::new(0.0f32, 10.0, 1000.0f64, ElasticInOut);
+        let mut duration = Tweener::new(0.0f32, 10.0, Duration::from_secs(1), ElasticInOut);
+
+        for frame in 0..=60 {
+            let expected = ElasticInOut.tween(10.0f32, frame as f32 / 60.0);
+
+            assert_relative_eq!(frames.move_to(frame), expected, epsilon = 1e-5);
+            assert_relative_eq!(seconds.move_to(frame as f32 / 60.0), expected, epsilon = 1e-5);
+            assert_relative_eq!(millis.move_to(frame as f64 * 1000.0 / 60.0), expected, epsilon = 1e-5);
+            assert_relative_eq!(
+                duration.move_to(Duration::from_secs_f32(frame as f32 / 60.0)),
+                expected,
+                epsilon = 1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn lands_on_endpoints() {
+        let almost = 1.0 - f32::EPSILON;
+
+        assert_eq!(ElasticIn.tween(10.0f32, almost), 10.0);
+        assert_eq!(ElasticOut.tween(10.0f32, almost), 10.0);
+        assert_eq!(ElasticInOut.tween(10.0f32, almost), 10.0);
+        assert_eq!(ElasticOut::with_params(2.0, 0.3).tween(10.0f32, almost), 10.0);
+        assert_eq!(ElasticIn.tween(10.0f32, f32::EPSILON), 0.0);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::math::float::powf;
+
 declare_tween!(
     /// An exponenential tween in. See [here](https://easings.net/#easeInExpo)
     pub struct ExpoIn;
@@ -10,17 +12,13 @@ declare_tween!(
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         // weird edge in this tween?
-        if percent == 0.0 {
+        if crate::percent_at_start(percent) {
             return value_delta.scale(0.0);
         }
 
         let percent = 10.0 * (percent - 1.0);
 
-        #[cfg(feature = "libm")]
-        let scalar = libm::powf(2.0, percent);
-
-        #[cfg(feature = "std")]
-        let scalar = 2.0f32.powf(percent);
+        let scalar = powf(2.0, percent);
 
         value_delta.scale(scalar)
     }
@@ -37,14 +35,10 @@ declare_tween!(
     pub fn expo_out_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
-        if percent == 1.0 {
+        if crate::percent_at_end(percent) {
             value_delta
         } else {
-            #[cfg(feature = "libm")]
-            let powf = libm::powf(2.0, -10.0 * percent);
-
-            #[cfg(feature = "std")]
-            let powf = 2.0f32.powf(-10.0 * percent);
+            let powf = powf(2.0, -10.0 * percent);
 
             value_delta.scale(1.0 - powf)
         }
@@ -62,32 +56,24 @@ declare_tween!(
     pub fn expo_in_out_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, mut percent: f32) -> Value {
-        if percent == 0.0 {
+        if crate::percent_at_start(percent) {
             return value_delta.scale(0.0);
         }
 
-        if percent == 1.0 {
+        if crate::percent_at_end(percent) {
             return value_delta;
         }
 
         percent *= 2.0;
 
         let powf = if percent < 1.0 {
-            #[cfg(feature = "libm")]
-            let scalar = libm::powf(2.0, 10.0 * (percent - 1.0));
-
-            #[cfg(feature = "std")]
-            let scalar = 2.0f32.powf(10.0 * (percent - 1.0));
+            let scalar = powf(2.0, 10.0 * (percent - 1.0));
 
             scalar / 2.0
         } else {
             let percent = percent - 1.0;
 
-            #[cfg(feature = "libm")]
-            let scalar = libm::powf(2.0, -10.0 * percent);
-
-            #[cfg(feature = "std")]
-            let scalar = 2.0f32.powf(-10.0 * percent);
+            let scalar = powf(2.0, -10.0 * percent);
 
             (2.0 - scalar) / 2.0
         };
@@ -0,0 +1,147 @@
+use crate::{
+    TweenValue,
+    math::float::{cos, sin},
+};
+
+/// The law a crossfade follows, which decides how the gains of the sound fading out and the sound
+/// fading in add up as they cross.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FadeCurve {
+    /// The gains always add up to `1.0`, following straight lines. This is right for signals which
+    /// are in phase with each other, like two takes of the same recording, and for visual
+    /// dissolves, where opacities should sum to fully opaque.
+    EqualGain,
+
+    /// The squares of the gains always add up to `1.0`, following a quarter of a sine and a cosine.
+    /// This keeps the loudness of two unrelated sounds steady through the middle of the fade,
+    /// where an equal-gain fade would dip.
+    #[default]
+    EqualPower,
+}
+
+impl FadeCurve {
+    /// The gains of the sound fading out and the sound fading in, in that order, at `percent` of
+    /// the way through the fade.
+    ///
+    /// ```
+    /// # use tween::FadeCurve;
+    /// let (out, into) = FadeCurve::EqualPower.gains(0.5);
+    /// assert!((out * out + into * into - 1.0).abs() < 1e-6);
+    ///
+    /// assert_eq!(FadeCurve::EqualGain.gains(0.25), (0.75, 0.25));
+    /// ```
+    pub fn gains(self, percent: f32) -> (f32, f32) {
+        let percent = percent.clamp(0.0, 1.0);
+
+        match self {
+            FadeCurve::EqualGain => (1.0 - percent, percent),
+            FadeCurve::EqualPower => {
+                // the ends are pinned, so the fade lands exactly on silence and on full gain
+                if percent >= 1.0 {
+                    return (0.0, 1.0);
+                }
+
+                let angle = percent * core::f32::consts::FRAC_PI_2;
+                (cos(angle), sin(angle))
+            }
+        }
+    }
+}
+
+/// A [FadeIn] tween follows the rising half of a [FadeCurve], for tweening a gain or an opacity
+/// from `0.0` up to `1.0`.
+///
+/// Paired with a [FadeOut] of the same curve on the same clock, the two make a matched crossfade.
+/// [FadePair](crate::FadePair) drives both at once.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FadeIn(pub FadeCurve);
+
+impl FadeIn {
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.0.gains(percent).1)
+    }
+}
+
+impl<Value: TweenValue> crate::Tween<Value> for FadeIn {
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+}
+
+/// A [FadeOut] tween follows the falling half of a [FadeCurve], for tweening a gain or an opacity
+/// from `1.0` down to `0.0`. Like any tween, it runs from its Tweener's `start` to its `end`, so
+/// the start should be the full gain.
+///
+/// ```
+/// # use tween::{FadeCurve, FadeIn, FadeOut, Tweener};
+/// let mut fading_out = Tweener::new(1.0f32, 0.0, 10.0, FadeOut(FadeCurve::EqualGain));
+/// let mut fading_in = Tweener::new(0.0f32, 1.0, 10.0, FadeIn(FadeCurve::EqualGain));
+///
+/// assert_eq!(fading_out.move_to(2.5) + fading_in.move_to(2.5), 1.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FadeOut(pub FadeCurve);
+
+impl FadeOut {
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(1.0 - self.0.gains(percent).0)
+    }
+}
+
+impl<Value: TweenValue> crate::Tween<Value> for FadeOut {
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+}
+
+impl_tween_ops!(FadeIn);
+impl_tween_ops!(FadeOut);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tweener;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn laws_hold_throughout() {
+        for i in 0..=100 {
+            let pct = i as f32 / 100.0;
+
+            let (out, into) = FadeCurve::EqualGain.gains(pct);
+            assert_relative_eq!(out + into, 1.0);
+
+            let (out, into) = FadeCurve::EqualPower.gains(pct);
+            assert_relative_eq!(out * out + into * into, 1.0, epsilon = 1e-6);
+        }
+
+        assert_eq!(FadeCurve::EqualPower.gains(0.0), (1.0, 0.0));
+        assert_eq!(FadeCurve::EqualPower.gains(1.0), (0.0, 1.0));
+        assert_eq!(FadeCurve::EqualGain.gains(2.0), (0.0, 1.0));
+    }
+
+    #[test]
+    fn tweens_follow_the_gains() {
+        for curve in [FadeCurve::EqualGain, FadeCurve::EqualPower] {
+            let mut fading_out = Tweener::new(1.0f32, 0.0, 1.0, FadeOut(curve));
+            let mut fading_in = Tweener::new(0.0f32, 1.0, 1.0, FadeIn(curve));
+
+            for i in 0..=10 {
+                let pct = i as f32 / 10.0;
+                let (out, into) = curve.gains(pct);
+
+                assert_relative_eq!(fading_out.move_to(pct), out, epsilon = 1e-6);
+                assert_relative_eq!(fading_in.move_to(pct), into, epsilon = 1e-6);
+            }
+        }
+    }
+}
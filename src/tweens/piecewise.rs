@@ -0,0 +1,148 @@
+use core::marker::PhantomData;
+
+use crate::{Tween, TweenValue};
+
+/// A tween assembled out of segments, each of which runs its own easing over part of the tween.
+///
+/// Each segment is a `(breakpoint, easing)` pair, where the breakpoint is the percent at which that
+/// segment *ends*. At each breakpoint, the tween has gone exactly that percent of the way, and in
+/// between, the segment's easing shapes the motion. The breakpoints must increase, and the last one
+/// must be `1.0`.
+///
+/// This makes curves like "fast start, long coast, snappy finish" easy to author, without reaching
+/// for [Keyframes](crate::Keyframes). To mix different easings, use [Easing](crate::Easing):
+///
+/// ```
+/// # #[cfg(all(feature = "quad", feature = "cubic"))] {
+/// # use tween::{Easing, Piecewise, Tweener};
+/// let curve = Piecewise::new([
+///     (0.2, Easing::QuadOut),
+///     (0.8, Easing::Linear),
+///     (1.0, Easing::CubicIn),
+/// ]);
+/// let mut tweener = Tweener::new(0, 100, 10, curve);
+///
+/// assert_eq!(tweener.move_to(2), 20);
+/// assert_eq!(tweener.move_to(5), 50);
+/// assert_eq!(tweener.move_to(8), 80);
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Piecewise<E, S> {
+    segments: S,
+    _marker: PhantomData<fn() -> E>,
+}
+
+/// A [Piecewise] stored in an array, for `no_std` usage.
+pub type PiecewiseArray<E, const N: usize> = Piecewise<E, [(f32, E); N]>;
+
+/// A [Piecewise] stored in a `Vec`.
+#[cfg(feature = "alloc")]
+pub type PiecewiseVec<E> = Piecewise<E, alloc::vec::Vec<(f32, E)>>;
+
+impl<E, S> Piecewise<E, S>
+where
+    S: AsRef<[(f32, E)]> + AsMut<[(f32, E)]>,
+{
+    /// Creates a new Piecewise tween out of `(breakpoint, easing)` segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is empty, if its breakpoints don't strictly increase, if the first
+    /// breakpoint isn't above `0.0`, or if the last breakpoint isn't `1.0`.
+    pub fn new(segments: S) -> Self {
+        let segs = segments.as_ref();
+        assert!(!segs.is_empty(), "piecewise segments cannot be empty");
+        assert!(segs[0].0 > 0.0, "the first breakpoint must be above 0.0");
+        assert!(
+            segs.windows(2).all(|w| w[0].0 < w[1].0),
+            "piecewise breakpoints must strictly increase"
+        );
+        assert!(segs[segs.len() - 1].0 == 1.0, "the last breakpoint must be 1.0");
+
+        Self {
+            segments,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The `(breakpoint, easing)` segments of this tween.
+    pub fn segments(&self) -> &[(f32, E)] {
+        self.segments.as_ref()
+    }
+
+    /// Consumes this Piecewise, returning its segments.
+    pub fn into_inner(self) -> S {
+        self.segments
+    }
+}
+
+impl<Value, E, S> Tween<Value> for Piecewise<E, S>
+where
+    Value: TweenValue,
+    E: Tween<Value>,
+    S: AsRef<[(f32, E)]> + AsMut<[(f32, E)]>,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let segments = self.segments.as_mut();
+        let index = segments
+            .partition_point(|(end, _)| *end < percent)
+            .min(segments.len() - 1);
+
+        let start = if index == 0 { 0.0 } else { segments[index - 1].0 };
+        let (end, easing) = &mut segments[index];
+        let width = *end - start;
+
+        value_delta.clone().scale(start) + easing.tween(value_delta.scale(width), (percent - start) / width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicBezier, Linear};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn single_segment_is_plain() {
+        let mut ease = CubicBezier::EASE_OUT;
+        let mut piecewise = Piecewise::new([(1.0, ease)]);
+
+        for i in 0..=10 {
+            let pct = i as f32 / 10.0;
+            assert_relative_eq!(piecewise.tween(10.0f32, pct), ease.tween(10.0, pct), epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn segments_map_their_subrange() {
+        let mut piecewise = Piecewise::new([(0.5, Linear), (1.0, Linear)]);
+        assert_relative_eq!(piecewise.tween(10.0f32, 0.25), 2.5);
+    }
+
+    #[cfg(feature = "quad")]
+    #[test]
+    fn segments_keep_their_easing() {
+        use crate::QuadIn;
+
+        let mut piecewise = Piecewise::new([(0.5, QuadIn), (1.0, QuadIn)]);
+        assert_relative_eq!(piecewise.tween(10.0f32, 0.25), 1.25);
+        assert_relative_eq!(piecewise.tween(10.0f32, 0.5), 5.0);
+        assert_relative_eq!(piecewise.tween(10.0f32, 0.75), 6.25);
+        assert_relative_eq!(piecewise.tween(10.0f32, 1.0), 10.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn must_end_at_one() {
+        Piecewise::new([(0.5, Linear)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn must_increase() {
+        Piecewise::new([(0.5, Linear), (0.5, Linear), (1.0, Linear)]);
+    }
+}
@@ -0,0 +1,344 @@
+use crate::{
+    Tween, TweenLength, TweenValue, Tweener,
+    math::float::{cbrt, sqrt},
+};
+
+/// A jerk-limited motion profile, often called an S-curve, for mechanical motion and camera moves
+/// which need to be smoother than a [TrapezoidProfile](crate::TrapezoidProfile).
+///
+/// A trapezoidal profile switches its acceleration on and off instantly, which shakes machines
+/// and shows up as a jolt on camera. This one ramps its acceleration up and down at no more than
+/// `max_jerk` instead, so the move has seven phases: the jerk ramps acceleration up, acceleration
+/// holds at `max_acceleration`, and the jerk ramps it back down as the profile reaches
+/// `max_velocity`; then it cruises, and mirrors all of that to stop. Moves too short to reach the
+/// limits skip the phases which would hold at them, and the phase durations it settled on can be
+/// read back with [jerk_duration](Self::jerk_duration),
+/// [acceleration_duration](Self::acceleration_duration) and
+/// [cruise_duration](Self::cruise_duration).
+///
+/// Just like a [TrapezoidProfile](crate::TrapezoidProfile), this is made for a particular
+/// `distance`, and should run over its own [duration](Self::duration), which
+/// [tweener](Self::tweener) does for you.
+///
+/// ```
+/// # use tween::SCurveProfile;
+/// let profile = SCurveProfile::new(20.0, 4.0, 2.0, 2.0);
+///
+/// assert_eq!(profile.jerk_duration(), 1.0);
+/// assert_eq!(profile.acceleration_duration(), 1.0);
+/// assert_eq!(profile.cruise_duration(), 2.0);
+/// assert_eq!(profile.duration(), 8.0);
+///
+/// let mut tweener = SCurveProfile::tweener(0.0f32, 20.0, 4.0, 2.0, 2.0);
+/// assert_eq!(tweener.move_to(4.0), 10.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SCurveProfile {
+    /// How far the profile moves. Negative distances are treated as positive, since the direction
+    /// comes from the tween itself.
+    pub distance: f32,
+
+    /// The fastest the profile may move.
+    pub max_velocity: f32,
+
+    /// The fastest the profile may speed up or slow down.
+    pub max_acceleration: f32,
+
+    /// The fastest the profile's acceleration may change.
+    pub max_jerk: f32,
+}
+
+impl SCurveProfile {
+    /// Creates a new SCurveProfile, moving `distance` without going over `max_velocity`,
+    /// `max_acceleration` or `max_jerk`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_velocity`, `max_acceleration` or `max_jerk` isn't positive.
+    pub fn new(distance: f32, max_velocity: f32, max_acceleration: f32, max_jerk: f32) -> Self {
+        assert!(max_velocity > 0.0, "a motion profile's max velocity must be positive");
+        assert!(
+            max_acceleration > 0.0,
+            "a motion profile's max acceleration must be positive"
+        );
+        assert!(max_jerk > 0.0, "a motion profile's max jerk must be positive");
+
+        Self {
+            distance,
+            max_velocity,
+            max_acceleration,
+            max_jerk,
+        }
+    }
+
+    /// Creates a [Tweener] which moves from `start` to `end` with a profile made for the distance
+    /// between them, running over the profile's [duration](Self::duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_velocity`, `max_acceleration` or `max_jerk` isn't positive.
+    pub fn tweener<Value: TweenLength>(
+        start: Value,
+        end: Value,
+        max_velocity: f32,
+        max_acceleration: f32,
+        max_jerk: f32,
+    ) -> Tweener<Value, f32, Self> {
+        let distance = (end.clone() - start.clone()).length();
+        let profile = Self::new(distance, max_velocity, max_acceleration, max_jerk);
+
+        Tweener::new(start, end, profile.duration(), profile)
+    }
+
+    /// How long each of the four phases where the jerk ramps the acceleration up or down lasts.
+    pub fn jerk_duration(&self) -> f32 {
+        self.phases().0
+    }
+
+    /// How long each of the two phases which hold at `max_acceleration` lasts. This is `0.0` if the
+    /// profile never reaches `max_acceleration`.
+    pub fn acceleration_duration(&self) -> f32 {
+        self.phases().1
+    }
+
+    /// How long the profile spends at `max_velocity`. This is `0.0` if the profile never reaches
+    /// it.
+    pub fn cruise_duration(&self) -> f32 {
+        self.phases().2
+    }
+
+    /// How long the whole move takes.
+    pub fn duration(&self) -> f32 {
+        let (jerk, acceleration, cruise) = self.phases();
+
+        4.0 * jerk + 2.0 * acceleration + cruise
+    }
+
+    /// The fastest the profile actually moves, which is at most `max_velocity`.
+    pub fn peak_velocity(&self) -> f32 {
+        let (jerk, acceleration, _) = self.phases();
+
+        self.max_jerk * jerk * (jerk + acceleration)
+    }
+
+    /// The fastest the profile actually speeds up, which is at most `max_acceleration`.
+    pub fn peak_acceleration(&self) -> f32 {
+        self.max_jerk * self.jerk_duration()
+    }
+
+    /// Returns how far the profile has moved at `time`, from `0.0` to its `distance`. Times outside
+    /// of the profile's [duration](Self::duration) are clamped.
+    pub fn position(&self, time: f32) -> f32 {
+        let duration = self.duration();
+        let time = time.clamp(0.0, duration);
+
+        // the second half mirrors the first, which also makes it land exactly on the end
+        if time <= duration / 2.0 {
+            self.first_half(time).0
+        } else {
+            self.distance.abs() - self.first_half(duration - time).0
+        }
+    }
+
+    /// Returns how fast the profile is moving at `time`. This is `0.0` outside of its
+    /// [duration](Self::duration).
+    pub fn velocity(&self, time: f32) -> f32 {
+        let duration = self.duration();
+        if time <= 0.0 || time >= duration {
+            return 0.0;
+        }
+
+        self.first_half(time.min(duration - time)).1
+    }
+
+    /// Returns how fast the profile is speeding up at `time`, which is negative while it slows
+    /// down. This is `0.0` outside of its [duration](Self::duration).
+    pub fn acceleration(&self, time: f32) -> f32 {
+        let duration = self.duration();
+        if time <= 0.0 || time >= duration {
+            0.0
+        } else if time <= duration / 2.0 {
+            self.first_half(time).2
+        } else {
+            -self.first_half(duration - time).2
+        }
+    }
+
+    /// Returns how far along the profile is at `percent` of its duration, from `0.0` to `1.0`. A
+    /// profile which doesn't move at all is always at `1.0`, since it's done before it starts.
+    pub fn progress(&self, percent: f32) -> f32 {
+        let distance = self.distance.abs();
+        if distance == 0.0 {
+            return 1.0;
+        }
+
+        self.position(percent * self.duration()) / distance
+    }
+
+    /// Works out how long the jerk, constant acceleration, and cruise phases last.
+    fn phases(&self) -> (f32, f32, f32) {
+        let distance = self.distance.abs();
+        let (velocity, acceleration, jerk) = (self.max_velocity, self.max_acceleration, self.max_jerk);
+
+        // first, assume we reach `max_velocity`
+        let (jerk_time, acceleration_time) = if velocity * jerk >= acceleration * acceleration {
+            (acceleration / jerk, velocity / acceleration - acceleration / jerk)
+        } else {
+            (sqrt(velocity / jerk), 0.0)
+        };
+        let ramp_distance = velocity * (2.0 * jerk_time + acceleration_time) / 2.0;
+
+        if 2.0 * ramp_distance <= distance {
+            return (
+                jerk_time,
+                acceleration_time,
+                (distance - 2.0 * ramp_distance) / velocity,
+            );
+        }
+
+        // we can't, so find the peak velocity which covers the distance while still reaching
+        // `max_acceleration`...
+        let ratio = acceleration / jerk;
+        let peak = acceleration / 2.0 * (sqrt(ratio * ratio + 4.0 * distance / acceleration) - ratio);
+        if peak * jerk >= acceleration * acceleration {
+            return (ratio, peak / acceleration - ratio, 0.0);
+        }
+
+        // ...or, for the shortest moves, which never reach it at all
+        (cbrt(distance / (2.0 * jerk)), 0.0, 0.0)
+    }
+
+    /// Returns the position, velocity and acceleration at `time`, which must be in the first half
+    /// of the profile.
+    fn first_half(&self, time: f32) -> (f32, f32, f32) {
+        let (jerk_time, acceleration_time, cruise_time) = self.phases();
+        let jerk = self.max_jerk;
+        let segments = [
+            (jerk_time, jerk),
+            (acceleration_time, 0.0),
+            (jerk_time, -jerk),
+            (cruise_time / 2.0, 0.0),
+        ];
+
+        let (mut p, mut v, mut a) = (0.0, 0.0, 0.0);
+        let mut left = time;
+        for (length, j) in segments {
+            let dt = left.min(length);
+
+            p += v * dt + a * dt * dt / 2.0 + j * dt * dt * dt / 6.0;
+            v += a * dt + j * dt * dt / 2.0;
+            a += j * dt;
+
+            left -= dt;
+            if left <= 0.0 {
+                break;
+            }
+        }
+
+        (p, v, a)
+    }
+}
+
+impl<Value: TweenValue> Tween<Value> for SCurveProfile {
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.progress(percent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn full_profile() {
+        let profile = SCurveProfile::new(20.0, 4.0, 2.0, 2.0);
+
+        assert_relative_eq!(profile.position(1.0), 1.0 / 3.0);
+        assert_eq!(profile.position(3.0), 6.0);
+        assert_eq!(profile.position(4.0), 10.0);
+        assert_eq!(profile.position(8.0), 20.0);
+
+        assert_eq!(profile.velocity(1.0), 1.0);
+        assert_eq!(profile.velocity(2.0), 3.0);
+        assert_eq!(profile.velocity(4.0), 4.0);
+        assert_eq!(profile.acceleration(1.5), 2.0);
+        assert_eq!(profile.acceleration(6.5), -2.0);
+    }
+
+    #[test]
+    fn short_moves() {
+        // reaches `max_acceleration`, but not `max_velocity`
+        let profile = SCurveProfile::new(7.5, 4.0, 2.0, 2.0);
+        assert_eq!(profile.cruise_duration(), 0.0);
+        assert_eq!(profile.acceleration_duration(), 0.5);
+        assert_eq!(profile.peak_velocity(), 3.0);
+        assert_eq!(profile.duration(), 5.0);
+
+        // reaches neither
+        let profile = SCurveProfile::new(0.5, 4.0, 2.0, 2.0);
+        assert_eq!(profile.jerk_duration(), 0.5);
+        assert_eq!(profile.acceleration_duration(), 0.0);
+        assert_eq!(profile.peak_acceleration(), 1.0);
+        assert_eq!(profile.duration(), 2.0);
+
+        // reaches `max_velocity` before it could reach `max_acceleration`
+        let profile = SCurveProfile::new(5.0, 1.0, 2.0, 1.0);
+        assert_eq!(profile.jerk_duration(), 1.0);
+        assert_eq!(profile.acceleration_duration(), 0.0);
+        assert_eq!(profile.cruise_duration(), 3.0);
+    }
+
+    #[test]
+    fn stays_within_limits() {
+        for profile in [
+            SCurveProfile::new(20.0, 4.0, 2.0, 2.0),
+            SCurveProfile::new(-7.5, 4.0, 2.0, 2.0),
+            SCurveProfile::new(0.5, 4.0, 2.0, 2.0),
+            SCurveProfile::new(5.0, 1.0, 2.0, 1.0),
+        ] {
+            let duration = profile.duration();
+            let dt = duration / 400.0;
+            let (mut last_position, mut last_acceleration) = (0.0, 0.0);
+
+            for i in 1..=400 {
+                let time = i as f32 * dt;
+                let position = profile.position(time);
+                let acceleration = profile.acceleration(time);
+
+                assert!(position >= last_position);
+                assert!(profile.velocity(time) <= profile.max_velocity * 1.001);
+                assert!(acceleration.abs() <= profile.max_acceleration * 1.001);
+                assert!((acceleration - last_acceleration).abs() / dt <= profile.max_jerk * 1.01);
+
+                last_position = position;
+                last_acceleration = acceleration;
+            }
+            assert_eq!(last_position, profile.distance.abs());
+        }
+    }
+
+    #[test]
+    fn velocity_matches_position() {
+        let profile = SCurveProfile::new(7.5, 4.0, 2.0, 2.0);
+
+        for i in 1..50 {
+            let t = i as f32 / 10.0;
+            let h = 1e-2;
+            let numeric = (profile.position(t + h) - profile.position(t - h)) / (2.0 * h);
+
+            assert_relative_eq!(profile.velocity(t), numeric, epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn tweens() {
+        let mut tweener = SCurveProfile::tweener(20.0f32, 0.0, 4.0, 2.0, 2.0);
+
+        assert_eq!(tweener.duration, 8.0);
+        assert_eq!(tweener.move_to(4.0), 10.0);
+        assert_eq!(tweener.move_to(8.0), 0.0);
+    }
+}
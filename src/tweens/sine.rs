@@ -1,3 +1,4 @@
+use crate::math::float::{cos, sin};
 use core::f32::consts::PI;
 
 declare_tween!(
@@ -11,13 +12,9 @@ declare_tween!(
     pub fn sine_in_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
-        #[cfg(feature = "libm")]
-        let time = libm::cosf(percent * PI / 2.0);
+        let time = cos(percent * PI / 2.0);
 
-        #[cfg(feature = "std")]
-        let time = (percent * PI / 2.0).cos();
-
-        value_delta.scale(-time) + value_delta
+        value_delta.clone().scale(-time) + value_delta
     }
 );
 
@@ -32,11 +29,7 @@ declare_tween!(
     pub fn sine_out_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
-        #[cfg(feature = "libm")]
-        let time = libm::sinf(percent * PI / 2.0);
-
-        #[cfg(feature = "std")]
-        let time = (percent * PI / 2.0).sin();
+        let time = sin(percent * PI / 2.0);
 
         value_delta.scale(time)
     }
@@ -54,11 +47,7 @@ declare_tween!(
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value
     {
-        #[cfg(feature = "libm")]
-        let time = libm::cosf(percent * PI) - 1.0;
-
-        #[cfg(feature = "std")]
-        let time = (percent * PI).cos() - 1.0;
+        let time = cos(percent * PI) - 1.0;
         value_delta.scale(-time / 2.0)
     }
 );
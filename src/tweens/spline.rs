@@ -0,0 +1,280 @@
+use core::marker::PhantomData;
+
+use crate::{Tween, TweenLength, TweenTime, TweenValue, Tweener};
+
+/// How many entries a [Spline] keeps in its arc length table.
+const ARC_LENGTH_SAMPLES: usize = 32;
+
+/// How many pieces each entry of the arc length table is measured in.
+const ARC_LENGTH_STEPS: usize = 8;
+
+/// A smooth path through a list of control points, for moving things along curves rather than in
+/// straight lines.
+///
+/// Between each pair of points, the path is a cubic Hermite curve. With
+/// [catmull_rom](Self::catmull_rom), the tangent at each point is worked out from its neighbours,
+/// so the path passes through every point without any corners. With [hermite](Self::hermite), the
+/// tangents are given explicitly instead.
+///
+/// The points are spread evenly over the tween, so each segment takes the same time, however long
+/// it is. To move along the path at a constant speed instead, use
+/// [constant_speed](Self::constant_speed).
+///
+/// A Spline ignores the `start` and `end` it's given, since it already knows where it's going:
+/// it returns how far it is from its first point. The easiest way to drive it is with
+/// [into_tweener](Self::into_tweener), which starts and ends the [Tweener] on the right points.
+///
+/// ```
+/// # use tween::Spline;
+/// let mut tweener = Spline::catmull_rom([0.0, 10.0, 5.0]).into_tweener(10.0);
+///
+/// assert_eq!(tweener.move_to(0.0), 0.0);
+/// assert_eq!(tweener.move_to(5.0), 10.0);
+/// assert_eq!(tweener.move_to(10.0), 5.0);
+/// ```
+///
+/// Like [Keyframes](crate::Keyframes), the points can be stored in an array or a `Vec`.
+#[derive(Debug, PartialEq, Clone, PartialOrd, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spline<Value, S> {
+    points: S,
+    tangents: Option<S>,
+    arc_lengths: Option<[f32; ARC_LENGTH_SAMPLES]>,
+    _marker: PhantomData<fn() -> Value>,
+}
+
+impl<Value, S> Spline<Value, S>
+where
+    Value: TweenValue,
+    S: AsRef<[Value]>,
+{
+    /// Creates a Catmull-Rom spline, which passes smoothly through each of `points`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    pub fn catmull_rom(points: S) -> Self {
+        assert!(!points.as_ref().is_empty(), "spline points cannot be empty");
+
+        Self {
+            points,
+            tangents: None,
+            arc_lengths: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a cubic Hermite spline, which passes through each of `points`, leaving each one in
+    /// the direction of its tangent in `tangents`. A tangent is how far the path would move over a
+    /// whole segment if it kept going in a straight line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty, or if there isn't exactly one tangent for each point.
+    pub fn hermite(points: S, tangents: S) -> Self {
+        assert!(!points.as_ref().is_empty(), "spline points cannot be empty");
+        assert!(
+            points.as_ref().len() == tangents.as_ref().len(),
+            "every spline point needs a tangent"
+        );
+
+        Self {
+            points,
+            tangents: Some(tangents),
+            arc_lengths: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reparameterizes this spline by arc length, so that it moves along its path at a constant
+    /// speed, rather than spending the same time on every segment.
+    ///
+    /// This measures the path once, up front, into a small table, so the speed is approximate:
+    /// paths with many points or very tight turns will be less even.
+    pub fn constant_speed(mut self) -> Self
+    where
+        Value: TweenLength,
+    {
+        let mut table = [0.0; ARC_LENGTH_SAMPLES];
+        let mut last = self.curve(0.0);
+        let steps = (ARC_LENGTH_SAMPLES - 1) * ARC_LENGTH_STEPS;
+
+        for step in 1..=steps {
+            let next = self.curve(step as f32 / steps as f32);
+            let entry = step.div_ceil(ARC_LENGTH_STEPS);
+
+            table[entry] += (next.clone() - last).length();
+            last = next;
+
+            if step % ARC_LENGTH_STEPS == 0 && entry + 1 < ARC_LENGTH_SAMPLES {
+                table[entry + 1] = table[entry];
+            }
+        }
+
+        self.arc_lengths = Some(table);
+        self
+    }
+
+    /// Returns the approximate length of the path, if this spline moves at a
+    /// [constant speed](Self::constant_speed).
+    pub fn length(&self) -> Option<f32> {
+        self.arc_lengths.map(|table| table[ARC_LENGTH_SAMPLES - 1])
+    }
+
+    /// The control points of this spline.
+    pub fn points(&self) -> &[Value] {
+        self.points.as_ref()
+    }
+
+    /// The tangents of this spline, if it was made with [hermite](Self::hermite).
+    pub fn tangents(&self) -> Option<&[Value]> {
+        self.tangents.as_ref().map(AsRef::as_ref)
+    }
+
+    /// Returns the point on the path at `percent`, where `0.0` is the first point and `1.0` is
+    /// the last. Percents outside of that range carry on along the end segments.
+    pub fn sample(&self, percent: f32) -> Value {
+        self.curve(self.reparameterize(percent))
+    }
+
+    /// Creates a [Tweener] which runs along this spline over `duration`.
+    pub fn into_tweener<Time: TweenTime>(self, duration: Time) -> Tweener<Value, Time, Self> {
+        let points = self.points.as_ref();
+        let (start, end) = (points[0].clone(), points[points.len() - 1].clone());
+
+        Tweener::new(start, end, duration, self)
+    }
+
+    /// Maps `percent` of the way along the path to the curve's own parameter.
+    fn reparameterize(&self, percent: f32) -> f32 {
+        let Some(table) = &self.arc_lengths else {
+            return percent;
+        };
+        let total = table[ARC_LENGTH_SAMPLES - 1];
+        if total <= 0.0 {
+            return percent;
+        }
+
+        let target = percent * total;
+        let index = table
+            .partition_point(|&length| length < target)
+            .clamp(1, ARC_LENGTH_SAMPLES - 1);
+        let (from, to) = (table[index - 1], table[index]);
+        let fraction = if to > from { (target - from) / (to - from) } else { 0.0 };
+
+        ((index - 1) as f32 + fraction) / (ARC_LENGTH_SAMPLES - 1) as f32
+    }
+
+    /// Evaluates the curve at `u`, spreading the segments evenly from `0.0` to `1.0`.
+    fn curve(&self, u: f32) -> Value {
+        let points = self.points.as_ref();
+        let segments = points.len() - 1;
+        if segments == 0 {
+            return points[0].clone();
+        }
+
+        let scaled = u * segments as f32;
+        let index = (scaled.max(0.0) as usize).min(segments - 1);
+        let t = scaled - index as f32;
+        let (t2, t3) = (t * t, t * t * t);
+
+        let (p0, p1) = (points[index].clone(), points[index + 1].clone());
+
+        // this is the Hermite basis, leaning on `h00 + h01 == 1` so integer values stay exact
+        p0.clone()
+            + (p1 - p0).scale(3.0 * t2 - 2.0 * t3)
+            + self.tangent(index).scale(t3 - 2.0 * t2 + t)
+            + self.tangent(index + 1).scale(t3 - t2)
+    }
+
+    /// The tangent at the point at `index`. For Catmull-Rom, the end points just face their
+    /// neighbour.
+    fn tangent(&self, index: usize) -> Value {
+        if let Some(tangents) = &self.tangents {
+            return tangents.as_ref()[index].clone();
+        }
+
+        let points = self.points.as_ref();
+        let prev = index.saturating_sub(1);
+        let next = (index + 1).min(points.len() - 1);
+
+        (points[next].clone() - points[prev].clone()).scale(1.0 / (next - prev) as f32)
+    }
+}
+
+impl<Value, S> Tween<Value> for Spline<Value, S>
+where
+    Value: TweenValue,
+    S: AsRef<[Value]>,
+{
+    #[inline]
+    fn tween(&mut self, _value_delta: Value, percent: f32) -> Value {
+        self.sample(percent) - self.points.as_ref()[0].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn passes_through_points() {
+        let spline = Spline::catmull_rom([0.0f32, 4.0, -2.0, 8.0, 8.0]);
+
+        for (i, point) in spline.points().iter().enumerate() {
+            assert_relative_eq!(spline.sample(i as f32 / 4.0), *point, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn zero_tangents_are_smoothstep() {
+        let spline = Spline::hermite([0.0f32, 10.0], [0.0, 0.0]);
+
+        assert_eq!(spline.sample(0.5), 5.0);
+        assert_relative_eq!(spline.sample(0.25), 10.0 * (3.0 * 0.0625 - 2.0 * 0.015625));
+    }
+
+    #[test]
+    fn integer_points() {
+        let spline = Spline::catmull_rom([0, 10, 20]);
+
+        assert_eq!(spline.sample(0.5), 10);
+        assert_eq!(spline.sample(1.0), 20);
+    }
+
+    #[test]
+    fn constant_speed_is_even() {
+        // the first segment is much shorter than the second
+        let spline = Spline::catmull_rom([0.0f32, 2.0, 10.0]).constant_speed();
+        assert_relative_eq!(spline.length().unwrap(), 10.0, epsilon = 0.01);
+
+        let mut last = spline.sample(0.0);
+        for i in 1..=20 {
+            let next = spline.sample(i as f32 / 20.0);
+            assert_relative_eq!(next - last, 0.5, epsilon = 0.05);
+            last = next;
+        }
+    }
+
+    #[test]
+    fn drives_a_tweener() {
+        let mut tweener = Spline::catmull_rom([5, 15, 10]).into_tweener(4);
+
+        assert_eq!(tweener.move_to(0), 5);
+        assert_eq!(tweener.move_to(2), 15);
+        assert_eq!(tweener.move_to(8), 10);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_path() {
+        use glam::Vec2;
+
+        let corners = [Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0)];
+        let spline = Spline::catmull_rom(corners).constant_speed();
+
+        assert_relative_eq!(spline.sample(0.5).distance(corners[1]), 0.0, epsilon = 0.5);
+        assert!(spline.length().unwrap() > 14.0);
+    }
+}
@@ -0,0 +1,564 @@
+use crate::math::float::{atan2, exp, floor, ln, sin_cos, sqrt};
+
+/// A damped spring, like the ones in iOS or react-spring, which gives natural motion to UI.
+///
+/// A spring starts at the beginning of a tween and is pulled towards the end. Depending on its
+/// `damping`, it either overshoots and wobbles around the end (underdamped), or eases into it
+/// without ever passing it (critically damped or overdamped). The motion is solved in closed form,
+/// so the spring can be moved to any time at all, in any order.
+///
+/// Springs don't have a duration, so they never stop moving entirely. Instead, the spring's time is
+/// measured in units of the [Tweener]'s `duration`: if the duration is `1.0` second, then
+/// `stiffness` and `damping` are per second. For the same reason, a Spring is not
+/// [finite](crate::Tween::is_finite), and is never clamped.
+///
+/// ```
+/// # use tween::{Spring, Tweener};
+/// let mut tweener = Tweener::new(0.0, 100.0, 1.0, Spring::new(170.0, 26.0));
+///
+/// // it's well on its way after 0.2 seconds...
+/// assert!(tweener.move_to(0.2) > 50.0);
+/// // and has basically settled after a second.
+/// assert!((tweener.move_to(1.0) - 100.0f32).abs() < 0.5);
+/// ```
+///
+/// [Tweener]: crate::Tweener
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spring {
+    /// How strongly the spring pulls towards its end. Higher values are faster.
+    pub stiffness: f32,
+
+    /// How strongly the spring resists motion. Lower values are bouncier.
+    pub damping: f32,
+
+    /// The velocity the spring starts with, as a fraction of the total distance per unit of time.
+    /// Positive values move towards the end.
+    pub initial_velocity: f32,
+}
+
+impl Default for Spring {
+    /// react-spring's default spring: a stiffness of `170` and a damping of `26`.
+    fn default() -> Self {
+        Self::new(170.0, 26.0)
+    }
+}
+
+impl Spring {
+    /// How close to its end a spring made with [from_settle_time](Self::from_settle_time) is once
+    /// it has settled, as a fraction of the total distance. This is the usual 2% band from control
+    /// theory.
+    pub const SETTLE_TOLERANCE: f32 = 0.02;
+
+    /// Creates a new Spring at rest, with the given stiffness and damping.
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            initial_velocity: 0.0,
+        }
+    }
+
+    /// Creates a new Spring at rest which overshoots its end by `overshoot`, as a fraction of the
+    /// total distance, and then settles within [SETTLE_TOLERANCE](Self::SETTLE_TOLERANCE) of its end
+    /// by `settle_time`. Like the spring's time, `settle_time` is in units of the [Tweener]'s
+    /// `duration`.
+    ///
+    /// An `overshoot` of `0.0` gives a critically damped spring, which eases in without passing its
+    /// end. `overshoot` is clamped to below `1.0`, since a spring which overshoots all the way back
+    /// to its start never settles.
+    ///
+    /// ```
+    /// # use tween::Spring;
+    /// // settle in 300ms with 10% overshoot, where the tweener's duration is in seconds
+    /// let spring = Spring::from_settle_time(0.3, 0.1);
+    ///
+    /// assert!((spring.overshoot() - 0.1).abs() < 1e-4);
+    /// assert!((spring.estimated_settle_time(Spring::SETTLE_TOLERANCE) - 0.3).abs() < 1e-4);
+    /// ```
+    ///
+    /// [Tweener]: crate::Tweener
+    pub fn from_settle_time(settle_time: f32, overshoot: f32) -> Self {
+        let overshoot = overshoot.clamp(0.0, 1.0 - f32::EPSILON);
+        let zeta = if overshoot <= 0.0 {
+            1.0
+        } else {
+            let log = ln(overshoot);
+            -log / sqrt(core::f32::consts::PI * core::f32::consts::PI + log * log)
+        };
+
+        // a spring at rest settles in a time inversely proportional to its natural frequency, so
+        // we can measure a spring with a frequency of one, and scale it to fit
+        let unit = Self::new(1.0, 2.0 * zeta).estimated_settle_time(Self::SETTLE_TOLERANCE);
+        let omega = unit / settle_time;
+
+        Self::new(omega * omega, 2.0 * zeta * omega)
+    }
+
+    /// Creates a new Spring from the frequency, damping ratio, and initial response used by
+    /// second-order procedural animation systems.
+    ///
+    /// - `frequency` is how many times per unit of time the spring would oscillate without any
+    ///   damping, and sets how quickly it responds.
+    /// - `damping_ratio` is the same as [damping_ratio](Self::damping_ratio): below `1.0` it
+    ///   overshoots, and at or above it, it doesn't.
+    /// - `response` is how sharply it starts. At `0.0` it starts at rest, above `0.0` it starts
+    ///   moving towards its end right away, and below `0.0` it winds up by moving away first.
+    ///
+    /// ```
+    /// # use tween::Spring;
+    /// let spring = Spring::from_response(2.0, 0.5, 0.0);
+    ///
+    /// assert!((spring.frequency() - 2.0).abs() < 1e-5);
+    /// assert_eq!(spring.damping_ratio(), 0.5);
+    /// assert_eq!(spring.initial_velocity, 0.0);
+    /// ```
+    pub fn from_response(frequency: f32, damping_ratio: f32, response: f32) -> Self {
+        let omega = core::f32::consts::TAU * frequency;
+
+        Self::new(omega * omega, 2.0 * damping_ratio * omega).with_velocity(response * damping_ratio * omega)
+    }
+
+    /// Sets the velocity the spring starts with.
+    pub fn with_velocity(mut self, initial_velocity: f32) -> Self {
+        self.initial_velocity = initial_velocity;
+        self
+    }
+
+    /// The damping ratio of the spring. Below `1.0`, the spring is underdamped and overshoots; at
+    /// `1.0` it's critically damped, and above it, it's overdamped.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * sqrt(self.stiffness))
+    }
+
+    /// How many times per unit of time the spring would oscillate if it had no damping.
+    pub fn frequency(&self) -> f32 {
+        sqrt(self.stiffness) / core::f32::consts::TAU
+    }
+
+    /// How far the spring overshoots its end when it starts at rest, as a fraction of the total
+    /// distance. This is `0.0` for springs which are critically damped or overdamped.
+    pub fn overshoot(&self) -> f32 {
+        let zeta = self.damping_ratio();
+        if zeta >= 1.0 {
+            return 0.0;
+        }
+
+        exp(-zeta * core::f32::consts::PI / sqrt(1.0 - zeta * zeta))
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.progress(percent))
+    }
+
+    /// Returns how far along the spring is at the given time, where `0.0` is the start and `1.0`
+    /// is the end. Underdamped springs will overshoot past `1.0`.
+    pub fn progress(&self, time: f32) -> f32 {
+        if time <= 0.0 {
+            return 0.0;
+        }
+
+        1.0 + self.displacement(time)
+    }
+
+    /// Returns how fast the spring is moving at the given time, as a fraction of the total distance
+    /// per unit of time. Positive values move towards the end.
+    pub fn velocity(&self, time: f32) -> f32 {
+        if time < 0.0 {
+            return 0.0;
+        }
+
+        let t = time;
+        let x0 = -1.0;
+        let v0 = self.initial_velocity;
+        let omega = sqrt(self.stiffness);
+        let zeta = self.damping_ratio();
+
+        if zeta < 1.0 {
+            let omega_d = omega * sqrt(1.0 - zeta * zeta);
+            let b = (v0 + zeta * omega * x0) / omega_d;
+            let (sin, cos) = sin_cos(omega_d * t);
+            let decay = exp(-zeta * omega * t);
+
+            -zeta * omega * decay * (x0 * cos + b * sin) + decay * omega_d * (b * cos - x0 * sin)
+        } else if zeta == 1.0 {
+            let b = v0 + omega * x0;
+            let decay = exp(-omega * t);
+
+            -omega * decay * (x0 + b * t) + decay * b
+        } else {
+            let root = omega * sqrt(zeta * zeta - 1.0);
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c1 = (v0 - r2 * x0) / (r1 - r2);
+            let c2 = x0 - c1;
+
+            c1 * r1 * exp(r1 * t) + c2 * r2 * exp(r2 * t)
+        }
+    }
+
+    /// Returns how many times the spring has crossed its end by the given time.
+    ///
+    /// Each crossing is an overshoot, or a swing back from one, so checking when this changes
+    /// between frames tells you exactly when to play a "thud". Only underdamped springs cross more
+    /// than once; the others only cross at all if they're given enough initial velocity.
+    ///
+    /// ```
+    /// # use tween::Spring;
+    /// let spring = Spring::new(100.0, 5.0);
+    ///
+    /// assert_eq!(spring.crossings(0.0), 0);
+    /// assert!(spring.progress(0.2) > 1.0);
+    /// assert_eq!(spring.crossings(0.2), 1);
+    /// ```
+    pub fn crossings(&self, time: f32) -> u32 {
+        if time <= 0.0 {
+            return 0;
+        }
+
+        let x0 = -1.0;
+        let omega = sqrt(self.stiffness);
+        let zeta = self.damping_ratio();
+
+        if zeta < 1.0 {
+            // the motion is `exp(-zeta * omega * t) * r * cos(omega_d * t - phase)`, which crosses
+            // zero every half period
+            let omega_d = omega * sqrt(1.0 - zeta * zeta);
+            let b = (self.initial_velocity + zeta * omega * x0) / omega_d;
+            let phase = atan2(b, x0) + core::f32::consts::FRAC_PI_2;
+
+            let first = floor(-phase / core::f32::consts::PI) + 1.0;
+            let last = floor((omega_d * time - phase) / core::f32::consts::PI);
+
+            (last - first + 1.0).max(0.0) as u32
+        } else {
+            // these cross at most once, and never come back
+            u32::from(self.displacement(time) > 0.0)
+        }
+    }
+
+    /// Returns the energy left in the spring at the given time, from both its motion and its
+    /// stretch, as though it had unit mass and the total distance was `1.0`. It starts at
+    /// `stiffness / 2` (plus any energy from its initial velocity) and decays towards `0.0`.
+    pub fn energy(&self, time: f32) -> f32 {
+        let velocity = self.velocity(time);
+        let displacement = self.displacement(time.max(0.0));
+
+        0.5 * (velocity * velocity + self.stiffness * displacement * displacement)
+    }
+
+    /// Estimates how long the spring takes to settle, after which it always stays within `epsilon`
+    /// of its end, where `epsilon` is a fraction of the total distance. Like the spring's time, this
+    /// is in units of the [Tweener]'s `duration`.
+    ///
+    /// Springs are never [finished](crate::Tweener::is_finished), so use this to decide when one
+    /// is done enough. The estimate is conservative, since it follows the envelope of the motion
+    /// rather than every wobble. A spring without any damping never settles, and gives
+    /// [f32::INFINITY].
+    ///
+    /// ```
+    /// # use tween::Spring;
+    /// let spring = Spring::new(170.0, 26.0);
+    /// let settled = spring.estimated_settle_time(0.001);
+    ///
+    /// assert!((spring.progress(settled) - 1.0).abs() <= 0.001);
+    /// ```
+    ///
+    /// [Tweener]: crate::Tweener
+    pub fn estimated_settle_time(&self, epsilon: f32) -> f32 {
+        let x0: f32 = -1.0;
+        let v0 = self.initial_velocity;
+        let omega = sqrt(self.stiffness);
+        let zeta = self.damping_ratio();
+
+        // each regime's displacement is bounded by an envelope, `amplitude * exp(-rate * t)`,
+        // except for critical damping, which has a linear term we have to search past
+        let (amplitude, rate) = if zeta < 1.0 {
+            let omega_d = omega * sqrt(1.0 - zeta * zeta);
+            let b = (v0 + zeta * omega * x0) / omega_d;
+
+            (sqrt(x0 * x0 + b * b), zeta * omega)
+        } else if zeta == 1.0 {
+            let b = v0 + omega * x0;
+            let envelope = |t: f32| (x0.abs() + b.abs() * t) * exp(-omega * t);
+
+            return Self::search_settled(envelope, epsilon, 1.0 / omega);
+        } else {
+            let root = omega * sqrt(zeta * zeta - 1.0);
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c1 = (v0 - r2 * x0) / (r1 - r2);
+            let c2 = x0 - c1;
+
+            (c1.abs() + c2.abs(), -r1)
+        };
+
+        if amplitude <= epsilon {
+            0.0
+        } else if rate <= 0.0 {
+            f32::INFINITY
+        } else {
+            ln(amplitude / epsilon) / rate
+        }
+    }
+
+    /// Finds the time after which a decaying `envelope` stays below `epsilon`.
+    fn search_settled(envelope: impl Fn(f32) -> f32, epsilon: f32, step: f32) -> f32 {
+        if envelope(0.0) <= epsilon {
+            return 0.0;
+        }
+        if !step.is_finite() {
+            return f32::INFINITY;
+        }
+
+        let mut hi = step;
+        while envelope(hi) > epsilon {
+            hi *= 2.0;
+        }
+
+        let mut lo = hi / 2.0;
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
+            if envelope(mid) > epsilon {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        hi
+    }
+
+    /// The offset from the end, which starts at `-1.0` and decays towards `0.0`.
+    fn displacement(&self, t: f32) -> f32 {
+        let x0 = -1.0;
+        let v0 = self.initial_velocity;
+        let omega = sqrt(self.stiffness);
+        let zeta = self.damping_ratio();
+
+        if zeta < 1.0 {
+            let omega_d = omega * sqrt(1.0 - zeta * zeta);
+            let (sin, cos) = sin_cos(omega_d * t);
+
+            exp(-zeta * omega * t) * (x0 * cos + (v0 + zeta * omega * x0) / omega_d * sin)
+        } else if zeta == 1.0 {
+            exp(-omega * t) * (x0 + (v0 + omega * x0) * t)
+        } else {
+            let root = omega * sqrt(zeta * zeta - 1.0);
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c1 = (v0 - r2 * x0) / (r1 - r2);
+            let c2 = x0 - c1;
+
+            c1 * exp(r1 * t) + c2 * exp(r2 * t)
+        }
+    }
+}
+
+impl_tween_ops!(Spring);
+
+impl<Value> crate::Tween<Value> for Spring
+where
+    Value: crate::TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+
+    fn is_finite(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Tweener};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn starts_and_settles() {
+        for spring in [
+            Spring::new(100.0, 5.0),
+            Spring::new(100.0, 20.0),
+            Spring::new(100.0, 50.0),
+        ] {
+            assert_eq!(spring.progress(0.0), 0.0);
+            assert_relative_eq!(spring.progress(10.0), 1.0, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn damping_regimes() {
+        let under = Spring::new(100.0, 5.0);
+        let critical = Spring::new(100.0, 20.0);
+        let over = Spring::new(100.0, 50.0);
+
+        assert!(under.damping_ratio() < 1.0);
+        assert_eq!(critical.damping_ratio(), 1.0);
+        assert!(over.damping_ratio() > 1.0);
+
+        let overshoots = |spring: Spring| (1..1000).any(|i| spring.progress(i as f32 / 100.0) > 1.0);
+        assert!(overshoots(under));
+        assert!(!overshoots(critical));
+        assert!(!overshoots(over));
+    }
+
+    #[test]
+    fn continuous_between_regimes() {
+        // tiny changes in damping around critical shouldn't change the curve much
+        let a = Spring::new(100.0, 19.99);
+        let b = Spring::new(100.0, 20.0);
+        let c = Spring::new(100.0, 20.01);
+
+        for i in 0..100 {
+            let t = i as f32 / 50.0;
+            assert_relative_eq!(a.progress(t), b.progress(t), epsilon = 1e-3);
+            assert_relative_eq!(c.progress(t), b.progress(t), epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn initial_velocity() {
+        let rest = Spring::new(100.0, 20.0);
+        let pushed = rest.with_velocity(10.0);
+
+        assert!(pushed.progress(0.05) > rest.progress(0.05));
+    }
+
+    #[test]
+    fn settle_time() {
+        for spring in [
+            Spring::new(100.0, 5.0),
+            Spring::new(100.0, 20.0),
+            Spring::new(100.0, 50.0),
+            Spring::new(100.0, 20.0).with_velocity(-5.0),
+        ] {
+            let settled = spring.estimated_settle_time(0.01);
+            assert!(settled.is_finite() && settled > 0.0);
+
+            for i in 0..100 {
+                let t = settled + i as f32 / 10.0;
+                assert!((spring.progress(t) - 1.0).abs() <= 0.01);
+            }
+        }
+
+        assert_eq!(Spring::new(100.0, 0.0).estimated_settle_time(0.01), f32::INFINITY);
+        assert!(
+            Spring::new(100.0, 5.0).estimated_settle_time(0.1) < Spring::new(100.0, 5.0).estimated_settle_time(0.01)
+        );
+    }
+
+    #[test]
+    fn velocity_matches_progress() {
+        for spring in [
+            Spring::new(100.0, 5.0),
+            Spring::new(100.0, 20.0),
+            Spring::new(100.0, 50.0).with_velocity(3.0),
+        ] {
+            assert_relative_eq!(spring.velocity(0.0), spring.initial_velocity, epsilon = 1e-5);
+
+            for i in 1..50 {
+                let t = i as f32 / 50.0;
+                let h = 1e-3;
+                let numeric = (spring.progress(t + h) - spring.progress(t - h)) / (2.0 * h);
+
+                assert_relative_eq!(spring.velocity(t), numeric, epsilon = 2e-2, max_relative = 2e-2);
+            }
+        }
+    }
+
+    #[test]
+    fn crossings_match_progress() {
+        for spring in [
+            Spring::new(100.0, 5.0),
+            Spring::new(200.0, 3.0).with_velocity(-4.0),
+            Spring::new(100.0, 20.0),
+            Spring::new(100.0, 20.0).with_velocity(40.0),
+            Spring::new(100.0, 50.0).with_velocity(80.0),
+        ] {
+            let mut crossings = 0;
+            let mut last = spring.progress(0.0) - 1.0;
+
+            for i in 1..400 {
+                let t = i as f32 / 200.0;
+                let now = spring.progress(t) - 1.0;
+                if (last < 0.0) != (now < 0.0) {
+                    crossings += 1;
+                }
+                last = now;
+
+                assert_eq!(spring.crossings(t), crossings, "{:?} at {}", spring, t);
+            }
+        }
+    }
+
+    #[test]
+    fn energy_decays() {
+        let spring = Spring::new(100.0, 5.0);
+        assert_eq!(spring.energy(0.0), 50.0);
+
+        let mut last = spring.energy(0.0);
+        for i in 1..100 {
+            let energy = spring.energy(i as f32 / 20.0);
+            assert!(energy <= last);
+            last = energy;
+        }
+        assert!(last < 1e-3);
+    }
+
+    #[test]
+    fn from_settle_time() {
+        for (settle_time, overshoot) in [(0.3, 0.1), (1.0, 0.0), (2.0, 0.5), (0.05, 0.01)] {
+            let spring = Spring::from_settle_time(settle_time, overshoot);
+            let peak = (1..2000)
+                .map(|i| spring.progress(settle_time * i as f32 / 500.0))
+                .fold(0.0f32, f32::max);
+
+            assert_relative_eq!(spring.overshoot(), overshoot, epsilon = 1e-4);
+            assert_relative_eq!(peak, 1.0 + overshoot, epsilon = 1e-3);
+            assert_relative_eq!(
+                spring.estimated_settle_time(Spring::SETTLE_TOLERANCE),
+                settle_time,
+                max_relative = 1e-3
+            );
+
+            for i in 0..100 {
+                let t = settle_time * (1.0 + i as f32 / 10.0);
+                assert!((spring.progress(t) - 1.0).abs() <= Spring::SETTLE_TOLERANCE);
+            }
+        }
+
+        // way too much overshoot still settles, eventually
+        assert!(
+            Spring::from_settle_time(1.0, 2.0)
+                .estimated_settle_time(0.02)
+                .is_finite()
+        );
+    }
+
+    #[test]
+    fn from_response() {
+        let at_rest = Spring::from_response(1.0, 1.0, 0.0);
+        let sharp = Spring::from_response(1.0, 1.0, 2.0);
+        let wind_up = Spring::from_response(1.0, 1.0, -1.0);
+
+        assert_relative_eq!(at_rest.frequency(), 1.0);
+        assert_relative_eq!(at_rest.damping_ratio(), 1.0);
+        assert!(sharp.progress(0.05) > at_rest.progress(0.05));
+        assert!(wind_up.progress(0.01) < 0.0);
+    }
+
+    #[test]
+    fn drivers() {
+        let mut tweener = Tweener::new(0.0, 10.0, 1.0, Spring::default());
+        let mut fixed = FixedTweener::new(0.0, 10.0, 1.0, Spring::default(), 0.5);
+
+        assert_eq!(tweener.move_by(0.5), fixed.next().unwrap());
+        assert_eq!(tweener.move_by(0.5), fixed.next().unwrap());
+        assert!(!tweener.is_finished());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::math::float::floor;
+
+/// Where the jumps of a [Steps] tween happen, matching CSS's `<step-position>`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum StepPosition {
+    /// The first jump happens right at the start, so the tween never sits at its start value.
+    /// This is CSS's `jump-start`, or `start`.
+    JumpStart,
+
+    /// The last jump happens right at the end, so the tween only reaches its end value once it's
+    /// over. This is CSS's `jump-end`, or `end`, and is the default.
+    #[default]
+    JumpEnd,
+
+    /// There are no jumps at either end, so the tween spends a whole step at both its start and
+    /// end values. This is CSS's `jump-none`.
+    JumpNone,
+
+    /// There are jumps at both ends, so the tween never sits at its start or end values. This is
+    /// CSS's `jump-both`.
+    JumpBoth,
+}
+
+/// A tween which moves in `steps` discrete jumps instead of smoothly, with the exact semantics of
+/// CSS's `steps()`.
+///
+/// This is handy for stepping through the frames of a sprite sheet, or for a retro look where
+/// values snap rather than glide.
+///
+/// ```
+/// # use tween::{StepPosition, Steps, Tweener};
+/// // `steps(4, jump-end)`, for a four frame animation
+/// let mut frame = Tweener::new(0, 4, 8, Steps::new(4, StepPosition::JumpEnd));
+///
+/// assert_eq!(frame.move_to(1), 0);
+/// assert_eq!(frame.move_to(2), 1);
+/// assert_eq!(frame.move_to(7), 3);
+/// assert_eq!(frame.move_to(8), 4);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Steps {
+    steps: u32,
+    position: StepPosition,
+}
+
+impl Steps {
+    /// Creates a new Steps tween, in the same order as CSS's `steps(steps, position)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `steps` is `0`, or if `steps` is `1` with [StepPosition::JumpNone], since CSS
+    /// rejects those too.
+    pub fn new(steps: u32, position: StepPosition) -> Self {
+        assert!(steps > 0, "steps must be at least 1");
+        assert!(
+            steps > 1 || position != StepPosition::JumpNone,
+            "steps must be at least 2 with `JumpNone`"
+        );
+
+        Self { steps, position }
+    }
+
+    /// The number of steps, which CSS calls the number of intervals.
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    /// Where the jumps happen.
+    pub fn position(&self) -> StepPosition {
+        self.position
+    }
+
+    /// The number of jumps the tween makes, which is `steps` adjusted for the [StepPosition].
+    pub fn jumps(&self) -> u32 {
+        match self.position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => self.steps,
+            StepPosition::JumpNone => self.steps - 1,
+            StepPosition::JumpBoth => self.steps + 1,
+        }
+    }
+
+    /// Returns which step `percent` is on, as CSS calculates it.
+    fn current_step(&self, percent: f32) -> f32 {
+        let mut step = floor(percent * self.steps as f32);
+
+        if matches!(self.position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+            step += 1.0;
+        }
+
+        if percent >= 0.0 && step < 0.0 {
+            step = 0.0;
+        }
+
+        step.min(self.jumps() as f32)
+    }
+}
+
+impl_tween_ops!(Steps);
+
+impl<Value: crate::TweenValue> crate::Tween<Value> for Steps {
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let scalar = self.current_step(percent) / self.jumps() as f32;
+
+        value_delta.scale(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tween;
+
+    fn sample(steps: u32, position: StepPosition) -> [f32; 5] {
+        let mut tween = Steps::new(steps, position);
+        [0.0, 0.25, 0.5, 0.75, 1.0].map(|percent| tween.tween(1.0, percent))
+    }
+
+    #[test]
+    fn css_positions() {
+        assert_eq!(sample(2, StepPosition::JumpEnd), [0.0, 0.0, 0.5, 0.5, 1.0]);
+        assert_eq!(sample(2, StepPosition::JumpStart), [0.5, 0.5, 1.0, 1.0, 1.0]);
+        assert_eq!(sample(2, StepPosition::JumpNone), [0.0, 0.0, 1.0, 1.0, 1.0]);
+
+        let third = 1.0 / 3.0;
+        assert_eq!(
+            sample(2, StepPosition::JumpBoth),
+            [third, third, third * 2.0, third * 2.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn sprite_frames() {
+        let mut tween = Steps::new(8, StepPosition::JumpEnd);
+
+        for frame in 0..8u32 {
+            let percent = (frame as f32 + 0.5) / 8.0;
+            assert_eq!(tween.tween(8, percent), frame);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_jump_none() {
+        Steps::new(1, StepPosition::JumpNone);
+    }
+}
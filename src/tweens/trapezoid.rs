@@ -0,0 +1,244 @@
+use crate::{Tween, TweenLength, TweenValue, Tweener, math::float::sqrt};
+
+/// A trapezoidal motion profile, the standard way to drive steppers and servos.
+///
+/// The motion accelerates at `max_acceleration` until it reaches `max_velocity`, cruises, and then
+/// decelerates at the same rate to stop exactly at the end. Plotted as velocity over time, that's a
+/// trapezoid. Moves too short to ever reach `max_velocity` fall back to a triangular profile, which
+/// starts decelerating as soon as it stops accelerating.
+///
+/// Unlike the easings, the shape of the profile depends on how far it moves, so it's made for a
+/// particular `distance`, in the same units as the velocity and acceleration. Its time is in the
+/// matching units too, which means it should run over its own [duration](Self::duration) to move
+/// at the speeds it was given. [tweener](Self::tweener) does that for you.
+///
+/// ```
+/// # use tween::TrapezoidProfile;
+/// // 10mm at up to 2mm/s, accelerating at 1mm/s²
+/// let mut tweener = TrapezoidProfile::tweener(0.0f32, 10.0, 2.0, 1.0);
+/// assert_eq!(tweener.duration, 7.0);
+///
+/// assert_eq!(tweener.move_to(2.0), 2.0); // done accelerating
+/// assert_eq!(tweener.move_to(5.0), 8.0); // done cruising
+/// assert_eq!(tweener.move_to(7.0), 10.0);
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrapezoidProfile {
+    /// How far the profile moves. Negative distances are treated as positive, since the direction
+    /// comes from the tween itself.
+    pub distance: f32,
+
+    /// The fastest the profile may move.
+    pub max_velocity: f32,
+
+    /// The fastest the profile may speed up or slow down.
+    pub max_acceleration: f32,
+}
+
+impl TrapezoidProfile {
+    /// Creates a new TrapezoidProfile, moving `distance` without going over `max_velocity` or
+    /// `max_acceleration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_velocity` or `max_acceleration` isn't positive.
+    pub fn new(distance: f32, max_velocity: f32, max_acceleration: f32) -> Self {
+        assert!(max_velocity > 0.0, "a motion profile's max velocity must be positive");
+        assert!(
+            max_acceleration > 0.0,
+            "a motion profile's max acceleration must be positive"
+        );
+
+        Self {
+            distance,
+            max_velocity,
+            max_acceleration,
+        }
+    }
+
+    /// Creates a [Tweener] which moves from `start` to `end` with a profile made for the distance
+    /// between them, running over the profile's [duration](Self::duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_velocity` or `max_acceleration` isn't positive.
+    pub fn tweener<Value: TweenLength>(
+        start: Value,
+        end: Value,
+        max_velocity: f32,
+        max_acceleration: f32,
+    ) -> Tweener<Value, f32, Self> {
+        let distance = (end.clone() - start.clone()).length();
+        let profile = Self::new(distance, max_velocity, max_acceleration);
+
+        Tweener::new(start, end, profile.duration(), profile)
+    }
+
+    /// Returns `true` if the move is too short to reach `max_velocity`, so the profile is a
+    /// triangle rather than a trapezoid.
+    pub fn is_triangular(&self) -> bool {
+        self.distance.abs() * self.max_acceleration < self.max_velocity * self.max_velocity
+    }
+
+    /// The fastest the profile actually moves. This is `max_velocity`, unless the profile is
+    /// [triangular](Self::is_triangular).
+    pub fn peak_velocity(&self) -> f32 {
+        if self.is_triangular() {
+            sqrt(self.distance.abs() * self.max_acceleration)
+        } else {
+            self.max_velocity
+        }
+    }
+
+    /// How long the profile spends speeding up. It spends just as long slowing down at the end.
+    pub fn acceleration_duration(&self) -> f32 {
+        self.peak_velocity() / self.max_acceleration
+    }
+
+    /// How long the profile spends at `max_velocity`. This is `0.0` for
+    /// [triangular](Self::is_triangular) profiles.
+    pub fn cruise_duration(&self) -> f32 {
+        if self.is_triangular() {
+            0.0
+        } else {
+            self.distance.abs() / self.max_velocity - self.max_velocity / self.max_acceleration
+        }
+    }
+
+    /// How long the whole move takes.
+    pub fn duration(&self) -> f32 {
+        2.0 * self.acceleration_duration() + self.cruise_duration()
+    }
+
+    /// Returns how far the profile has moved at `time`, from `0.0` to its `distance`. Times outside
+    /// of the profile's [duration](Self::duration) are clamped.
+    pub fn position(&self, time: f32) -> f32 {
+        let distance = self.distance.abs();
+        let (ramp, cruise, peak) = (
+            self.acceleration_duration(),
+            self.cruise_duration(),
+            self.peak_velocity(),
+        );
+        let duration = 2.0 * ramp + cruise;
+        let time = time.clamp(0.0, duration);
+
+        if time < ramp {
+            0.5 * self.max_acceleration * time * time
+        } else if time < ramp + cruise {
+            0.5 * peak * ramp + peak * (time - ramp)
+        } else {
+            let left = duration - time;
+            distance - 0.5 * self.max_acceleration * left * left
+        }
+    }
+
+    /// Returns how fast the profile is moving at `time`. This is `0.0` outside of its
+    /// [duration](Self::duration).
+    pub fn velocity(&self, time: f32) -> f32 {
+        let (ramp, cruise, peak) = (
+            self.acceleration_duration(),
+            self.cruise_duration(),
+            self.peak_velocity(),
+        );
+        let duration = 2.0 * ramp + cruise;
+
+        if time <= 0.0 || time >= duration {
+            0.0
+        } else if time < ramp {
+            self.max_acceleration * time
+        } else if time < ramp + cruise {
+            peak
+        } else {
+            self.max_acceleration * (duration - time)
+        }
+    }
+
+    /// Returns how far along the profile is at `percent` of its duration, from `0.0` to `1.0`. A
+    /// profile which doesn't move at all is always at `1.0`, since it's done before it starts.
+    pub fn progress(&self, percent: f32) -> f32 {
+        let distance = self.distance.abs();
+        if distance == 0.0 {
+            return 1.0;
+        }
+
+        self.position(percent * self.duration()) / distance
+    }
+}
+
+impl<Value: TweenValue> Tween<Value> for TrapezoidProfile {
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.progress(percent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn phases() {
+        let profile = TrapezoidProfile::new(10.0, 2.0, 1.0);
+
+        assert!(!profile.is_triangular());
+        assert_eq!(profile.acceleration_duration(), 2.0);
+        assert_eq!(profile.cruise_duration(), 3.0);
+        assert_eq!(profile.duration(), 7.0);
+
+        assert_eq!(profile.position(2.0), 2.0);
+        assert_eq!(profile.position(3.5), 5.0);
+        assert_eq!(profile.position(6.0), 9.5);
+        assert_eq!(profile.velocity(1.0), 1.0);
+        assert_eq!(profile.velocity(4.0), 2.0);
+        assert_eq!(profile.velocity(8.0), 0.0);
+    }
+
+    #[test]
+    fn triangular_fallback() {
+        let profile = TrapezoidProfile::new(1.0, 2.0, 1.0);
+
+        assert!(profile.is_triangular());
+        assert_eq!(profile.peak_velocity(), 1.0);
+        assert_eq!(profile.cruise_duration(), 0.0);
+        assert_eq!(profile.duration(), 2.0);
+        assert_eq!(profile.progress(0.5), 0.5);
+    }
+
+    #[test]
+    fn stays_within_limits() {
+        for profile in [
+            TrapezoidProfile::new(10.0, 2.0, 1.0),
+            TrapezoidProfile::new(-3.0, 4.0, 0.5),
+            TrapezoidProfile::new(0.2, 1.0, 3.0),
+        ] {
+            let duration = profile.duration();
+            let dt = duration / 200.0;
+            let mut last = 0.0;
+
+            for i in 1..=200 {
+                let position = profile.position(i as f32 * dt);
+                let velocity = (position - last) / dt;
+
+                assert!(position >= last);
+                assert!(velocity <= profile.max_velocity * 1.001);
+                last = position;
+            }
+            assert_relative_eq!(last, profile.distance.abs(), max_relative = 1e-5);
+        }
+    }
+
+    #[test]
+    fn tweens_any_direction() {
+        let mut tweener = TrapezoidProfile::tweener(10.0, 0.0, 2.0, 1.0);
+
+        assert_eq!(tweener.duration, 7.0);
+        assert_eq!(tweener.move_to(3.5), 5.0);
+        assert_eq!(tweener.move_to(7.0), 0.0);
+
+        let mut still = TrapezoidProfile::tweener(4.0, 4.0, 2.0, 1.0);
+        assert_eq!(still.move_to(0.0), 4.0);
+        assert_eq!(still.duration, 0.0);
+    }
+}
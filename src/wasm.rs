@@ -0,0 +1,119 @@
+use crate::{Easing, ParseEasingError, Tweener};
+use std::{string::String, vec::Vec};
+use wasm_bindgen::prelude::*;
+
+/// A small, JS-friendly [Tweener] for web apps which embed Rust through `wasm-bindgen`, so the
+/// page can use the exact same curves as the Rust side.
+///
+/// In JS this is exported as `Tweener`. Easings are picked by the same names [Easing] parses, like
+/// `"sine_in_out"`, and time is given in milliseconds:
+///
+/// ```js
+/// const fade = new Tweener("quad_out", 0, 1, 250);
+///
+/// function frame(deltaMs) {
+///     element.style.opacity = fade.advance(deltaMs);
+///     if (!fade.isFinished()) requestAnimationFrame(frame);
+/// }
+/// ```
+#[wasm_bindgen(js_name = Tweener)]
+#[derive(Debug, Clone)]
+pub struct JsTweener {
+    tweener: Tweener<f32, f32, Easing>,
+    value: f32,
+}
+
+#[wasm_bindgen(js_class = Tweener)]
+impl JsTweener {
+    /// Creates a new tweener from `start` to `end` over `duration_ms`, using the easing called
+    /// `easing`. Throws if there's no easing with that name.
+    #[wasm_bindgen(constructor)]
+    pub fn new(easing: &str, start: f32, end: f32, duration_ms: f32) -> Result<JsTweener, JsError> {
+        Self::from_name(easing, start, end, duration_ms).map_err(|e| JsError::new(&format!("{e}: `{easing}`")))
+    }
+
+    /// Moves the tweener forward by `delta_ms`, returning the new value.
+    pub fn advance(&mut self, delta_ms: f32) -> f32 {
+        self.value = self.tweener.move_by(delta_ms);
+        self.value
+    }
+
+    /// Moves the tweener to `time_ms`, returning the new value.
+    pub fn seek(&mut self, time_ms: f32) -> f32 {
+        self.value = self.tweener.move_to(time_ms);
+        self.value
+    }
+
+    /// The value the tweener was last moved to.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// How far into the tween we are, in milliseconds.
+    #[wasm_bindgen(getter, js_name = currentTime)]
+    pub fn current_time(&self) -> f32 {
+        self.tweener.current_time
+    }
+
+    /// How long the tween lasts, in milliseconds.
+    #[wasm_bindgen(getter)]
+    pub fn duration(&self) -> f32 {
+        self.tweener.duration
+    }
+
+    /// Returns `true` once the tween has reached its end.
+    #[wasm_bindgen(js_name = isFinished)]
+    pub fn is_finished(&self) -> bool {
+        self.tweener.is_finished()
+    }
+}
+
+impl JsTweener {
+    /// Creates a new JsTweener, without going through JS's error types.
+    pub fn from_name(easing: &str, start: f32, end: f32, duration_ms: f32) -> Result<Self, ParseEasingError> {
+        let easing: Easing = easing.parse()?;
+
+        Ok(Self {
+            tweener: Tweener::new(start, end, duration_ms, easing),
+            value: start,
+        })
+    }
+}
+
+/// Returns the name of every easing which [JsTweener] accepts.
+#[wasm_bindgen(js_name = easingNames)]
+pub fn easing_names() -> Vec<String> {
+    Easing::ALL
+        .iter()
+        .filter_map(|easing| easing.name())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_tweener() {
+        let mut js = JsTweener::from_name("quad_in", 0.0, 100.0, 1000.0).unwrap();
+        let mut rust = Tweener::quad_in(0.0, 100.0, 1000.0);
+
+        assert_eq!(js.value(), 0.0);
+        for _ in 0..10 {
+            assert_eq!(js.advance(125.0), rust.move_by(125.0));
+        }
+        assert!(js.is_finished());
+        assert_eq!(js.value(), 100.0);
+    }
+
+    #[test]
+    fn unknown_easing() {
+        assert_eq!(
+            JsTweener::from_name("wobbly", 0.0, 1.0, 1.0).unwrap_err(),
+            ParseEasingError
+        );
+        assert!(easing_names().iter().any(|name| name == "sine_in_out"));
+    }
+}
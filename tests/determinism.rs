@@ -0,0 +1,80 @@
+//! Checks that every way of driving a tweener lands on exactly the same values, frame for frame,
+//! for every built-in easing, so users can switch drivers without anything looking different.
+
+use core::time::Duration;
+use tween::{CubicBezier, Easing, FixedTweener, StepPosition, Steps, Tween, TweenTime, TweenValue, Tweener};
+
+/// Every built-in easing, including a couple of the parameterized ones.
+fn easings() -> impl Iterator<Item = Easing> {
+    Easing::ALL.iter().copied().chain([
+        Easing::CubicBezier(CubicBezier::EASE_IN_OUT),
+        Easing::Steps(Steps::new(5, StepPosition::JumpEnd)),
+    ])
+}
+
+/// Steps `frames` frames of `delta` through a [FixedTweener], a [Tweener] fed deltas, and a
+/// [Tweener] moved to each frame's absolute time, asserting they agree exactly on every frame.
+///
+/// Absolute times are built by adding up deltas, as a game's clock would, so this only promises
+/// identical results where that sum is exact, like for integer frames or power of two fractions.
+fn assert_drivers_agree<Value, Time>(start: Value, end: Value, duration: Time, delta: Time, frames: usize)
+where
+    Value: TweenValue + PartialEq + core::fmt::Debug,
+    Time: TweenTime + core::fmt::Debug,
+    Easing: Tween<Value>,
+{
+    for easing in easings() {
+        let mut fixed = FixedTweener::new(start.clone(), end.clone(), duration, easing, delta);
+        let mut fed = Tweener::new(start.clone(), end.clone(), duration, easing);
+        let mut seeked = Tweener::new(start.clone(), end.clone(), duration, easing);
+        let mut clock = Time::ZERO;
+
+        for frame in 1..=frames {
+            clock += delta;
+
+            let stepped = fixed.move_next();
+            let driven = fed.move_by(delta);
+            let moved = seeked.move_to(clock);
+
+            assert_eq!(stepped, driven, "{easing:?} split on frame {frame}");
+            assert_eq!(stepped, moved, "{easing:?} split on frame {frame}");
+        }
+
+        assert_eq!(fixed.move_next(), end, "{easing:?} didn't land");
+        assert_eq!(fed.move_by(delta), end, "{easing:?} didn't land");
+    }
+}
+
+#[test]
+fn integer_frames() {
+    assert_drivers_agree(0.0f32, 100.0, 60u32, 1, 60);
+    assert_drivers_agree(-50i32, 1000, 7u32, 1, 7);
+    assert_drivers_agree(0.0f64, 1.0, 240u64, 3, 80);
+}
+
+#[test]
+fn float_seconds() {
+    // an eighth of a second adds up exactly, so the clock never drifts from the deltas
+    assert_drivers_agree(0.0f32, 100.0, 4.0f32, 0.125, 32);
+    assert_drivers_agree(10.0f64, -10.0, 0.5f64, 0.015625, 32);
+}
+
+#[test]
+fn durations() {
+    assert_drivers_agree(0.0f32, 1.0, Duration::from_millis(500), Duration::from_millis(10), 50);
+}
+
+#[test]
+fn uneven_deltas_land_together() {
+    // a stuttering game loop should still end up where one long frame would have put it
+    let deltas = [1u32, 3, 2, 7, 1, 1, 5, 4];
+    let total: u32 = deltas.iter().sum();
+
+    for easing in easings() {
+        let mut fed = Tweener::new(0.0f32, 100.0, 30, easing);
+        let mut seeked = fed;
+
+        let driven = deltas.iter().map(|&delta| fed.move_by(delta)).last().unwrap();
+        assert_eq!(driven, seeked.move_to(total), "{easing:?}");
+    }
+}
@@ -0,0 +1,108 @@
+//! Checks that the core of the library never allocates, as promised in the README.
+#![cfg(feature = "std")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+use tween::{CubicBezier, Easing, FixedTweener, Linear, Looper, Oscillator, StepPosition, Steps, Tween, Tweener};
+
+/// Counts allocations made on the current thread, so tests running in parallel don't count each
+/// other's.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[allow(unsafe_code)]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs `f`, and panics if it allocated.
+fn assert_no_alloc<R>(name: &str, f: impl FnOnce() -> R) -> R {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let allocated = ALLOCATIONS.with(Cell::get) - before;
+
+    assert_eq!(allocated, 0, "{name} allocated {allocated} times");
+    result
+}
+
+fn run<T: Tween<f32> + Clone>(name: &str, tween: T) {
+    assert_no_alloc(name, || {
+        let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, tween.clone());
+        for i in 0..=20 {
+            tweener.move_to(i as f32 / 20.0);
+            tweener.move_by(0.01);
+        }
+
+        let mut fixed = FixedTweener::new(0.0f32, 100.0, 10, tween.clone(), 1);
+        for value in fixed.by_ref() {
+            core::hint::black_box(value);
+        }
+        fixed.move_next();
+    });
+}
+
+#[test]
+fn tweeners_and_tweens() {
+    run("Linear", Linear);
+    #[cfg(feature = "quad")]
+    run("QuadInOut", tween::QuadInOut);
+    #[cfg(feature = "sine")]
+    run("SineInOut", tween::SineInOut);
+    #[cfg(feature = "back")]
+    run("BackInOut", tween::BackInOut);
+    #[cfg(feature = "bounce")]
+    run("BounceOut", tween::BounceOut);
+    #[cfg(feature = "elastic")]
+    run("ElasticInOut", tween::ElasticInOut);
+    run("CubicBezier", CubicBezier::EASE_IN_OUT);
+    run("Steps", Steps::new(4, StepPosition::JumpEnd));
+}
+
+#[test]
+fn easings() {
+    for &easing in Easing::ALL {
+        run(easing.name().unwrap(), easing);
+    }
+
+    run("Easing::CubicBezier", Easing::CubicBezier(CubicBezier::EASE));
+    run("Easing::Steps", Easing::Steps(Steps::new(3, StepPosition::JumpStart)));
+}
+
+#[test]
+fn wrappers() {
+    assert_no_alloc("Looper", || {
+        let mut looper = Tweener::new(0.0f32, 1.0, 1.0, Looper::new(CubicBezier::EASE_IN_OUT));
+        for i in 0..100 {
+            looper.move_to(i as f32 * 0.1);
+        }
+
+        let mut oscillator = Tweener::new(0.0f32, 1.0, 1.0, Oscillator::new(CubicBezier::EASE_IN_OUT));
+        oscillator.move_by(3.5)
+    });
+}
+
+#[test]
+fn counter_works() {
+    let allocated = std::panic::catch_unwind(|| assert_no_alloc("Vec", || vec![1, 2, 3]));
+    assert!(allocated.is_err());
+}
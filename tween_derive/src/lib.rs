@@ -0,0 +1,84 @@
+//! Derive macros for [tween](https://docs.rs/tween). You shouldn't need to depend on this crate
+//! directly -- enable the `derive` feature of `tween` instead.
+
+#![deny(unsafe_code)]
+#![deny(rust_2018_idioms)]
+#![deny(missing_docs)]
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Index, Member, parse_macro_input, spanned::Spanned};
+
+/// Derives `TweenValue`, along with the `Add` and `Sub` impls it needs, for a struct whose fields
+/// are all `TweenValue`s. Each operation is done field by field.
+///
+/// The struct still needs to derive `Clone` and `Debug` itself.
+#[proc_macro_derive(TweenValue)]
+pub fn derive_tween_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new(input.span(), "`TweenValue` can only be derived for structs")),
+    };
+
+    if let Fields::Unit = fields {
+        return Err(Error::new(
+            input.span(),
+            "`TweenValue` can't be derived for unit structs",
+        ));
+    }
+
+    let members: Vec<Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        })
+        .collect();
+
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for field in fields {
+            let ty = &field.ty;
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ty: ::tween::TweenValue));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::ops::Add for #name #ty_generics #where_clause {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { #(#members: self.#members + rhs.#members,)* }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Sub for #name #ty_generics #where_clause {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { #(#members: self.#members - rhs.#members,)* }
+            }
+        }
+
+        impl #impl_generics ::tween::TweenValue for #name #ty_generics #where_clause {
+            #[inline]
+            fn scale(self, scale: f32) -> Self {
+                Self { #(#members: ::tween::TweenValue::scale(self.#members, scale),)* }
+            }
+        }
+    })
+}
@@ -0,0 +1,52 @@
+use tween::{Linear, TweenValue, Tweener};
+
+#[derive(Debug, Clone, Copy, PartialEq, TweenValue)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, TweenValue)]
+struct Size(i32, i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, TweenValue)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+#[test]
+fn named_fields() {
+    let mut tweener = Tweener::new(Position { x: 0.0, y: 10.0 }, Position { x: 10.0, y: 0.0 }, 10.0, Linear);
+
+    assert_eq!(tweener.move_to(5.0), Position { x: 5.0, y: 5.0 });
+    assert_eq!(Position { x: 1.0, y: 2.0 }.scale(2.0), Position { x: 2.0, y: 4.0 });
+}
+
+#[test]
+fn tuple_fields() {
+    let mut tweener = Tweener::new(Size(0, 100), Size(100, 0), 4, Linear);
+
+    assert_eq!(tweener.move_to(1), Size(25, 75));
+    assert_eq!(Size(5, 5) - Size(2, 3), Size(3, 2));
+}
+
+#[test]
+fn generic_fields() {
+    let start = Pair {
+        first: 0.0,
+        second: 1.0,
+    };
+    let end = Pair {
+        first: 1.0,
+        second: 0.0,
+    };
+
+    assert_eq!(
+        tween::lerp(start, end, 0.25),
+        Pair {
+            first: 0.25,
+            second: 0.75
+        }
+    );
+}